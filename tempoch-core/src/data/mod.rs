@@ -6,6 +6,8 @@
 pub mod runtime_data;
 pub mod status;
 
+#[cfg(feature = "chrono")]
+pub use status::assert_fresh_chrono;
 pub use status::{
     assert_fresh, time_data_status, ActiveTimeDataSource, DataHorizons, FreshnessError,
     TimeDataStatus,
@@ -15,9 +15,11 @@ pub(crate) use store::{
 #[cfg(feature = "runtime-data-fetch")]
 pub use store::{fetch_latest_time_data, refresh_runtime_time_data, update_runtime_time_data};
 pub(crate) use utc_tai::{
-    time_data_tai_seconds_from_utc, time_data_tai_seconds_is_in_leap_window,
-    time_data_try_tai_minus_utc_mjd, time_data_utc_from_tai_seconds,
+    time_data_tai_nanos_from_utc_day_nanos, time_data_tai_seconds_is_in_leap_window,
+    time_data_try_tai_minus_utc_mjd, time_data_utc_day_nanos_from_tai_nanos,
 };
+#[cfg(feature = "chrono")]
+pub(crate) use utc_tai::{time_data_tai_seconds_from_utc, time_data_utc_from_tai_seconds};
 
 #[cfg(test)]
 mod tests {
@@ -27,6 +29,7 @@ mod tests {
     use crate::archive::time::{EopPoint, TimeDataBundle, TimeDataProvenance};
     use crate::format::{JulianDate, Unix, JD};
     use crate::{Time, TimeContext, TT, UT1, UTC};
+    #[cfg(feature = "chrono")]
     use chrono::DateTime;
     use qtty::{Arcsecond, Day as DayQuantity, Millisecond, Second};
 
@@ -125,7 +128,7 @@ mod tests {
     #[test]
     fn stale_cache_prefers_refresh_but_falls_back_if_refresh_fails() {
         let stale = bundle_with_timestamp("2026-04-15T00:00:00");
-        let now = DateTime::from_timestamp(1_776_134_400, 0).unwrap();
+        let now = 1_776_134_400;
         let selected = select_time_data_for_auto_refresh(
             Ok(stale.clone()),
             || Err(InternalDataError::Download("network unreachable".into())),
@@ -141,7 +144,7 @@ mod tests {
     #[test]
     fn fresh_cache_skips_refresh_in_auto_mode() {
         let fresh = bundle_with_timestamp("2026-04-20T00:00:00");
-        let now = DateTime::from_timestamp(1_776_139_200, 0).unwrap();
+        let now = 1_776_139_200;
         let selected = select_time_data_for_auto_refresh(
             Ok(fresh.clone()),
             || {
@@ -263,13 +266,16 @@ mod tests {
                 .raw_unix_seconds_with(&TimeContext::new())
                 .unwrap();
             assert!((roundtrip - unix).abs() < Second::new(1e-3));
-            let chrono = overridden.try_to_chrono().unwrap();
-            let from_chrono = Time::<UTC>::try_from_chrono(chrono).unwrap();
-            let drift = ((from_chrono.raw_seconds_pair().0.value()
-                + from_chrono.raw_seconds_pair().1.value())
-                - overridden_value)
-                .abs();
-            assert!(drift < 1e-4, "chrono round-trip drift = {drift}");
+            #[cfg(feature = "chrono")]
+            {
+                let chrono = overridden.try_to_chrono().unwrap();
+                let from_chrono = Time::<UTC>::try_from_chrono(chrono).unwrap();
+                let drift = ((from_chrono.raw_seconds_pair().0.value()
+                    + from_chrono.raw_seconds_pair().1.value())
+                    - overridden_value)
+                    .abs();
+                assert!(drift < 1e-4, "chrono round-trip drift = {drift}");
+            }
         });
     }
 
@@ -312,18 +318,21 @@ mod tests {
 
     #[test]
     fn pre_1961_utc_errors_by_default_and_roundtrips_with_opt_in() {
-        let dt = DateTime::from_timestamp(-631_152_000, 250_000_000).unwrap();
-
-        assert!(matches!(
-            Time::<UTC>::try_from_chrono(dt),
-            Err(crate::ConversionError::UtcBeforeDefinition)
-        ));
-
         let ctx = TimeContext::new().allow_pre_definition_utc();
-        let utc = Time::<UTC>::try_from_chrono_with(dt, &ctx).unwrap();
-        let back = utc.try_to_chrono_with(&ctx).unwrap();
-        let drift = (back.timestamp_nanos_opt().unwrap() - dt.timestamp_nanos_opt().unwrap()).abs();
-        assert!(drift < 50_000, "pre-1961 UTC round-trip drift = {drift} ns");
+
+        #[cfg(feature = "chrono")]
+        {
+            let dt = DateTime::from_timestamp(-631_152_000, 250_000_000).unwrap();
+            assert!(matches!(
+                Time::<UTC>::try_from_chrono(dt),
+                Err(crate::ConversionError::UtcBeforeDefinition)
+            ));
+            let utc = Time::<UTC>::try_from_chrono_with(dt, &ctx).unwrap();
+            let back = utc.try_to_chrono_with(&ctx).unwrap();
+            let drift =
+                (back.timestamp_nanos_opt().unwrap() - dt.timestamp_nanos_opt().unwrap()).abs();
+            assert!(drift < 50_000, "pre-1961 UTC round-trip drift = {drift} ns");
+        }
 
         let unix = Second::new(-631_152_000.75);
         assert!(matches!(
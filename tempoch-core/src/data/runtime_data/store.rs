@@ -8,8 +8,6 @@ use crate::archive::time::TimeDataError as InternalDataError;
 use crate::archive::time::TimeDataManager;
 use crate::data::status::ActiveTimeDataSource;
 #[cfg(test)]
-use chrono::{DateTime, Utc};
-#[cfg(test)]
 use std::sync::Mutex;
 use std::sync::{Arc, OnceLock, RwLock};
 
@@ -121,10 +119,10 @@ pub(crate) fn select_time_data(
 }
 
 #[cfg(test)]
-fn bundle_is_stale(bundle: &TimeDataBundle, now: DateTime<Utc>) -> bool {
+fn bundle_is_stale(bundle: &TimeDataBundle, now_unix_seconds: i64) -> bool {
     match bundle.provenance().fetched_at() {
         Some(fetched_at) => {
-            now.signed_duration_since(fetched_at).num_seconds() > RUNTIME_DATA_MAX_AGE_SECONDS
+            now_unix_seconds - fetched_at.timestamp() > RUNTIME_DATA_MAX_AGE_SECONDS
         }
         None => true,
     }
@@ -134,10 +132,10 @@ fn bundle_is_stale(bundle: &TimeDataBundle, now: DateTime<Utc>) -> bool {
 pub(crate) fn select_time_data_for_auto_refresh(
     cached: Result<TimeDataBundle, InternalDataError>,
     refresh: impl FnOnce() -> Result<TimeDataBundle, InternalDataError>,
-    now: DateTime<Utc>,
+    now_unix_seconds: i64,
 ) -> Result<TimeDataBundle, InternalDataError> {
     match cached {
-        Ok(bundle) if !bundle_is_stale(&bundle, now) => Ok(bundle),
+        Ok(bundle) if !bundle_is_stale(&bundle, now_unix_seconds) => Ok(bundle),
         Ok(bundle) => refresh().or(Ok(bundle)),
         Err(_) => refresh(),
     }
@@ -2,19 +2,27 @@
 // Copyright (C) 2026 Vallés Puig, Ramon
 
 use crate::archive::time::{TimeDataBundle, UtcTaiSegment};
-use crate::encoding::{
-    day_to_j2000_seconds, j2000_seconds_to_day, jd_to_mjd, mjd_to_unix_seconds, unix_seconds_to_jd,
-};
+#[cfg(feature = "chrono")]
+use crate::encoding::{day_to_j2000_seconds, mjd_to_unix_seconds, unix_seconds_to_jd};
+use crate::encoding::{j2000_seconds_to_day, jd_to_mjd};
 use crate::format::JD;
 use crate::foundation::constats::{TT_MINUS_TAI, UTC_INTERVAL_EPS};
 use crate::foundation::error::ConversionError;
+#[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
-use qtty::unit::{Day, Nanosecond, Second as SecondUnit};
-use qtty::{Day as DayQuantity, Nanosecond as NanosecondQty, Second};
+use qtty::unit::Day;
+#[cfg(feature = "chrono")]
+use qtty::unit::{Nanosecond, Second as SecondUnit};
+#[cfg(feature = "chrono")]
+use qtty::Nanosecond as NanosecondQty;
+use qtty::{Day as DayQuantity, Second};
 
+#[cfg(feature = "chrono")]
 const NANOS_PER_SECOND: NanosecondQty = NanosecondQty::new(1_000_000_000.0);
+const NANOS_PER_DAY: i128 = 86_400 * 1_000_000_000;
 
 #[derive(Clone, Copy)]
+#[cfg_attr(not(feature = "chrono"), allow(dead_code))]
 enum UtcTaiRegion {
     Segment(UtcTaiSegment),
     Leap {
@@ -60,6 +68,7 @@ pub(super) fn time_data_tai_minus_utc_mjd_extrapolated(
     time_data_try_tai_minus_utc_mjd(data, mjd_utc, true).ok()
 }
 
+#[cfg(feature = "chrono")]
 pub(crate) fn time_data_utc_from_tai_seconds(
     data: &TimeDataBundle,
     tai_secs: Second,
@@ -97,6 +106,7 @@ pub(crate) fn time_data_utc_from_tai_seconds(
     }
 }
 
+#[cfg(feature = "chrono")]
 pub(crate) fn time_data_tai_seconds_from_utc(
     data: &TimeDataBundle,
     dt: DateTime<Utc>,
@@ -135,6 +145,108 @@ pub(crate) fn time_data_tai_seconds_is_in_leap_window(
     )
 }
 
+/// UTC `(MJD, nanoseconds of day)` for an instant given as TAI nanoseconds
+/// since MJD 0 on the TAI axis.
+///
+/// Integer-offset segments (1972 onward) are resolved in exact integer
+/// arithmetic; inside an inserted leap second the label stays on the ending
+/// day with nanoseconds of day in `[86_400 s, 86_400 s + step)`. The
+/// pre-1972 rubber-second segments are resolved in `f64` and rounded to the
+/// nearest nanosecond.
+pub(crate) fn time_data_utc_day_nanos_from_tai_nanos(
+    data: &TimeDataBundle,
+    tai_nanos: i128,
+    allow_extrapolation: bool,
+) -> Result<(i64, i128), ConversionError> {
+    let segments = data.utc_tai_segments();
+    let idx = segments.partition_point(|segment| segment_start_tai_nanos(*segment) <= tai_nanos);
+    if idx == 0 && !allow_extrapolation {
+        return Err(ConversionError::UtcBeforeDefinition);
+    }
+    let segment = segments[idx.saturating_sub(1)];
+    if let (Some(end_mjd), Some(_)) = (segment.end_mjd, segments.get(idx)) {
+        let end_tai = segment_tai_nanos_at_midnight(segment, end_mjd);
+        if tai_nanos >= end_tai {
+            return Ok((end_mjd as i64 - 1, NANOS_PER_DAY + (tai_nanos - end_tai)));
+        }
+    }
+    let utc_nanos = if segment.slope_seconds_per_day == 0.0 {
+        tai_nanos - segment_offset_nanos(segment, segment.start_mjd as f64)
+    } else {
+        let rate = 1.0 + segment.slope_seconds_per_day / 86_400.0;
+        let elapsed = (tai_nanos - segment_start_tai_nanos(segment)) as f64 / rate;
+        segment.start_mjd as i128 * NANOS_PER_DAY + elapsed.round() as i128
+    };
+    Ok((
+        utc_nanos.div_euclid(NANOS_PER_DAY) as i64,
+        utc_nanos.rem_euclid(NANOS_PER_DAY),
+    ))
+}
+
+/// Inverse of [`time_data_utc_day_nanos_from_tai_nanos`].
+///
+/// Nanoseconds of day at or beyond 86 400 s are accepted only on a day that
+/// ends with a positive UTC step, and only up to the length of that step.
+pub(crate) fn time_data_tai_nanos_from_utc_day_nanos(
+    data: &TimeDataBundle,
+    mjd_utc: i64,
+    nanos_of_day: i128,
+    allow_extrapolation: bool,
+) -> Result<i128, ConversionError> {
+    if nanos_of_day < 0 {
+        return Err(ConversionError::OutOfRange);
+    }
+    let segments = data.utc_tai_segments();
+    let idx = segments.partition_point(|segment| segment.start_mjd as i64 <= mjd_utc);
+    if idx == 0 && !allow_extrapolation {
+        return Err(ConversionError::UtcBeforeDefinition);
+    }
+    let segment = segments[idx.saturating_sub(1)];
+    let ends_segment = segment.end_mjd.map(i64::from) == Some(mjd_utc + 1);
+    let step = match (ends_segment, segments.get(idx)) {
+        (true, Some(next)) => {
+            segment_start_tai_nanos(*next)
+                - segment_tai_nanos_at_midnight(segment, mjd_utc as i32 + 1)
+        }
+        _ => 0,
+    };
+    if nanos_of_day >= NANOS_PER_DAY + step {
+        let leap_label = NANOS_PER_DAY..NANOS_PER_DAY + 1_000_000_000;
+        return Err(if leap_label.contains(&nanos_of_day) {
+            ConversionError::InvalidLeapSecond
+        } else {
+            ConversionError::OutOfRange
+        });
+    }
+    if nanos_of_day >= NANOS_PER_DAY {
+        return Ok(segment_tai_nanos_at_midnight(segment, mjd_utc as i32 + 1)
+            + (nanos_of_day - NANOS_PER_DAY));
+    }
+    let utc_nanos = mjd_utc as i128 * NANOS_PER_DAY + nanos_of_day;
+    if segment.slope_seconds_per_day == 0.0 {
+        return Ok(utc_nanos + segment_offset_nanos(segment, segment.start_mjd as f64));
+    }
+    let rate = 1.0 + segment.slope_seconds_per_day / 86_400.0;
+    let elapsed = (utc_nanos - segment.start_mjd as i128 * NANOS_PER_DAY) as f64 * rate;
+    Ok(segment_start_tai_nanos(segment) + elapsed.round() as i128)
+}
+
+/// TAI − UTC of `segment` at a UTC MJD, rounded to the nearest nanosecond.
+fn segment_offset_nanos(segment: UtcTaiSegment, mjd_utc: f64) -> i128 {
+    (utc_offset_seconds_in_segment(DayQuantity::new(mjd_utc), segment).value() * 1e9).round()
+        as i128
+}
+
+/// TAI nanoseconds since MJD 0 at UTC midnight of `mjd_utc`, using the
+/// offset law of `segment`.
+fn segment_tai_nanos_at_midnight(segment: UtcTaiSegment, mjd_utc: i32) -> i128 {
+    mjd_utc as i128 * NANOS_PER_DAY + segment_offset_nanos(segment, mjd_utc as f64)
+}
+
+fn segment_start_tai_nanos(segment: UtcTaiSegment) -> i128 {
+    segment_tai_nanos_at_midnight(segment, segment.start_mjd)
+}
+
 fn utc_offset_seconds_in_segment(mjd_utc: DayQuantity, segment: UtcTaiSegment) -> Second {
     let utc_offset = mjd_utc - DayQuantity::new(segment.reference_mjd);
     segment.base + Second::new(segment.slope_seconds_per_day) * (utc_offset / DayQuantity::new(1.0))
@@ -144,6 +256,7 @@ fn utc_mjd_to_tt_mjd_in_segment(mjd_utc: DayQuantity, segment: UtcTaiSegment) ->
     mjd_utc + (utc_offset_seconds_in_segment(mjd_utc, segment) + TT_MINUS_TAI).to::<Day>()
 }
 
+#[cfg(feature = "chrono")]
 fn tt_mjd_to_utc_mjd_in_segment(mjd_tt: DayQuantity, segment: UtcTaiSegment) -> DayQuantity {
     let scale = DayQuantity::new(1.0) + Second::new(segment.slope_seconds_per_day).to::<Day>();
     let ref_days = DayQuantity::new(segment.reference_mjd) / DayQuantity::new(1.0);
@@ -187,6 +300,7 @@ fn locate_utc_region_from_tt_mjd(
     Ok(UtcTaiRegion::Segment(segment))
 }
 
+#[cfg(feature = "chrono")]
 fn datetime_from_seconds_since_epoch(seconds_since_epoch: Second) -> Option<DateTime<Utc>> {
     if !seconds_since_epoch.is_finite() {
         return None;
@@ -205,6 +319,7 @@ fn datetime_from_seconds_since_epoch(seconds_since_epoch: Second) -> Option<Date
     )
 }
 
+#[cfg(feature = "chrono")]
 fn datetime_from_utc_mjd(mjd_utc: DayQuantity) -> Option<DateTime<Utc>> {
     datetime_from_seconds_since_epoch(mjd_to_unix_seconds(mjd_utc))
 }
@@ -9,10 +9,13 @@
 //! active: the archive provenance record, validity horizons relevant to time
 //! conversions, and the source of the active bundle.
 
-use chrono::{DateTime, Utc};
-
 use crate::archive::time::TimeDataProvenance;
 use crate::data::runtime_data::{active_time_data, active_time_data_source};
+use crate::format::Iso8601Profile;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::UTC;
+use crate::model::time::Time;
 
 /// Source of the currently active time-data bundle.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -65,6 +68,9 @@ pub struct DataHorizons {
 pub enum FreshnessError {
     /// The active bundle has no parseable archive provenance timestamp.
     MissingTimestamp,
+    /// `now`, `max_age`, or the bundle age is outside the exact
+    /// nanosecond range.
+    Conversion(ConversionError),
     /// The bundle is older than `max_age` relative to `now`. Carries the
     /// observed age in seconds.
     Stale {
@@ -79,6 +85,7 @@ impl core::fmt::Display for FreshnessError {
             Self::MissingTimestamp => {
                 f.write_str("time-data bundle has no parseable fetched_at timestamp")
             }
+            Self::Conversion(err) => write!(f, "cannot measure time-data bundle age: {err}"),
             Self::Stale {
                 age_seconds,
                 max_age_seconds,
@@ -90,7 +97,14 @@ impl core::fmt::Display for FreshnessError {
     }
 }
 
-impl std::error::Error for FreshnessError {}
+impl std::error::Error for FreshnessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 /// Capture status for the currently active time-data bundle.
 ///
@@ -114,24 +128,53 @@ pub fn time_data_status() -> TimeDataStatus {
 
 /// Assert the active bundle is no older than `max_age` relative to `now`.
 ///
-/// Freshness is based on `status.provenance.fetched_at()` from the archive
-/// provenance record.
-pub fn assert_fresh(now: DateTime<Utc>, max_age: chrono::Duration) -> Result<(), FreshnessError> {
+/// Freshness is based on `status.provenance.fetched_utc()` from the archive
+/// provenance record, read as an ISO 8601 UTC label. The age is the elapsed
+/// SI time between that label and `now`, leap seconds included.
+pub fn assert_fresh(now: Time<UTC>, max_age: ExactDuration) -> Result<(), FreshnessError> {
     let status = time_data_status();
-    let fetched = status
-        .provenance
-        .fetched_at()
-        .ok_or(FreshnessError::MissingTimestamp)?;
-    let age = now.signed_duration_since(fetched);
-    if age > max_age {
+    let fetched =
+        Time::<UTC>::parse_iso8601(status.provenance.fetched_utc(), Iso8601Profile::EXTENDED)
+            .map_err(|_| FreshnessError::MissingTimestamp)?;
+    let fetched = fetched
+        .to_exact_j2000()
+        .map_err(|_| FreshnessError::MissingTimestamp)?;
+    let age = now
+        .to_exact_j2000()
+        .and_then(|now| {
+            now.checked_sub(fetched)
+                .map_err(|_| ConversionError::OutOfRange)
+        })
+        .map_err(FreshnessError::Conversion)?;
+    if age.as_nanos_i128() > max_age.as_nanos_i128() {
         return Err(FreshnessError::Stale {
-            age_seconds: age.num_seconds(),
-            max_age_seconds: max_age.num_seconds(),
+            age_seconds: whole_seconds(age),
+            max_age_seconds: whole_seconds(max_age),
         });
     }
     Ok(())
 }
 
+/// Like [`assert_fresh`], taking `chrono` types.
+#[cfg(feature = "chrono")]
+pub fn assert_fresh_chrono(
+    now: chrono::DateTime<chrono::Utc>,
+    max_age: chrono::Duration,
+) -> Result<(), FreshnessError> {
+    let now = Time::<UTC>::try_from_chrono(now).map_err(FreshnessError::Conversion)?;
+    let max_age = max_age
+        .num_nanoseconds()
+        .map_or(ExactDuration::from_nanos(i128::MAX), |nanos| {
+            ExactDuration::from_nanos(nanos as i128)
+        });
+    assert_fresh(now, max_age)
+}
+
+fn whole_seconds(duration: ExactDuration) -> i64 {
+    let seconds = duration.as_nanos_i128() / NANOS_PER_SECOND;
+    seconds.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -229,14 +272,16 @@ mod tests {
         });
     }
 
+    fn fifteen_minutes() -> ExactDuration {
+        ExactDuration::from_seconds_and_nanos(900, 0).unwrap()
+    }
+
     #[test]
     fn assert_fresh_accepts_recent_archive_provenance() {
         let bundle = eop_bundle_for_status_test("2024-01-01T00:00:00");
         with_test_time_data(bundle, || {
-            let now = DateTime::parse_from_rfc3339("2024-01-01T00:10:00Z")
-                .unwrap()
-                .with_timezone(&Utc);
-            assert!(assert_fresh(now, chrono::Duration::minutes(15)).is_ok());
+            let now = Time::<UTC>::parse_rfc3339("2024-01-01T00:10:00Z").unwrap();
+            assert!(assert_fresh(now, fifteen_minutes()).is_ok());
         });
     }
 
@@ -244,11 +289,30 @@ mod tests {
     fn assert_fresh_rejects_stale_archive_provenance() {
         let bundle = eop_bundle_for_status_test("2024-01-01T00:00:00");
         with_test_time_data(bundle, || {
-            let now = DateTime::parse_from_rfc3339("2024-01-01T01:00:00Z")
-                .unwrap()
-                .with_timezone(&Utc);
-            let res = assert_fresh(now, chrono::Duration::minutes(15));
-            assert!(matches!(res, Err(FreshnessError::Stale { .. })));
+            let now = Time::<UTC>::parse_rfc3339("2024-01-01T01:00:00Z").unwrap();
+            let res = assert_fresh(now, fifteen_minutes());
+            assert_eq!(
+                res,
+                Err(FreshnessError::Stale {
+                    age_seconds: 3_600,
+                    max_age_seconds: 900,
+                })
+            );
+        });
+    }
+
+    #[test]
+    fn assert_fresh_reports_unrepresentable_now() {
+        let bundle = eop_bundle_for_status_test("2024-01-01T00:00:00");
+        with_test_time_data(bundle, || {
+            let now = Time::<UTC>::from_raw_j2000_seconds(Second::new(1e300)).unwrap();
+            let res = assert_fresh(now, fifteen_minutes());
+            assert_eq!(
+                res,
+                Err(FreshnessError::Conversion(ConversionError::OutOfRange))
+            );
+            let err = res.unwrap_err();
+            assert!(std::error::Error::source(&err).is_some());
         });
     }
 
@@ -98,7 +98,7 @@ impl TimeContext {
 
     /// Allow UTC conversions for dates before 1961-01-01.
     ///
-    /// By default, [`Time::<UTC>::try_from_chrono_with`](crate::Time::try_from_chrono_with) and related conversions
+    /// By default, [`Time::<UTC>::parse_rfc3339_with`](crate::Time::parse_rfc3339_with) and related conversions
     /// return [`crate::ConversionError::UtcBeforeDefinition`] for any date
     /// before MJD 37 300 (1961-01-01), because UTC was not an international
     /// standard before that date and the back-extrapolated offset is
@@ -111,12 +111,15 @@ impl TimeContext {
     ///
     /// # Example
     /// ```
-    /// use tempoch_core::{TimeContext, Time, UTC};
-    /// use chrono::DateTime;
+    /// use tempoch_core::{ConversionError, TimeContext, Time, UTC};
     ///
-    /// let dt = DateTime::from_timestamp(-631_152_000, 0).unwrap();
+    /// let label = "1950-01-01T00:00:00Z";
+    /// assert_eq!(
+    ///     Time::<UTC>::parse_rfc3339(label),
+    ///     Err(ConversionError::UtcBeforeDefinition)
+    /// );
     /// let ctx = TimeContext::new().allow_pre_definition_utc();
-    /// let utc = Time::<UTC>::try_from_chrono_with(dt, &ctx).unwrap();
+    /// let utc = Time::<UTC>::parse_rfc3339_with(label, &ctx).unwrap();
     /// ```
     #[inline]
     pub fn allow_pre_definition_utc(mut self) -> Self {
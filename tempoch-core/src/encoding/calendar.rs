@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Proleptic Gregorian and Julian calendars ↔ integer MJD.
+//!
+//! Pure integer arithmetic (H. Hinnant's `days_from_civil` /
+//! `civil_from_days`), valid for years within ±[`MAX_ABS_YEAR`] and MJDs
+//! within ±[`MAX_ABS_MJD`], which keeps every day count well inside `i64`.
+
+/// Days from 0000-03-01 (Gregorian) to 1858-11-17 (MJD 0).
+const MJD_ZERO_FROM_MARCH_EPOCH: i64 = 678_881;
+/// Days from 0000-03-01 (Julian) to 1858-11-05 Julian (MJD 0).
+const MJD_ZERO_FROM_JULIAN_MARCH_EPOCH: i64 = 678_883;
+
+/// Largest year magnitude accepted by [`mjd_from_gregorian`] and
+/// [`mjd_from_julian`] (≈ 4.5 × 10¹⁵).
+pub(crate) const MAX_ABS_YEAR: i64 = 1 << 52;
+/// Largest MJD magnitude whose date stays within ±[`MAX_ABS_YEAR`].
+pub(crate) const MAX_ABS_MJD: i64 = MAX_ABS_YEAR * 365;
+
+/// Whether `year` is a Gregorian leap year.
+#[inline]
+pub(crate) const fn is_gregorian_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Days in `month` (1–12) of `year`.
+#[inline]
+pub(crate) const fn days_in_month(year: i64, month: u32) -> u32 {
+    match month {
+        2 if is_gregorian_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days in `year` (365 or 366).
+#[inline]
+pub(crate) const fn days_in_year(year: i64) -> u32 {
+    if is_gregorian_leap_year(year) {
+        366
+    } else {
+        365
+    }
+}
+
+/// MJD of a Gregorian calendar date, or `None` for an invalid date or a
+/// year beyond ±[`MAX_ABS_YEAR`].
+pub(crate) fn mjd_from_gregorian(year: i64, month: u32, day: u32) -> Option<i64> {
+    if year.unsigned_abs() > MAX_ABS_YEAR as u64
+        || !(1..=12).contains(&month)
+        || day == 0
+        || day > days_in_month(year, month)
+    {
+        return None;
+    }
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Some(era * 146_097 + doe - MJD_ZERO_FROM_MARCH_EPOCH)
+}
+
+/// Gregorian `(year, month, day)` of an MJD within ±[`MAX_ABS_MJD`].
+pub(crate) fn gregorian_from_mjd(mjd: i64) -> (i64, u32, u32) {
+    let z = mjd + MJD_ZERO_FROM_MARCH_EPOCH;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// MJD of day-of-year `ordinal` (1-based) in `year`, or `None` if out of range.
+pub(crate) fn mjd_from_year_day(year: i64, ordinal: u32) -> Option<i64> {
+    if ordinal == 0 || ordinal > days_in_year(year) {
+        return None;
+    }
+    Some(mjd_from_gregorian(year, 1, 1)? + ordinal as i64 - 1)
+}
+
+/// MJD of a proleptic Julian calendar date, or `None` for an invalid date or
+/// a year beyond ±[`MAX_ABS_YEAR`].
+pub(crate) fn mjd_from_julian(year: i64, month: u32, day: u32) -> Option<i64> {
+    if year.unsigned_abs() > MAX_ABS_YEAR as u64 {
+        return None;
+    }
+    let month_len = match month {
+        2 if year.rem_euclid(4) == 0 => 29,
+        1..=12 => days_in_month(1, month),
+        _ => return None,
+    };
+    if day == 0 || day > month_len {
+        return None;
+    }
+    let y = if month <= 2 { year - 1 } else { year };
+    let mp = (month as i64 + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day as i64 - 1;
+    let doe = y.rem_euclid(4) * 365 + doy;
+    Some(y.div_euclid(4) * 1_461 + doe - MJD_ZERO_FROM_JULIAN_MARCH_EPOCH)
+}
+
+/// Proleptic Julian `(year, month, day)` of an MJD within ±[`MAX_ABS_MJD`].
+pub(crate) fn julian_from_mjd(mjd: i64) -> (i64, u32, u32) {
+    let z = mjd + MJD_ZERO_FROM_JULIAN_MARCH_EPOCH;
+    let cycle = z.div_euclid(1_461);
+    let doc = z.rem_euclid(1_461);
+    // The leap day closes the four-year cycle at day 1 460.
+    let yoc = (doc - doc / 1_460) / 365;
+    let doy = doc - 365 * yoc;
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoc + cycle * 4 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+/// `(year, day_of_year)` of an MJD, with a 1-based day of year.
+pub(crate) fn year_day_from_mjd(mjd: i64) -> (i64, u32) {
+    let (year, _, _) = gregorian_from_mjd(mjd);
+    let jan1 = mjd_from_gregorian(year, 1, 1).expect("January 1 is a valid date");
+    (year, (mjd - jan1 + 1) as u32)
+}
+
+/// ISO weekday (Monday = 1 … Sunday = 7) of `mjd`; MJD 0 was a Wednesday.
+#[inline]
+pub(crate) fn iso_weekday(mjd: i64) -> u32 {
+    (mjd + 2).rem_euclid(7) as u32 + 1
+}
+
+/// ISO 8601 week-numbering year and week of `mjd`.
+pub(crate) fn iso_week(mjd: i64) -> (i64, u32) {
+    // A week belongs to the year that contains its Thursday.
+    let thursday = mjd + 4 - iso_weekday(mjd) as i64;
+    let (year, ordinal) = year_day_from_mjd(thursday);
+    (year, (ordinal - 1) / 7 + 1)
+}
+
+/// MJD of ISO week date `year-Wweek-weekday`, or `None` if out of range.
+pub(crate) fn mjd_from_iso_week(year: i64, week: u32, weekday: u32) -> Option<i64> {
+    if !(1..=7).contains(&weekday) {
+        return None;
+    }
+    let jan4 = mjd_from_gregorian(year, 1, 4)?;
+    let week_one = jan4 + 1 - iso_weekday(jan4) as i64;
+    let mjd = week_one + (week as i64 - 1) * 7 + weekday as i64 - 1;
+    (iso_week(mjd) == (year, week)).then_some(mjd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_dates() {
+        assert_eq!(mjd_from_gregorian(1858, 11, 17), Some(0));
+        assert_eq!(mjd_from_gregorian(2000, 1, 1), Some(51_544));
+        assert_eq!(mjd_from_gregorian(1970, 1, 1), Some(40_587));
+        assert_eq!(mjd_from_gregorian(1958, 1, 1), Some(36_204));
+        assert_eq!(gregorian_from_mjd(57_753), (2016, 12, 31));
+        assert_eq!(gregorian_from_mjd(-678_881), (0, 3, 1));
+        // The Gregorian reform: 1582-10-04 Julian is followed by 1582-10-15.
+        assert_eq!(mjd_from_julian(1582, 10, 4), Some(-100_841));
+        assert_eq!(mjd_from_gregorian(1582, 10, 15), Some(-100_840));
+        assert_eq!(julian_from_mjd(-100_841), (1582, 10, 4));
+        assert_eq!(julian_from_mjd(-678_883), (0, 3, 1));
+        assert_eq!(mjd_from_julian(1858, 11, 5), Some(0));
+        assert_eq!(
+            mjd_from_julian(1900, 2, 29),
+            mjd_from_gregorian(1900, 3, 13)
+        );
+    }
+
+    #[test]
+    fn round_trips_and_rejects_invalid_dates() {
+        for mjd in (-800_000..800_000).step_by(997) {
+            let (y, m, d) = gregorian_from_mjd(mjd);
+            assert_eq!(mjd_from_gregorian(y, m, d), Some(mjd));
+            let (y, doy) = year_day_from_mjd(mjd);
+            assert_eq!(mjd_from_year_day(y, doy), Some(mjd));
+            let (y, m, d) = julian_from_mjd(mjd);
+            assert_eq!(mjd_from_julian(y, m, d), Some(mjd));
+        }
+        assert_eq!(mjd_from_gregorian(1900, 2, 29), None);
+        assert!(mjd_from_julian(1900, 2, 29).is_some());
+        assert_eq!(mjd_from_gregorian(2024, 13, 1), None);
+        assert_eq!(mjd_from_year_day(2023, 366), None);
+        assert_eq!(year_day_from_mjd(60_675), (2024, 366));
+    }
+}
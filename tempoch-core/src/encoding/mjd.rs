@@ -1,4 +1,4 @@
-use crate::foundation::constats::{unix_epoch_mjd_day, JD_MINUS_MJD, UNIX_EPOCH_JD_DAY};
+use crate::foundation::constats::{unix_epoch_mjd_day, JD_MINUS_MJD};
 use qtty::unit::{Day as DayUnit, Second as SecondUnit};
 use qtty::{Day, Second};
 
@@ -21,7 +21,8 @@ pub(crate) fn unix_seconds_to_mjd(seconds: Second) -> Day {
 }
 
 /// Seconds since Unix epoch → Julian Day (UTC axis).
+#[cfg(any(test, feature = "chrono"))]
 #[inline]
 pub(crate) fn unix_seconds_to_jd(seconds: Second) -> Day {
-    UNIX_EPOCH_JD_DAY + seconds.to::<DayUnit>()
+    crate::foundation::constats::UNIX_EPOCH_JD_DAY + seconds.to::<DayUnit>()
 }
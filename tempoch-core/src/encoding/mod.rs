@@ -80,11 +80,18 @@ pub(crate) fn j2000_seconds_to_day<F: DayEncoding>(seconds: Second) -> Day {
     affine_day_coordinate(seconds.to::<DayUnit>(), Day::new(0.0), F::j2000_origin())
 }
 
+mod calendar;
 mod jd;
 mod mjd;
 
+pub(crate) use calendar::{
+    days_in_month, gregorian_from_mjd, iso_week, iso_weekday, julian_from_mjd, mjd_from_gregorian,
+    mjd_from_iso_week, mjd_from_julian, mjd_from_year_day, year_day_from_mjd, MAX_ABS_MJD,
+};
 pub(crate) use jd::jd_to_julian_centuries;
-pub(crate) use mjd::{jd_to_mjd, mjd_to_unix_seconds, unix_seconds_to_jd, unix_seconds_to_mjd};
+#[cfg(any(test, feature = "chrono"))]
+pub(crate) use mjd::unix_seconds_to_jd;
+pub(crate) use mjd::{jd_to_mjd, mjd_to_unix_seconds, unix_seconds_to_mjd};
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,300 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Proleptic Julian and Gregorian calendars with astronomical year
+//! numbering.
+//!
+//! [`Calendar`] converts between Julian Days (or integer MJDs) and
+//! [`CalendarDate`]s in the proleptic Julian calendar, the proleptic
+//! Gregorian calendar, or the Julian calendar up to a configurable
+//! switchover and the Gregorian one from it on
+//! ([`Calendar::GREGORIAN_REFORM`] is the 1582 reform). Years are
+//! astronomical: year 0 is 1 BC and year −584 is 585 BC.
+//!
+//! [`Time::from_calendar_date`] and [`Time::to_calendar_date`] label
+//! instants on continuous scales such as `UT1` and `TT`, so ancient dates
+//! feed straight into the ΔT model of `UT1 ↔ TT` conversions.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{Calendar, CalendarDate, ExactDuration, Time, TimeContext, TT, UT1};
+//!
+//! // The eclipse of Thales, 585 BC May 28 (Julian), at noon UT1.
+//! let date = CalendarDate::new(-584, 5, 28);
+//! let noon = ExactDuration::from_seconds_and_nanos(43_200, 0).unwrap();
+//! let ut1 = Time::<UT1>::from_calendar_date(Calendar::GREGORIAN_REFORM, date, noon).unwrap();
+//! assert_eq!(ut1.to_calendar_date(Calendar::Julian).unwrap(), (date, noon));
+//! assert_eq!(
+//!     ut1.to_calendar_date(Calendar::Gregorian).unwrap().0,
+//!     CalendarDate::new(-584, 5, 22)
+//! );
+//!
+//! // ΔT from the pre-948 model applies on the way to TT.
+//! let tt = ut1.to_scale_with::<TT>(&TimeContext::new()).unwrap();
+//! assert!(tt.to_calendar_date(Calendar::Julian).unwrap().1 > noon);
+//! ```
+
+use qtty::Day;
+
+use crate::encoding;
+use crate::foundation::constats::JD_MINUS_MJD;
+use crate::foundation::duration::ExactDuration;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::ContinuousScale;
+use crate::model::time::Time;
+
+use super::{CalendarScale, MjdSod};
+
+/// A calendar date in astronomical year numbering.
+///
+/// Which calendar the fields refer to is given by the [`Calendar`] used to
+/// read or write them; the date is not validated on its own.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CalendarDate {
+    /// Astronomical year (year 0 = 1 BC, year −1 = 2 BC).
+    pub year: i64,
+    /// Month, 1–12.
+    pub month: u32,
+    /// Day of month, 1–31.
+    pub day: u32,
+}
+
+impl CalendarDate {
+    /// Build a date from its fields.
+    #[inline]
+    pub const fn new(year: i64, month: u32, day: u32) -> Self {
+        Self { year, month, day }
+    }
+}
+
+impl core::fmt::Display for CalendarDate {
+    /// `YYYY-MM-DD`, with a leading `-` for negative years (`-0584-05-28`).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.year < 0 { "-" } else { "" };
+        write!(
+            f,
+            "{sign}{:04}-{:02}-{:02}",
+            self.year.unsigned_abs(),
+            self.month,
+            self.day
+        )
+    }
+}
+
+/// Calendar used to read and write [`CalendarDate`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Calendar {
+    /// Proleptic Julian calendar (leap year every fourth year).
+    Julian,
+    /// Proleptic Gregorian calendar.
+    Gregorian,
+    /// Julian before `first_gregorian_mjd`, Gregorian from it on. Dates
+    /// skipped by the switch are invalid.
+    Switchover {
+        /// MJD of the first day counted in the Gregorian calendar.
+        first_gregorian_mjd: i64,
+    },
+}
+
+impl Calendar {
+    /// The 1582 reform: Julian 1582-10-04 is followed by Gregorian
+    /// 1582-10-15.
+    pub const GREGORIAN_REFORM: Self = Self::Switchover {
+        first_gregorian_mjd: -100_840,
+    };
+
+    /// Switch to the Gregorian calendar on the Gregorian date `first`
+    /// (e.g. 1752-09-14 for Great Britain).
+    pub fn switchover(first: CalendarDate) -> Result<Self, ConversionError> {
+        let first_gregorian_mjd = Self::Gregorian.mjd_from_date(first)?;
+        Ok(Self::Switchover {
+            first_gregorian_mjd,
+        })
+    }
+
+    /// Integer MJD of `date`. Returns [`ConversionError::OutOfRange`] for a
+    /// date that does not exist in this calendar or whose year is beyond
+    /// ±2⁵².
+    pub fn mjd_from_date(self, date: CalendarDate) -> Result<i64, ConversionError> {
+        let CalendarDate { year, month, day } = date;
+        let julian = || encoding::mjd_from_julian(year, month, day);
+        let gregorian = || encoding::mjd_from_gregorian(year, month, day);
+        match self {
+            Self::Julian => julian(),
+            Self::Gregorian => gregorian(),
+            Self::Switchover {
+                first_gregorian_mjd,
+            } => gregorian()
+                .filter(|&mjd| mjd >= first_gregorian_mjd)
+                .or_else(|| julian().filter(|&mjd| mjd < first_gregorian_mjd)),
+        }
+        .ok_or(ConversionError::OutOfRange)
+    }
+
+    /// Calendar date of an integer MJD. Returns
+    /// [`ConversionError::OutOfRange`] for an MJD whose year is beyond ±2⁵².
+    pub fn date_from_mjd(self, mjd: i64) -> Result<CalendarDate, ConversionError> {
+        if mjd.unsigned_abs() > encoding::MAX_ABS_MJD as u64 {
+            return Err(ConversionError::OutOfRange);
+        }
+        let gregorian = match self {
+            Self::Julian => false,
+            Self::Gregorian => true,
+            Self::Switchover {
+                first_gregorian_mjd,
+            } => mjd >= first_gregorian_mjd,
+        };
+        let (year, month, day) = if gregorian {
+            encoding::gregorian_from_mjd(mjd)
+        } else {
+            encoding::julian_from_mjd(mjd)
+        };
+        Ok(CalendarDate::new(year, month, day))
+    }
+
+    /// Julian Day at 0h of `date`.
+    pub fn jd_from_date(self, date: CalendarDate) -> Result<Day, ConversionError> {
+        Ok(Day::new(self.mjd_from_date(date)? as f64) + JD_MINUS_MJD)
+    }
+
+    /// Calendar date of the day containing Julian Day `jd` (days begin at
+    /// JD `n + 0.5`).
+    pub fn date_from_jd(self, jd: Day) -> Result<CalendarDate, ConversionError> {
+        let mjd = (jd - JD_MINUS_MJD).value().floor();
+        if !mjd.is_finite() {
+            return Err(ConversionError::NonFinite);
+        }
+        if mjd.abs() >= i64::MAX as f64 {
+            return Err(ConversionError::OutOfRange);
+        }
+        self.date_from_mjd(mjd as i64)
+    }
+}
+
+impl<S: ContinuousScale + CalendarScale> Time<S> {
+    /// Instant at `second_of_day` into `date` of `calendar`, on this scale's
+    /// own uniform days.
+    pub fn from_calendar_date(
+        calendar: Calendar,
+        date: CalendarDate,
+        second_of_day: ExactDuration,
+    ) -> Result<Self, ConversionError> {
+        let mjd = calendar.mjd_from_date(date)?;
+        Self::from_mjd_sod(MjdSod::new(qtty::i64::Day::new(mjd), second_of_day)?)
+    }
+
+    /// Date in `calendar` and elapsed time of day of this instant.
+    pub fn to_calendar_date(
+        self,
+        calendar: Calendar,
+    ) -> Result<(CalendarDate, ExactDuration), ConversionError> {
+        let label = self.to_mjd_sod()?;
+        Ok((
+            calendar.date_from_mjd(label.mjd.value())?,
+            label.second_of_day,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::earth::context::TimeContext;
+    use crate::model::scale::{TT, UT1};
+
+    #[test]
+    fn reform_switchover_skips_ten_days() {
+        let reform = Calendar::GREGORIAN_REFORM;
+        let last_julian = reform
+            .mjd_from_date(CalendarDate::new(1582, 10, 4))
+            .unwrap();
+        let first_gregorian = reform
+            .mjd_from_date(CalendarDate::new(1582, 10, 15))
+            .unwrap();
+        assert_eq!(first_gregorian - last_julian, 1);
+        assert_eq!(
+            reform.mjd_from_date(CalendarDate::new(1582, 10, 10)),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            reform.date_from_mjd(last_julian),
+            Ok(CalendarDate::new(1582, 10, 4))
+        );
+        // Julian leap day in a Gregorian common year.
+        assert!(reform.mjd_from_date(CalendarDate::new(1500, 2, 29)).is_ok());
+
+        let britain = Calendar::switchover(CalendarDate::new(1752, 9, 14)).unwrap();
+        assert_eq!(
+            britain.date_from_mjd(first_gregorian),
+            Ok(CalendarDate::new(1582, 10, 5))
+        );
+    }
+
+    #[test]
+    fn extreme_years_and_mjds_are_out_of_range() {
+        for calendar in [
+            Calendar::Julian,
+            Calendar::Gregorian,
+            Calendar::GREGORIAN_REFORM,
+        ] {
+            for year in [i64::MAX / 2, i64::MIN / 2, i64::MAX, i64::MIN] {
+                assert_eq!(
+                    calendar.mjd_from_date(CalendarDate::new(year, 6, 1)),
+                    Err(ConversionError::OutOfRange)
+                );
+            }
+            for mjd in [i64::MAX, i64::MIN] {
+                assert_eq!(
+                    calendar.date_from_mjd(mjd),
+                    Err(ConversionError::OutOfRange)
+                );
+            }
+            // The edges of the supported range round-trip.
+            for mjd in [-encoding::MAX_ABS_MJD, encoding::MAX_ABS_MJD] {
+                let date = calendar.date_from_mjd(mjd).unwrap();
+                assert_eq!(calendar.mjd_from_date(date), Ok(mjd));
+            }
+        }
+        assert_eq!(
+            Calendar::Gregorian.date_from_jd(Day::new(2e18)),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn julian_days_use_astronomical_years() {
+        // JD 0 is noon on -4712-01-01 Julian.
+        let date = Calendar::Julian.date_from_jd(Day::new(0.0)).unwrap();
+        assert_eq!(date, CalendarDate::new(-4_712, 1, 1));
+        assert_eq!(date.to_string(), "-4712-01-01");
+        assert_eq!(Calendar::Julian.jd_from_date(date).unwrap(), Day::new(-0.5));
+        assert_eq!(
+            Calendar::Gregorian.jd_from_date(CalendarDate::new(2000, 1, 1)),
+            Ok(Day::new(2_451_544.5))
+        );
+        assert_eq!(
+            Calendar::Gregorian.date_from_jd(Day::new(f64::NAN)),
+            Err(ConversionError::NonFinite)
+        );
+    }
+
+    #[test]
+    fn ancient_ut1_dates_convert_to_tt_with_delta_t() {
+        let date = CalendarDate::new(-500, 3, 1);
+        let ut1 =
+            Time::<UT1>::from_calendar_date(Calendar::Julian, date, ExactDuration::ZERO).unwrap();
+        assert_eq!(
+            ut1.to_calendar_date(Calendar::Julian),
+            Ok((date, ExactDuration::ZERO))
+        );
+
+        let tt = ut1.to_scale_with::<TT>(&TimeContext::new()).unwrap();
+        let delta_t = tt.to_exact_j2000().unwrap().as_nanos_i128()
+            - ut1.to_exact_j2000().unwrap().as_nanos_i128();
+        // ΔT was several hours in 500 BC.
+        assert!(delta_t > 3 * 3_600 * 1_000_000_000);
+        let (tt_date, _) = tt.to_calendar_date(Calendar::Julian).unwrap();
+        assert_eq!(tt_date, date);
+    }
+}
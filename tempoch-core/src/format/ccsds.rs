@@ -0,0 +1,865 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! CCSDS binary time codes (CCSDS 301.0-B-4 §3.2–3.3).
+//!
+//! - **CUC** (unsegmented): a binary count of seconds since an epoch
+//!   (1–7 coarse octets) and a binary fraction of a second (0–10 fine
+//!   octets). [`CucCodec`] counts elapsed seconds on the axis of `S`.
+//! - **CDS** (day segmented): a day count (16 or 24 bits), milliseconds of
+//!   day (32 bits), and an optional 16-bit microsecond or 32-bit picosecond
+//!   sub-field. [`CdsCodec`] labels days through [`CalendarScale`], so on
+//!   [`crate::UTC`] a leap-second day has 86 401 000 ms.
+//!
+//! Level-1 codes use the CCSDS epoch 1958-01-01T00:00:00 TAI; level-2 codes
+//! use an agency-defined epoch on any scale. Both codecs emit and accept the
+//! optional P-field (preamble) through [`CcsdsPField`].
+//!
+//! Encoding truncates to the field resolution, as a clock latching the code
+//! would; decoding rounds to the nearest nanosecond. Codes with at least four
+//! fine octets (CUC) or a picosecond field (CDS) therefore round-trip
+//! nanosecond instants exactly.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{CcsdsPField, CucCodec, ExactDuration, Time, TAI};
+//!
+//! let codec = CucCodec::level1(4, 2).unwrap().with_p_field(true);
+//! let epoch = Time::<TAI>::try_from_exact_j2000(ExactDuration::from_nanos(
+//!     -1_325_419_200 * 1_000_000_000,
+//! ))
+//! .unwrap();
+//! let t = epoch.try_add_exact(ExactDuration::from_nanos(1_500_000_000)).unwrap();
+//!
+//! let bytes = codec.encode(t).unwrap();
+//! assert_eq!(bytes, [0x1e, 0, 0, 0, 1, 0x80, 0]);
+//! let (decoded, used) = codec.decode(&bytes).unwrap();
+//! assert_eq!((decoded, used), (t, 7));
+//! assert!(matches!(CcsdsPField::parse(&bytes), Ok((CcsdsPField::Cuc { .. }, 1))));
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{CoordinateScale, TAI};
+use crate::model::time::Time;
+
+use super::mjd_sod::{CalendarScale, MjdSod};
+
+/// CCSDS level-1 epoch, 1958-01-01T00:00:00 TAI, as J2000 nanoseconds.
+const CCSDS_EPOCH_J2000_NANOS: i128 = -1_325_419_200 * NANOS_PER_SECOND;
+/// MJD of 1958-01-01.
+const CCSDS_EPOCH_MJD: i64 = 36_204;
+const NANOS_PER_MILLI: i128 = 1_000_000;
+
+const CODE_ID_CUC_LEVEL1: u8 = 0b001;
+const CODE_ID_CUC_LEVEL2: u8 = 0b010;
+const CODE_ID_CDS: u8 = 0b100;
+
+/// Error returned by the CCSDS codecs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CcsdsError {
+    /// The input ended before the P-field or T-field was complete.
+    Truncated,
+    /// The P-field uses a reserved value or a time code this module does
+    /// not implement (CCS, further extension octets).
+    UnsupportedPField,
+    /// The P-field describes a different time code or epoch level than the
+    /// codec decoding it.
+    PFieldMismatch,
+    /// Octet counts outside the ranges allowed by the standard.
+    InvalidLayout,
+    /// A T-field value is outside its sub-field range, or the instant does
+    /// not fit the configured fields.
+    OutOfRange,
+    /// The instant could not be converted on the codec's scale.
+    Conversion(ConversionError),
+}
+
+impl core::fmt::Display for CcsdsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("CCSDS time code is truncated"),
+            Self::UnsupportedPField => f.write_str("CCSDS P-field is reserved or unsupported"),
+            Self::PFieldMismatch => {
+                f.write_str("CCSDS P-field does not match the codec's time code and epoch")
+            }
+            Self::InvalidLayout => f.write_str("CCSDS octet counts are outside the standard"),
+            Self::OutOfRange => f.write_str("CCSDS time code value is out of range"),
+            Self::Conversion(err) => write!(f, "CCSDS time conversion failed: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for CcsdsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for CcsdsError {
+    fn from(err: ConversionError) -> Self {
+        Self::Conversion(err)
+    }
+}
+
+/// Epoch level of a CCSDS time code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CcsdsLevel {
+    /// CCSDS epoch 1958-01-01T00:00:00 TAI.
+    Level1,
+    /// Agency-defined epoch and time scale.
+    Level2,
+}
+
+/// Resolution of the CDS sub-millisecond field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CdsSubMillis {
+    /// No sub-millisecond field.
+    None,
+    /// 16-bit microseconds of millisecond.
+    Microseconds,
+    /// 32-bit picoseconds of millisecond.
+    Picoseconds,
+}
+
+impl CdsSubMillis {
+    const fn octets(self) -> usize {
+        match self {
+            Self::None => 0,
+            Self::Microseconds => 2,
+            Self::Picoseconds => 4,
+        }
+    }
+}
+
+/// Decoded CCSDS P-field (preamble).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CcsdsPField {
+    /// Unsegmented time code.
+    Cuc {
+        /// Epoch level (time code ID `001` or `010`).
+        level: CcsdsLevel,
+        /// Coarse (whole-second) octets, 1–7.
+        coarse_octets: u8,
+        /// Fine (fraction) octets, 0–10.
+        fine_octets: u8,
+    },
+    /// Day-segmented time code.
+    Cds {
+        /// Epoch level (epoch identification bit).
+        level: CcsdsLevel,
+        /// Day-segment octets, 2 or 3.
+        day_octets: u8,
+        /// Sub-millisecond field.
+        sub_millis: CdsSubMillis,
+    },
+}
+
+impl CcsdsPField {
+    /// Parse a P-field from the start of `bytes`, returning it and the number
+    /// of octets consumed (1 or 2).
+    pub fn parse(bytes: &[u8]) -> Result<(Self, usize), CcsdsError> {
+        let first = *bytes.first().ok_or(CcsdsError::Truncated)?;
+        let extended = first & 0x80 != 0;
+        match (first >> 4) & 0b111 {
+            id @ (CODE_ID_CUC_LEVEL1 | CODE_ID_CUC_LEVEL2) => {
+                let level = if id == CODE_ID_CUC_LEVEL1 {
+                    CcsdsLevel::Level1
+                } else {
+                    CcsdsLevel::Level2
+                };
+                let mut coarse_octets = ((first >> 2) & 0b11) + 1;
+                let mut fine_octets = first & 0b11;
+                let mut used = 1;
+                if extended {
+                    let second = *bytes.get(1).ok_or(CcsdsError::Truncated)?;
+                    if second & 0x80 != 0 {
+                        return Err(CcsdsError::UnsupportedPField);
+                    }
+                    coarse_octets += (second >> 5) & 0b11;
+                    fine_octets += (second >> 2) & 0b111;
+                    used = 2;
+                }
+                Ok((
+                    Self::Cuc {
+                        level,
+                        coarse_octets,
+                        fine_octets,
+                    },
+                    used,
+                ))
+            }
+            CODE_ID_CDS => {
+                if extended {
+                    return Err(CcsdsError::UnsupportedPField);
+                }
+                let sub_millis = match first & 0b11 {
+                    0b00 => CdsSubMillis::None,
+                    0b01 => CdsSubMillis::Microseconds,
+                    0b10 => CdsSubMillis::Picoseconds,
+                    _ => return Err(CcsdsError::UnsupportedPField),
+                };
+                let level = if first & 0b1000 == 0 {
+                    CcsdsLevel::Level1
+                } else {
+                    CcsdsLevel::Level2
+                };
+                let day_octets = if first & 0b100 == 0 { 2 } else { 3 };
+                Ok((
+                    Self::Cds {
+                        level,
+                        day_octets,
+                        sub_millis,
+                    },
+                    1,
+                ))
+            }
+            _ => Err(CcsdsError::UnsupportedPField),
+        }
+    }
+
+    /// Encode as one or two octets (CUC uses the extension octet only when
+    /// the octet counts need it).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match *self {
+            Self::Cuc {
+                level,
+                coarse_octets,
+                fine_octets,
+            } => {
+                let id = match level {
+                    CcsdsLevel::Level1 => CODE_ID_CUC_LEVEL1,
+                    CcsdsLevel::Level2 => CODE_ID_CUC_LEVEL2,
+                };
+                let (coarse_base, fine_base) = (coarse_octets.min(4), fine_octets.min(3));
+                let mut first = (id << 4) | ((coarse_base - 1) << 2) | fine_base;
+                if coarse_octets <= 4 && fine_octets <= 3 {
+                    return vec![first];
+                }
+                first |= 0x80;
+                let second =
+                    ((coarse_octets - coarse_base) << 5) | ((fine_octets - fine_base) << 2);
+                vec![first, second]
+            }
+            Self::Cds {
+                level,
+                day_octets,
+                sub_millis,
+            } => {
+                let mut octet = CODE_ID_CDS << 4;
+                if level == CcsdsLevel::Level2 {
+                    octet |= 0b1000;
+                }
+                if day_octets == 3 {
+                    octet |= 0b100;
+                }
+                octet |= match sub_millis {
+                    CdsSubMillis::None => 0b00,
+                    CdsSubMillis::Microseconds => 0b01,
+                    CdsSubMillis::Picoseconds => 0b10,
+                };
+                vec![octet]
+            }
+        }
+    }
+
+    /// Length of the T-field this P-field describes.
+    pub fn t_field_len(&self) -> usize {
+        match *self {
+            Self::Cuc {
+                coarse_octets,
+                fine_octets,
+                ..
+            } => coarse_octets as usize + fine_octets as usize,
+            Self::Cds {
+                day_octets,
+                sub_millis,
+                ..
+            } => day_octets as usize + 4 + sub_millis.octets(),
+        }
+    }
+
+    fn validate(&self) -> Result<(), CcsdsError> {
+        let valid = match *self {
+            Self::Cuc {
+                coarse_octets,
+                fine_octets,
+                ..
+            } => (1..=7).contains(&coarse_octets) && fine_octets <= 10,
+            Self::Cds { day_octets, .. } => day_octets == 2 || day_octets == 3,
+        };
+        if valid {
+            Ok(())
+        } else {
+            Err(CcsdsError::InvalidLayout)
+        }
+    }
+}
+
+/// Read a big-endian unsigned field.
+fn read_be(bytes: &[u8]) -> u128 {
+    bytes.iter().fold(0, |acc, &b| (acc << 8) | b as u128)
+}
+
+/// Append the low `len` octets of `value`, big-endian.
+fn write_be(out: &mut Vec<u8>, value: u128, len: usize) {
+    out.extend((0..len).rev().map(|i| (value >> (8 * i)) as u8));
+}
+
+/// Output buffer for `field`'s code, starting with the P-field if `include`.
+fn preamble(field: CcsdsPField, include: bool) -> Vec<u8> {
+    let mut out = Vec::with_capacity(1 + field.t_field_len());
+    if include {
+        out.extend(field.to_bytes());
+    }
+    out
+}
+
+/// Split a P-field-prefixed code and check it against the expected code.
+fn split_p_field(
+    bytes: &[u8],
+    matches: impl Fn(&CcsdsPField) -> bool,
+) -> Result<(CcsdsPField, &[u8], usize), CcsdsError> {
+    let (field, used) = CcsdsPField::parse(bytes)?;
+    if !matches(&field) {
+        return Err(CcsdsError::PFieldMismatch);
+    }
+    Ok((field, &bytes[used..], used))
+}
+
+/// CCSDS unsegmented time code (CUC) codec on scale `S`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CucCodec<S: CoordinateScale> {
+    epoch: Time<S>,
+    /// `epoch` as an exact offset from J2000.0 on the axis of `S`.
+    epoch_offset: ExactDuration,
+    level: CcsdsLevel,
+    coarse_octets: u8,
+    fine_octets: u8,
+    p_field: bool,
+}
+
+impl CucCodec<TAI> {
+    /// Level-1 codec (epoch 1958-01-01 TAI) with the given octet counts.
+    pub fn level1(coarse_octets: u8, fine_octets: u8) -> Result<Self, CcsdsError> {
+        let epoch_offset = ExactDuration::from_nanos(CCSDS_EPOCH_J2000_NANOS);
+        Self::build(
+            Time::try_from_exact_j2000(epoch_offset)?,
+            epoch_offset,
+            CcsdsLevel::Level1,
+            coarse_octets,
+            fine_octets,
+        )
+    }
+}
+
+impl<S: CoordinateScale> CucCodec<S> {
+    /// Level-2 codec counting from an agency-defined `epoch` on scale `S`.
+    pub fn agency(epoch: Time<S>, coarse_octets: u8, fine_octets: u8) -> Result<Self, CcsdsError> {
+        let epoch_offset = epoch.to_exact_j2000()?;
+        Self::build(
+            Time::try_from_exact_j2000(epoch_offset)?,
+            epoch_offset,
+            CcsdsLevel::Level2,
+            coarse_octets,
+            fine_octets,
+        )
+    }
+
+    fn build(
+        epoch: Time<S>,
+        epoch_offset: ExactDuration,
+        level: CcsdsLevel,
+        coarse_octets: u8,
+        fine_octets: u8,
+    ) -> Result<Self, CcsdsError> {
+        let codec = Self {
+            epoch,
+            epoch_offset,
+            level,
+            coarse_octets,
+            fine_octets,
+            p_field: false,
+        };
+        codec.p_field().validate()?;
+        Ok(codec)
+    }
+
+    /// Epoch instant (count zero), rounded to the nearest nanosecond.
+    #[inline]
+    pub fn epoch(&self) -> Time<S> {
+        self.epoch
+    }
+
+    /// P-field describing this codec.
+    pub fn p_field(&self) -> CcsdsPField {
+        CcsdsPField::Cuc {
+            level: self.level,
+            coarse_octets: self.coarse_octets,
+            fine_octets: self.fine_octets,
+        }
+    }
+
+    /// Emit a P-field before each T-field and expect one when decoding.
+    ///
+    /// The decoded octet counts are then taken from the P-field, which must
+    /// describe a CUC code at this codec's epoch level.
+    pub fn with_p_field(mut self, include: bool) -> Self {
+        self.p_field = include;
+        self
+    }
+
+    /// Whether codes carry a P-field.
+    #[inline]
+    pub fn includes_p_field(&self) -> bool {
+        self.p_field
+    }
+
+    /// Encode the T-field.
+    ///
+    /// Instants before the epoch or beyond the coarse range return
+    /// [`CcsdsError::OutOfRange`].
+    pub fn encode(&self, time: Time<S>) -> Result<Vec<u8>, CcsdsError> {
+        let elapsed = time.to_exact_j2000()?.as_nanos_i128() - self.epoch_offset.as_nanos_i128();
+        if elapsed < 0 {
+            return Err(CcsdsError::OutOfRange);
+        }
+        let seconds = (elapsed / NANOS_PER_SECOND) as u128;
+        if seconds >> (8 * self.coarse_octets as u32) != 0 {
+            return Err(CcsdsError::OutOfRange);
+        }
+        let sub_nanos = (elapsed % NANOS_PER_SECOND) as u128;
+        let fine = (sub_nanos << (8 * self.fine_octets as u32)) / NANOS_PER_SECOND as u128;
+        let mut out = preamble(self.p_field(), self.p_field);
+        write_be(&mut out, seconds, self.coarse_octets as usize);
+        write_be(&mut out, fine, self.fine_octets as usize);
+        Ok(out)
+    }
+
+    /// Decode a code from the start of `bytes`, returning the instant and
+    /// the number of octets consumed (P-field included).
+    pub fn decode(&self, bytes: &[u8]) -> Result<(Time<S>, usize), CcsdsError> {
+        if !self.p_field {
+            return self.decode_layout(bytes, self.coarse_octets, self.fine_octets);
+        }
+        let (field, rest, used) = split_p_field(
+            bytes,
+            |field| matches!(field, CcsdsPField::Cuc { level, .. } if *level == self.level),
+        )?;
+        let CcsdsPField::Cuc {
+            coarse_octets,
+            fine_octets,
+            ..
+        } = field
+        else {
+            unreachable!("split_p_field checked the code type");
+        };
+        let (time, t_len) = self.decode_layout(rest, coarse_octets, fine_octets)?;
+        Ok((time, used + t_len))
+    }
+
+    fn decode_layout(
+        &self,
+        bytes: &[u8],
+        coarse_octets: u8,
+        fine_octets: u8,
+    ) -> Result<(Time<S>, usize), CcsdsError> {
+        let (coarse_len, fine_len) = (coarse_octets as usize, fine_octets as usize);
+        let t_field = bytes
+            .get(..coarse_len + fine_len)
+            .ok_or(CcsdsError::Truncated)?;
+        let seconds = read_be(&t_field[..coarse_len]) as i128;
+        let fine = read_be(&t_field[coarse_len..]);
+        let fine_bits = 8 * fine_octets as u32;
+        let nanos = if fine_bits == 0 {
+            0
+        } else {
+            ((fine * NANOS_PER_SECOND as u128 + (1 << (fine_bits - 1))) >> fine_bits) as i128
+        };
+        let offset = self.epoch_offset.as_nanos_i128() + seconds * NANOS_PER_SECOND + nanos;
+        let time = Time::try_from_exact_j2000(ExactDuration::from_nanos(offset))?;
+        Ok((time, t_field.len()))
+    }
+}
+
+/// CCSDS day-segmented time code (CDS) codec on scale `S`.
+///
+/// Days and milliseconds of day are calendar labels on `S` (see
+/// [`CalendarScale`]); the epoch is midnight at the start of an MJD day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CdsCodec<S: CalendarScale> {
+    epoch_mjd: i64,
+    level: CcsdsLevel,
+    day_octets: u8,
+    sub_millis: CdsSubMillis,
+    p_field: bool,
+    _scale: core::marker::PhantomData<S>,
+}
+
+impl CdsCodec<TAI> {
+    /// Level-1 codec (epoch 1958-01-01 TAI).
+    ///
+    /// `day_octets` is 2 or 3.
+    pub fn level1(day_octets: u8, sub_millis: CdsSubMillis) -> Result<Self, CcsdsError> {
+        Self::build(CCSDS_EPOCH_MJD, CcsdsLevel::Level1, day_octets, sub_millis)
+    }
+}
+
+impl<S: CalendarScale> CdsCodec<S> {
+    /// Level-2 codec counting days from midnight of `epoch_mjd` on scale `S`.
+    pub fn agency(
+        epoch_mjd: qtty::i64::Day,
+        day_octets: u8,
+        sub_millis: CdsSubMillis,
+    ) -> Result<Self, CcsdsError> {
+        Self::build(
+            epoch_mjd.value(),
+            CcsdsLevel::Level2,
+            day_octets,
+            sub_millis,
+        )
+    }
+
+    fn build(
+        epoch_mjd: i64,
+        level: CcsdsLevel,
+        day_octets: u8,
+        sub_millis: CdsSubMillis,
+    ) -> Result<Self, CcsdsError> {
+        let codec = Self {
+            epoch_mjd,
+            level,
+            day_octets,
+            sub_millis,
+            p_field: false,
+            _scale: core::marker::PhantomData,
+        };
+        codec.p_field().validate()?;
+        Ok(codec)
+    }
+
+    /// MJD of the epoch day.
+    #[inline]
+    pub fn epoch_mjd(&self) -> qtty::i64::Day {
+        qtty::i64::Day::new(self.epoch_mjd)
+    }
+
+    /// P-field describing this codec.
+    pub fn p_field(&self) -> CcsdsPField {
+        CcsdsPField::Cds {
+            level: self.level,
+            day_octets: self.day_octets,
+            sub_millis: self.sub_millis,
+        }
+    }
+
+    /// Emit a P-field before each T-field and expect one when decoding.
+    ///
+    /// The decoded field layout is then taken from the P-field, which must
+    /// describe a CDS code at this codec's epoch level.
+    pub fn with_p_field(mut self, include: bool) -> Self {
+        self.p_field = include;
+        self
+    }
+
+    /// Whether codes carry a P-field.
+    #[inline]
+    pub fn includes_p_field(&self) -> bool {
+        self.p_field
+    }
+
+    /// Encode the code (P-field first if enabled).
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    pub fn encode(&self, time: Time<S>) -> Result<Vec<u8>, CcsdsError> {
+        self.encode_with(time, &TimeContext::new())
+    }
+
+    /// Like [`Self::encode`], using `ctx` for calendar labels.
+    pub fn encode_with(&self, time: Time<S>, ctx: &TimeContext) -> Result<Vec<u8>, CcsdsError> {
+        let label = time.to_mjd_sod_with(ctx)?;
+        let day = label.mjd.value() - self.epoch_mjd;
+        if day < 0 || day >> (8 * self.day_octets as u32) != 0 {
+            return Err(CcsdsError::OutOfRange);
+        }
+        let nanos_of_day = label.second_of_day.as_nanos_i128();
+        let millis = nanos_of_day / NANOS_PER_MILLI;
+        let sub_nanos = nanos_of_day % NANOS_PER_MILLI;
+        let mut out = preamble(self.p_field(), self.p_field);
+        write_be(&mut out, day as u128, self.day_octets as usize);
+        write_be(&mut out, millis as u128, 4);
+        match self.sub_millis {
+            CdsSubMillis::None => {}
+            CdsSubMillis::Microseconds => write_be(&mut out, (sub_nanos / 1_000) as u128, 2),
+            CdsSubMillis::Picoseconds => write_be(&mut out, (sub_nanos * 1_000) as u128, 4),
+        }
+        Ok(out)
+    }
+
+    /// Decode a code from the start of `bytes`, returning the instant and
+    /// the number of octets consumed (P-field included).
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    pub fn decode(&self, bytes: &[u8]) -> Result<(Time<S>, usize), CcsdsError> {
+        self.decode_with(bytes, &TimeContext::new())
+    }
+
+    /// Like [`Self::decode`], using `ctx` for calendar labels.
+    pub fn decode_with(
+        &self,
+        bytes: &[u8],
+        ctx: &TimeContext,
+    ) -> Result<(Time<S>, usize), CcsdsError> {
+        if !self.p_field {
+            return self.decode_layout(bytes, self.day_octets, self.sub_millis, ctx);
+        }
+        let (field, rest, used) = split_p_field(
+            bytes,
+            |field| matches!(field, CcsdsPField::Cds { level, .. } if *level == self.level),
+        )?;
+        let CcsdsPField::Cds {
+            day_octets,
+            sub_millis,
+            ..
+        } = field
+        else {
+            unreachable!("split_p_field checked the code type");
+        };
+        let (time, t_len) = self.decode_layout(rest, day_octets, sub_millis, ctx)?;
+        Ok((time, used + t_len))
+    }
+
+    fn decode_layout(
+        &self,
+        bytes: &[u8],
+        day_octets: u8,
+        sub_millis: CdsSubMillis,
+        ctx: &TimeContext,
+    ) -> Result<(Time<S>, usize), CcsdsError> {
+        let day_len = day_octets as usize;
+        let t_field = bytes
+            .get(..day_len + 4 + sub_millis.octets())
+            .ok_or(CcsdsError::Truncated)?;
+        let day = read_be(&t_field[..day_len]) as i64;
+        let millis = read_be(&t_field[day_len..day_len + 4]) as i128;
+        let sub = read_be(&t_field[day_len + 4..]) as i128;
+        let sub_nanos = match sub_millis {
+            CdsSubMillis::None => 0,
+            CdsSubMillis::Microseconds if sub < 1_000 => sub * 1_000,
+            CdsSubMillis::Picoseconds if sub < 1_000_000_000 => (sub + 500) / 1_000,
+            _ => return Err(CcsdsError::OutOfRange),
+        };
+        let label = MjdSod::new(
+            qtty::i64::Day::new(self.epoch_mjd + day),
+            ExactDuration::from_nanos(millis * NANOS_PER_MILLI + sub_nanos),
+        )
+        .map_err(|_| CcsdsError::OutOfRange)?;
+        let time = Time::from_mjd_sod_with(label, ctx)?;
+        Ok((time, t_field.len()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TT, UTC};
+
+    fn tai_since_1958(nanos: i128) -> Time<TAI> {
+        Time::try_from_exact_j2000(ExactDuration::from_nanos(CCSDS_EPOCH_J2000_NANOS + nanos))
+            .unwrap()
+    }
+
+    #[test]
+    fn p_field_round_trips_standard_layouts() {
+        let cases = [
+            (
+                CcsdsPField::Cuc {
+                    level: CcsdsLevel::Level1,
+                    coarse_octets: 4,
+                    fine_octets: 3,
+                },
+                vec![0x1f],
+            ),
+            (
+                CcsdsPField::Cuc {
+                    level: CcsdsLevel::Level2,
+                    coarse_octets: 6,
+                    fine_octets: 5,
+                },
+                vec![0xaf, 0x48],
+            ),
+            (
+                CcsdsPField::Cds {
+                    level: CcsdsLevel::Level1,
+                    day_octets: 2,
+                    sub_millis: CdsSubMillis::Microseconds,
+                },
+                vec![0x41],
+            ),
+            (
+                CcsdsPField::Cds {
+                    level: CcsdsLevel::Level2,
+                    day_octets: 3,
+                    sub_millis: CdsSubMillis::Picoseconds,
+                },
+                vec![0x4e],
+            ),
+        ];
+        for (field, bytes) in cases {
+            assert_eq!(field.to_bytes(), bytes);
+            assert_eq!(CcsdsPField::parse(&bytes).unwrap(), (field, bytes.len()));
+        }
+    }
+
+    #[test]
+    fn p_field_rejects_reserved_and_truncated_input() {
+        assert_eq!(CcsdsPField::parse(&[]), Err(CcsdsError::Truncated));
+        assert_eq!(CcsdsPField::parse(&[0x9f]), Err(CcsdsError::Truncated));
+        // CCS (101) and CDS reserved sub-ms code.
+        assert_eq!(
+            CcsdsPField::parse(&[0x50]),
+            Err(CcsdsError::UnsupportedPField)
+        );
+        assert_eq!(
+            CcsdsPField::parse(&[0x43]),
+            Err(CcsdsError::UnsupportedPField)
+        );
+        // A further extension octet.
+        assert_eq!(
+            CcsdsPField::parse(&[0x9f, 0x80]),
+            Err(CcsdsError::UnsupportedPField)
+        );
+        assert_eq!(CucCodec::level1(0, 0), Err(CcsdsError::InvalidLayout));
+        assert_eq!(
+            CdsCodec::level1(4, CdsSubMillis::None),
+            Err(CcsdsError::InvalidLayout)
+        );
+    }
+
+    #[test]
+    fn cuc_level1_encodes_seconds_and_binary_fraction() {
+        let codec = CucCodec::level1(4, 4).unwrap();
+        let t = tai_since_1958(2_000_000_000 * NANOS_PER_SECOND + 123_456_789);
+        let bytes = codec.encode(t).unwrap();
+        assert_eq!(&bytes[..4], &2_000_000_000_u32.to_be_bytes());
+        assert_eq!(
+            read_be(&bytes[4..]),
+            (123_456_789_u128 << 32) / 1_000_000_000
+        );
+        assert_eq!(codec.decode(&bytes).unwrap(), (t, 8));
+        assert_eq!(codec.decode(&bytes[..7]), Err(CcsdsError::Truncated));
+    }
+
+    #[test]
+    fn cuc_extended_layout_and_range() {
+        let codec = CucCodec::level1(7, 10).unwrap().with_p_field(true);
+        let t = tai_since_1958(3 * NANOS_PER_SECOND + 1);
+        let bytes = codec.encode(t).unwrap();
+        assert_eq!(bytes.len(), 2 + 17);
+        assert_eq!(codec.decode(&bytes).unwrap(), (t, 19));
+
+        let narrow = CucCodec::level1(1, 0).unwrap();
+        assert_eq!(
+            narrow.encode(tai_since_1958(256 * NANOS_PER_SECOND)),
+            Err(CcsdsError::OutOfRange)
+        );
+        assert_eq!(
+            narrow.encode(tai_since_1958(-1)),
+            Err(CcsdsError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn cuc_agency_epoch_on_tt() {
+        let epoch = Time::<TT>::try_from_exact_j2000(ExactDuration::from_nanos(
+            315_576_000 * NANOS_PER_SECOND,
+        ))
+        .unwrap();
+        let codec = CucCodec::agency(epoch, 4, 2).unwrap().with_p_field(true);
+        let t = epoch
+            .try_add_exact(ExactDuration::from_nanos(90_500_000_000))
+            .unwrap();
+        let bytes = codec.encode(t).unwrap();
+        assert_eq!(bytes, [0x2e, 0, 0, 0, 90, 0x80, 0]);
+        assert_eq!(codec.decode(&bytes).unwrap(), (t, 7));
+        // A level-1 P-field does not describe this codec.
+        let mut level1 = bytes.clone();
+        level1[0] = 0x1e;
+        assert_eq!(codec.decode(&level1), Err(CcsdsError::PFieldMismatch));
+    }
+
+    #[test]
+    fn cds_level1_fields() {
+        let codec = CdsCodec::level1(2, CdsSubMillis::Microseconds)
+            .unwrap()
+            .with_p_field(true);
+        let t = tai_since_1958(
+            (21_915 * 86_400 + 3_600) * NANOS_PER_SECOND + 250 * NANOS_PER_MILLI + 7_999,
+        );
+        let bytes = codec.encode(t).unwrap();
+        // P-field, day 21915 (2018-01-01), 3 600 250 ms, 7 µs (truncated).
+        assert_eq!(
+            bytes,
+            [0x41, 0x55, 0x9b, 0x00, 0x36, 0xef, 0x7a, 0x00, 0x07]
+        );
+        let (decoded, used) = codec.decode(&bytes).unwrap();
+        assert_eq!(used, 9);
+        assert_eq!(
+            t.to_exact_j2000().unwrap().as_nanos_i128()
+                - decoded.to_exact_j2000().unwrap().as_nanos_i128(),
+            999
+        );
+    }
+
+    #[test]
+    fn cds_picoseconds_round_trip_nanoseconds() {
+        let codec = CdsCodec::level1(3, CdsSubMillis::Picoseconds).unwrap();
+        let t = tai_since_1958(12_345 * 86_400 * NANOS_PER_SECOND + 86_399_999_999_999);
+        let bytes = codec.encode(t).unwrap();
+        assert_eq!(bytes.len(), 3 + 4 + 4);
+        assert_eq!(codec.decode(&bytes).unwrap(), (t, 11));
+        let mut bad = bytes.clone();
+        bad[7..].copy_from_slice(&1_000_000_000_u32.to_be_bytes());
+        assert_eq!(codec.decode(&bad), Err(CcsdsError::OutOfRange));
+    }
+
+    #[test]
+    fn cds_on_utc_counts_the_leap_millisecond_range() {
+        // Agency epoch 2000-01-01 UTC; 2016-12-31 ends in a leap second.
+        let codec =
+            CdsCodec::<UTC>::agency(qtty::i64::Day::new(51_544), 2, CdsSubMillis::None).unwrap();
+        let leap = Time::<UTC>::from_mjd_sod(
+            MjdSod::new(
+                qtty::i64::Day::new(57_753),
+                ExactDuration::from_nanos(86_400_500 * NANOS_PER_MILLI),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let bytes = codec.encode(leap).unwrap();
+        assert_eq!(read_be(&bytes[..2]), 57_753 - 51_544);
+        assert_eq!(read_be(&bytes[2..6]), 86_400_500);
+        assert_eq!(codec.decode(&bytes).unwrap(), (leap, 6));
+        let ctx = TimeContext::new();
+        let framing = codec.with_p_field(true);
+        assert!(framing.includes_p_field() && !codec.includes_p_field());
+        let framed = framing.encode_with(leap, &ctx).unwrap();
+        assert_eq!(&framed[framed.len() - 6..], &bytes[..]);
+        assert_eq!(
+            framing.decode_with(&framed, &ctx).unwrap(),
+            (leap, framed.len())
+        );
+        // The same millisecond count is invalid on an ordinary day.
+        let mut ordinary = bytes.clone();
+        ordinary[..2].copy_from_slice(&1_u16.to_be_bytes());
+        assert_eq!(
+            codec.decode(&ordinary),
+            Err(CcsdsError::Conversion(ConversionError::InvalidLeapSecond))
+        );
+    }
+}
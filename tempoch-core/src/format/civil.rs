@@ -0,0 +1,294 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Native civil decomposition of an instant on any calendar scale.
+//!
+//! [`CivilDateTime`] holds the proleptic Gregorian year, month, day, hour,
+//! minute, second, and nanosecond of a label, and derives the day of year,
+//! ISO week, and weekday from them. Unlike `chrono`, it represents the leap
+//! second `23:59:60`.
+//!
+//! [`Time::to_civil`] reads the label through [`crate::MjdSod`]: for
+//! `Time<UTC>` from the UTC-TAI table, and for continuous scales from the
+//! raw axis. [`Time::from_civil`] is the exact, fallible inverse.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{CivilDateTime, Time, UTC};
+//!
+//! let leap = CivilDateTime::new(2016, 12, 31, 23, 59, 60, 500_000_000).unwrap();
+//! let t = Time::<UTC>::from_civil(leap).unwrap();
+//! let civil = t.to_civil().unwrap();
+//! assert_eq!(civil, leap);
+//! assert_eq!(civil.day_of_year(), 366);
+//! assert_eq!(civil.iso_week(), (2016, 52));
+//! assert_eq!(civil.weekday(), 6);
+//! assert_eq!(civil.to_string(), "2016-12-31T23:59:60.500000000");
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+
+use super::iso::time_from_clock;
+use super::CalendarScale;
+
+/// Proleptic Gregorian civil label of an instant on one scale.
+///
+/// `second` may be 60 during a leap second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CivilDateTime {
+    /// Astronomical year (year 0 = 1 BC).
+    pub year: i64,
+    /// Month, 1–12.
+    pub month: u32,
+    /// Day of month, 1–31.
+    pub day: u32,
+    /// Hour, 0–23.
+    pub hour: u32,
+    /// Minute, 0–59.
+    pub minute: u32,
+    /// Second, 0–60.
+    pub second: u32,
+    /// Nanoseconds within the second.
+    pub nanosecond: u32,
+}
+
+impl CivilDateTime {
+    /// Validate and build a label. Returns [`ConversionError::OutOfRange`]
+    /// for an invalid date, a year beyond ±2⁵², an invalid clock field or
+    /// nanosecond count, or for second 60 outside `23:59`.
+    ///
+    /// Whether a leap second exists on that day is checked by
+    /// [`Time::from_civil`].
+    pub fn new(
+        year: i64,
+        month: u32,
+        day: u32,
+        hour: u32,
+        minute: u32,
+        second: u32,
+        nanosecond: u32,
+    ) -> Result<Self, ConversionError> {
+        let civil = Self {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+        };
+        civil.mjd()?;
+        Ok(civil)
+    }
+
+    /// Modified Julian Day of the date, after validating every field.
+    fn mjd(&self) -> Result<i64, ConversionError> {
+        let mjd = encoding::mjd_from_gregorian(self.year, self.month, self.day)
+            .ok_or(ConversionError::OutOfRange)?;
+        let leap_ok = self.second < 60 || (self.hour == 23 && self.minute == 59);
+        if self.hour >= 24
+            || self.minute >= 60
+            || self.second > 60
+            || !leap_ok
+            || self.nanosecond >= 1_000_000_000
+        {
+            return Err(ConversionError::OutOfRange);
+        }
+        Ok(mjd)
+    }
+
+    /// Day of year, 1–366.
+    pub fn day_of_year(&self) -> u32 {
+        let days_before: u32 = (1..self.month)
+            .map(|month| encoding::days_in_month(self.year, month))
+            .sum();
+        days_before + self.day
+    }
+
+    /// ISO 8601 week-numbering year and week, 1–53.
+    pub fn iso_week(&self) -> (i64, u32) {
+        encoding::iso_week(self.mjd_unchecked())
+    }
+
+    /// ISO 8601 weekday, Monday = 1 to Sunday = 7.
+    pub fn weekday(&self) -> u32 {
+        encoding::iso_weekday(self.mjd_unchecked())
+    }
+
+    /// Elapsed time since the start of the day, including a leap second.
+    pub fn second_of_day(&self) -> ExactDuration {
+        let seconds = (self.hour * 3_600 + self.minute * 60 + self.second) as i128;
+        ExactDuration::from_nanos(seconds * NANOS_PER_SECOND + self.nanosecond as i128)
+    }
+
+    /// MJD of a possibly out-of-range date, clamping the day to the month.
+    fn mjd_unchecked(&self) -> i64 {
+        let month = self.month.clamp(1, 12);
+        let day = self.day.clamp(1, encoding::days_in_month(self.year, month));
+        encoding::mjd_from_gregorian(self.year, month, day).unwrap_or_default()
+    }
+}
+
+impl core::fmt::Display for CivilDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )?;
+        if self.nanosecond != 0 {
+            write!(f, ".{:09}", self.nanosecond)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: CalendarScale> Time<S> {
+    /// Civil label of this instant on its own scale.
+    #[inline]
+    pub fn to_civil(&self) -> Result<CivilDateTime, ConversionError> {
+        self.to_civil_with(&TimeContext::new())
+    }
+
+    /// Like [`to_civil`](Self::to_civil), with an explicit [`TimeContext`].
+    pub fn to_civil_with(&self, ctx: &TimeContext) -> Result<CivilDateTime, ConversionError> {
+        let label = self.to_mjd_sod_with(ctx)?;
+        let nanos = label.second_of_day.as_nanos_i128();
+        let seconds = (nanos / NANOS_PER_SECOND) as u32;
+        let (year, month, day) = encoding::gregorian_from_mjd(label.mjd.value());
+        let (hour, minute, second) = if seconds >= 86_400 {
+            (23, 59, seconds - 86_340)
+        } else {
+            (seconds / 3_600, seconds / 60 % 60, seconds % 60)
+        };
+        Ok(CivilDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond: (nanos % NANOS_PER_SECOND) as u32,
+        })
+    }
+
+    /// Like [`to_civil_with`](Self::to_civil_with), for targets that cannot
+    /// label `23:59:60`: a leap second is
+    /// [`ConversionError::InvalidLeapSecond`].
+    #[cfg(any(feature = "time", feature = "jiff"))]
+    pub(crate) fn civil_without_leap_with(
+        &self,
+        ctx: &TimeContext,
+    ) -> Result<CivilDateTime, ConversionError> {
+        let civil = self.to_civil_with(ctx)?;
+        if civil.second == 60 {
+            return Err(ConversionError::InvalidLeapSecond);
+        }
+        Ok(civil)
+    }
+
+    /// Instant labelled `civil` on this scale.
+    ///
+    /// Second 60 is accepted only on a UTC day that ends with an announced
+    /// positive leap second; otherwise it is
+    /// [`ConversionError::InvalidLeapSecond`] on UTC and
+    /// [`ConversionError::OutOfRange`] on continuous scales.
+    #[inline]
+    pub fn from_civil(civil: CivilDateTime) -> Result<Self, ConversionError> {
+        Self::from_civil_with(civil, &TimeContext::new())
+    }
+
+    /// Like [`from_civil`](Self::from_civil), with an explicit
+    /// [`TimeContext`].
+    pub fn from_civil_with(
+        civil: CivilDateTime,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        let mjd = civil.mjd()?;
+        time_from_clock(
+            mjd,
+            [civil.hour, civil.minute, civil.second],
+            civil.nanosecond,
+            ctx,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::scale::{TAI, TT, UTC};
+
+    #[test]
+    fn utc_civil_labels_include_the_leap_second() {
+        let before = Time::<UTC>::parse_ccsds_ascii("2016-12-31T23:59:59.75").unwrap();
+        let leap = before
+            .try_add_exact(ExactDuration::from_nanos(500_000_000))
+            .unwrap();
+        let civil = leap.to_civil().unwrap();
+        assert_eq!(
+            civil,
+            CivilDateTime::new(2016, 12, 31, 23, 59, 60, 250_000_000).unwrap()
+        );
+        assert_eq!(civil.second_of_day().as_nanos_i128(), 86_400_250_000_000);
+        assert_eq!(Time::<UTC>::from_civil(civil).unwrap(), leap);
+
+        let next = leap
+            .try_add_exact(ExactDuration::SECOND)
+            .unwrap()
+            .to_civil()
+            .unwrap();
+        assert_eq!(
+            (next.year, next.month, next.day, next.second),
+            (2017, 1, 1, 0)
+        );
+        assert_eq!(next.iso_week(), (2016, 52));
+        assert_eq!(next.weekday(), 7);
+        assert_eq!(next.day_of_year(), 1);
+    }
+
+    #[test]
+    fn continuous_scales_decompose_the_raw_axis() {
+        let civil = CivilDateTime::new(2024, 12, 30, 12, 0, 0, 0).unwrap();
+        let tt = Time::<TT>::from_civil(civil).unwrap();
+        assert_eq!(tt.to_civil().unwrap(), civil);
+        assert_eq!(civil.iso_week(), (2025, 1));
+        assert_eq!(civil.day_of_year(), 365);
+        let tai = Time::<TAI>::from_civil(civil).unwrap();
+        assert_eq!(tt.to_civil().unwrap(), tai.to_civil().unwrap());
+
+        let leap = CivilDateTime {
+            second: 60,
+            hour: 23,
+            minute: 59,
+            ..civil
+        };
+        assert_eq!(
+            Time::<TT>::from_civil(leap),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn invalid_fields_are_rejected() {
+        assert!(CivilDateTime::new(2023, 2, 29, 0, 0, 0, 0).is_err());
+        assert!(CivilDateTime::new(2024, 1, 1, 12, 0, 60, 0).is_err());
+        assert!(CivilDateTime::new(2024, 1, 1, 0, 0, 0, 1_000_000_000).is_err());
+        for year in [i64::MAX, i64::MIN] {
+            assert_eq!(
+                CivilDateTime::new(year, 1, 1, 0, 0, 0, 0),
+                Err(ConversionError::OutOfRange)
+            );
+        }
+        let no_leap = CivilDateTime::new(2024, 12, 31, 23, 59, 60, 0).unwrap();
+        assert_eq!(
+            Time::<UTC>::from_civil(no_leap),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+    }
+}
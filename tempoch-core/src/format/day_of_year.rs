@@ -0,0 +1,406 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Day-of-year timestamps (`YYYY-DDDThh:mm:ss`, `YYYY:DDD:hh:mm:ss`, …).
+//!
+//! Flight software, SPICE, and ground-station logs label instants by year and
+//! day of year in several punctuation variants. The parser and formatter work
+//! on any [`CalendarScale`]: on [`UTC`](crate::UTC) days come from the active UTC-TAI
+//! table, so `23:59:60[.x]` is accepted and emitted during announced positive
+//! leap seconds exactly as in [`Time::<UTC>::parse_rfc3339`]; other scales
+//! use uniform 86 400 s days and are labelled with their name
+//! (`2024-166T12:00:00 TDB`).
+//!
+//! Two parsing profiles are available:
+//!
+//! - [`DayOfYearProfile::Strict`] accepts exactly the four-digit-year forms
+//!   produced by [`DayOfYearStyle::Iso`], [`DayOfYearStyle::Slash`], and
+//!   [`DayOfYearStyle::Colon`], with a full `hh:mm:ss` clock. UTC accepts an
+//!   optional `Z`; other scales require their ` NAME` suffix.
+//! - [`DayOfYearProfile::Lenient`] also accepts two-digit years (POSIX `%y`
+//!   pivot: `69`–`99` → 1969–1999, `00`–`68` → 2000–2068), one- to
+//!   three-digit days, any of `-`, `:`, `/` after the year, `T`, `/`, `:`, or
+//!   spaces before the clock, a clock truncated to `hh` or `hh:mm` or omitted
+//!   entirely, surrounding whitespace, and an optional case-insensitive scale
+//!   name (`UTC` or `Z` on UTC).
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{DayOfYearProfile, DayOfYearStyle, FormatOptions, Time, TDB, UTC};
+//!
+//! let t = Time::<UTC>::parse_day_of_year("24/166 12:00", DayOfYearProfile::Lenient).unwrap();
+//! assert_eq!(
+//!     t.format_day_of_year(DayOfYearStyle::Colon, FormatOptions::milliseconds()),
+//!     "2024:166:12:00:00.000Z"
+//! );
+//!
+//! let tdb = Time::<TDB>::parse_day_of_year("2024-166T12:00:00 TDB", DayOfYearProfile::Strict)
+//!     .unwrap();
+//! assert_eq!(
+//!     tdb.format_day_of_year(DayOfYearStyle::Slash, FormatOptions::SECONDS),
+//!     "2024-166/12:00:00 TDB"
+//! );
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+
+use super::iso::{parse_digits, parse_fraction_nanos, rounded_label, time_from_clock};
+use super::{CalendarScale, FormatOptions};
+
+/// Parsing profile for day-of-year timestamps. See the [module docs](self).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOfYearProfile {
+    /// Canonical four-digit-year forms only.
+    Strict,
+    /// Common punctuation variants, two-digit years, and truncated clocks.
+    Lenient,
+}
+
+/// Output punctuation for day-of-year timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayOfYearStyle {
+    /// `2024-166T12:00:00` (ISO 8601 ordinal date).
+    Iso,
+    /// `2024-166/12:00:00` (NASA/SPICE).
+    Slash,
+    /// `2024:166:12:00:00` (flight software and ground logs).
+    Colon,
+    /// `24/166 12:00:00`; years outside 1969–2068 are rejected so the output
+    /// re-parses under [`DayOfYearProfile::Lenient`].
+    TwoDigitYear,
+}
+
+/// Fields of a parsed day-of-year timestamp.
+struct Fields {
+    year: i64,
+    ordinal: u32,
+    clock: [u32; 3],
+    frac_nanos: u32,
+}
+
+/// Strip the scale designator, returning the remaining timestamp.
+fn strip_scale<S: CalendarScale>(
+    s: &str,
+    profile: DayOfYearProfile,
+) -> Result<&str, ConversionError> {
+    let is_utc = S::IS_UTC;
+    match profile {
+        DayOfYearProfile::Strict if is_utc => Ok(s.strip_suffix('Z').unwrap_or(s)),
+        DayOfYearProfile::Strict => s
+            .strip_suffix(S::NAME)
+            .and_then(|rest| rest.strip_suffix(' '))
+            .ok_or(ConversionError::OutOfRange),
+        DayOfYearProfile::Lenient => {
+            let s = s.trim();
+            let split = s.len().saturating_sub(S::NAME.len());
+            let s = match s.get(split..) {
+                Some(tail) if tail.eq_ignore_ascii_case(S::NAME) => s[..split].trim_end(),
+                _ => s,
+            };
+            Ok(if is_utc {
+                s.strip_suffix(['Z', 'z']).unwrap_or(s)
+            } else {
+                s
+            })
+        }
+    }
+}
+
+fn parse_strict(s: &str) -> Result<Fields, ConversionError> {
+    let b = s.as_bytes();
+    if !s.is_ascii() || b.len() < 17 {
+        return Err(ConversionError::OutOfRange);
+    }
+    if !matches!((b[4], b[8]), (b'-', b'T') | (b'-', b'/') | (b':', b':')) {
+        return Err(ConversionError::OutOfRange);
+    }
+    let (clock, frac) = match s[9..].split_once('.') {
+        Some((_, "")) => return Err(ConversionError::OutOfRange),
+        Some((clock, frac)) => (clock, frac),
+        None => (&s[9..], ""),
+    };
+    let cb = clock.as_bytes();
+    if cb.len() != 8 || cb[2] != b':' || cb[5] != b':' {
+        return Err(ConversionError::OutOfRange);
+    }
+    Ok(Fields {
+        year: parse_digits(&s[..4])? as i64,
+        ordinal: parse_digits(&s[5..8])?,
+        clock: [
+            parse_digits(&clock[..2])?,
+            parse_digits(&clock[3..5])?,
+            parse_digits(&clock[6..])?,
+        ],
+        frac_nanos: parse_fraction_nanos(frac)?,
+    })
+}
+
+/// Split a leading run of ASCII digits off `s`.
+fn take_digits(s: &str) -> (&str, &str) {
+    let end = s
+        .bytes()
+        .position(|b| !b.is_ascii_digit())
+        .unwrap_or(s.len());
+    s.split_at(end)
+}
+
+fn parse_lenient(s: &str) -> Result<Fields, ConversionError> {
+    let (year, rest) = take_digits(s);
+    let year = match year.len() {
+        4 => parse_digits(year)? as i64,
+        2 => match parse_digits(year)? as i64 {
+            yy @ 69.. => 1900 + yy,
+            yy => 2000 + yy,
+        },
+        _ => return Err(ConversionError::OutOfRange),
+    };
+    let rest = rest
+        .strip_prefix(['-', ':', '/'])
+        .ok_or(ConversionError::OutOfRange)?;
+    let (ordinal, rest) = take_digits(rest);
+    if ordinal.len() > 3 {
+        return Err(ConversionError::OutOfRange);
+    }
+    let ordinal = parse_digits(ordinal)?;
+
+    let mut clock = [0; 3];
+    let mut frac_nanos = 0;
+    if !rest.is_empty() {
+        let mut rest = rest
+            .strip_prefix(['T', '/', ':', ' '])
+            .ok_or(ConversionError::OutOfRange)?
+            .trim_start_matches(' ');
+        for (i, field) in clock.iter_mut().enumerate() {
+            if i > 0 {
+                match rest.strip_prefix(':') {
+                    Some(next) => rest = next,
+                    None => break,
+                }
+            }
+            let (digits, next) = take_digits(rest);
+            if digits.len() > 2 {
+                return Err(ConversionError::OutOfRange);
+            }
+            *field = parse_digits(digits)?;
+            rest = next;
+            if i == 2 {
+                if let Some(frac) = rest.strip_prefix('.') {
+                    if frac.is_empty() {
+                        return Err(ConversionError::OutOfRange);
+                    }
+                    frac_nanos = parse_fraction_nanos(frac)?;
+                    rest = "";
+                }
+            }
+        }
+        if !rest.is_empty() {
+            return Err(ConversionError::OutOfRange);
+        }
+    }
+    Ok(Fields {
+        year,
+        ordinal,
+        clock,
+        frac_nanos,
+    })
+}
+
+impl<S: CalendarScale> Time<S> {
+    /// Parse a day-of-year timestamp under `profile`. See the
+    /// [module docs](crate::format::day_of_year) for the accepted forms.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn parse_day_of_year(s: &str, profile: DayOfYearProfile) -> Result<Self, ConversionError> {
+        Self::parse_day_of_year_with(s, profile, &TimeContext::new())
+    }
+
+    /// Like [`parse_day_of_year`](Self::parse_day_of_year), with an explicit
+    /// [`TimeContext`].
+    pub fn parse_day_of_year_with(
+        s: &str,
+        profile: DayOfYearProfile,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        let s = strip_scale::<S>(s, profile)?;
+        let fields = match profile {
+            DayOfYearProfile::Strict => parse_strict(s)?,
+            DayOfYearProfile::Lenient => parse_lenient(s)?,
+        };
+        let mjd = encoding::mjd_from_year_day(fields.year, fields.ordinal)
+            .ok_or(ConversionError::OutOfRange)?;
+        time_from_clock(mjd, fields.clock, fields.frac_nanos, ctx)
+    }
+
+    /// Format as a day-of-year timestamp.
+    ///
+    /// Subseconds follow the same [`FormatOptions`] policy as
+    /// [`Time::<UTC>::format_rfc3339`]. UTC appends `Z` when
+    /// `opts.include_zulu` is set; other scales always append ` NAME`.
+    pub fn format_day_of_year(&self, style: DayOfYearStyle, opts: FormatOptions) -> String {
+        self.format_day_of_year_with(style, opts, &TimeContext::new())
+    }
+
+    /// Like [`format_day_of_year`](Self::format_day_of_year), with an
+    /// explicit [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the instant cannot be labelled; use
+    /// [`try_format_day_of_year_with`](Self::try_format_day_of_year_with) to
+    /// handle that case explicitly.
+    pub fn format_day_of_year_with(
+        &self,
+        style: DayOfYearStyle,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> String {
+        match self.try_format_day_of_year_with(style, opts, ctx) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of
+    /// [`format_day_of_year_with`](Self::format_day_of_year_with).
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for years outside
+    /// `0000..=9999` (1969–2068 for [`DayOfYearStyle::TwoDigitYear`]).
+    pub fn try_format_day_of_year_with(
+        &self,
+        style: DayOfYearStyle,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> Result<String, ConversionError> {
+        let label = rounded_label(*self, opts, ctx)?;
+        let (year, ordinal) = encoding::year_day_from_mjd(label.mjd);
+        let year_range = match style {
+            DayOfYearStyle::TwoDigitYear => 1_969..=2_068,
+            _ => 0..=9_999,
+        };
+        if !year_range.contains(&year) {
+            return Err(ConversionError::OutOfRange);
+        }
+        let clock = label.clock_string();
+        let mut out = match style {
+            DayOfYearStyle::Iso => format!("{year:04}-{ordinal:03}T{clock}"),
+            DayOfYearStyle::Slash => format!("{year:04}-{ordinal:03}/{clock}"),
+            DayOfYearStyle::Colon => format!("{year:04}:{ordinal:03}:{clock}"),
+            DayOfYearStyle::TwoDigitYear => format!("{:02}/{ordinal:03} {clock}", year % 100),
+        };
+        if !S::IS_UTC {
+            out.push(' ');
+            out.push_str(S::NAME);
+        } else if opts.include_zulu {
+            out.push('Z');
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{FormatPrecision, TAI, TT, UTC};
+
+    const LENIENT: DayOfYearProfile = DayOfYearProfile::Lenient;
+    const STRICT: DayOfYearProfile = DayOfYearProfile::Strict;
+
+    fn utc_midnight(mjd: i64) -> Time<UTC> {
+        let label =
+            crate::MjdSod::new(qtty::i64::Day::new(mjd), crate::ExactDuration::ZERO).unwrap();
+        Time::<UTC>::from_mjd_sod(label).unwrap()
+    }
+
+    #[test]
+    fn punctuation_variants_parse_to_the_same_instant() {
+        let reference = utc_midnight(60_475)
+            .try_add_exact(crate::ExactDuration::from_nanos(43_200_000_000_000))
+            .unwrap();
+        for s in [
+            "2024-166T12:00:00",
+            "2024-166/12:00:00Z",
+            "2024:166:12:00:00.000",
+        ] {
+            assert_eq!(
+                Time::<UTC>::parse_day_of_year(s, STRICT).unwrap(),
+                reference
+            );
+        }
+        for s in [
+            "24/166 12:00",
+            "2024-166T12",
+            " 2024:166:12:00:00 UTC ",
+            "24-166  12:00:00z",
+        ] {
+            assert_eq!(
+                Time::<UTC>::parse_day_of_year(s, LENIENT).unwrap(),
+                reference
+            );
+        }
+        assert_eq!(
+            Time::<UTC>::parse_day_of_year("70:1", LENIENT).unwrap(),
+            utc_midnight(40_587)
+        );
+    }
+
+    #[test]
+    fn strict_profile_rejects_lenient_forms() {
+        for s in [
+            "24/166 12:00",
+            "2024-166T12:00",
+            "2024-66T12:00:00",
+            "2024:166T12:00:00",
+            "2024-166T12:00:00.",
+            "2024-166T12:00:00 UTC",
+        ] {
+            assert!(
+                Time::<UTC>::parse_day_of_year(s, STRICT).is_err(),
+                "accepted {s}"
+            );
+        }
+        assert!(Time::<UTC>::parse_day_of_year("2023-366", LENIENT).is_err());
+        assert!(Time::<UTC>::parse_day_of_year("2024-166T12:00:00 TT", LENIENT).is_err());
+    }
+
+    #[test]
+    fn leap_seconds_follow_the_utc_table() {
+        let t = Time::<UTC>::parse_day_of_year("2016:366:23:59:60.25", STRICT).unwrap();
+        assert!(t.is_leap_second());
+        let opts = FormatOptions {
+            subsecond_digits: 2,
+            precision: FormatPrecision::Truncate,
+            include_zulu: false,
+            utc_offset: None,
+        };
+        assert_eq!(
+            t.format_day_of_year(DayOfYearStyle::Colon, opts),
+            "2016:366:23:59:60.25"
+        );
+        assert_eq!(
+            Time::<UTC>::parse_day_of_year("2023-166T23:59:60", STRICT),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+        assert!(Time::<TAI>::parse_day_of_year("2016-366T23:59:60 TAI", STRICT).is_err());
+    }
+
+    #[test]
+    fn other_scales_are_labelled_and_exact() {
+        let t = Time::<TT>::parse_day_of_year("2024-001T00:00:00.000000001 TT", STRICT).unwrap();
+        assert_eq!(
+            t.format_day_of_year(DayOfYearStyle::Iso, FormatOptions::nanoseconds()),
+            "2024-001T00:00:00.000000001 TT"
+        );
+        assert_eq!(
+            t.format_day_of_year(DayOfYearStyle::TwoDigitYear, FormatOptions::SECONDS),
+            "24/001 00:00:00 TT"
+        );
+        assert!(Time::<TT>::parse_day_of_year("2024-001T00:00:00", STRICT).is_err());
+        assert_eq!(
+            Time::<TT>::parse_day_of_year("2024-1 tt", LENIENT).unwrap(),
+            t.try_sub_exact(crate::ExactDuration::NANOSECOND).unwrap()
+        );
+    }
+}
@@ -0,0 +1,289 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Runtime "count of units since an epoch" formats.
+//!
+//! The built-in markers ([`crate::JD`], [`crate::Unix`], [`crate::GPS`], …)
+//! fix their epoch and unit at compile time. Mission data, instrument logs,
+//! and file formats frequently use their own convention instead ("seconds
+//! since 2010-01-01 TT", "milliseconds since the launch epoch", …).
+//! [`EpochFormat`] describes such a convention at runtime: an epoch
+//! [`Time<S>`], a tick [`EpochUnit`], and the scale `S` on which the count
+//! advances.
+//!
+//! Encoding and decoding go through [`ExactDuration`], so integer tick counts
+//! round-trip without touching a single `f64` total. The decoded values are
+//! ordinary [`Time<S>`] instants and can be passed to
+//! [`crate::Interval::try_new`], [`crate::TimeSeries::new`], or any scale
+//! conversion.
+//!
+//! `EpochFormat` is a value, not a [`TimeFormat`](crate::TimeFormat) marker:
+//! the sealed markers fix their epoch and unit in the type, so a runtime
+//! epoch cannot be the `F` of a `Time<S, F>`. Decode counts to `Time<S>` to
+//! use them with intervals, series, and conversions, and encode back when
+//! writing them out.
+//!
+//! Counts are elapsed SI seconds on the storage axis of `S`. For
+//! [`crate::UTC`] this means leap seconds are counted (the value is not a
+//! POSIX-style label); use a continuous scale when that distinction matters.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{EpochFormat, EpochUnit, ExactDuration, Period, Time, TimeSeries, TT};
+//! use qtty::Second;
+//!
+//! let epoch = Time::<TT>::from_raw_j2000_seconds(Second::new(0.0)).unwrap();
+//! let millis = EpochFormat::new(epoch, EpochUnit::Milliseconds).unwrap();
+//!
+//! let t = millis.decode_ticks(1_500).unwrap();
+//! assert_eq!(millis.encode_ticks(t).unwrap(), (1_500, ExactDuration::ZERO));
+//!
+//! let window = Period::try_new(millis.decode_ticks(0).unwrap(), t).unwrap();
+//! let series = TimeSeries::new(window.start, window.end, millis.ticks(500).unwrap()).unwrap();
+//! let counts: Vec<i128> = series.map(|t| millis.encode_ticks(t).unwrap().0).collect();
+//! assert_eq!(counts, [0, 500, 1_000]);
+//! ```
+
+use crate::foundation::duration::{DurationError, ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::CoordinateScale;
+use crate::model::time::Time;
+
+/// Tick length of an [`EpochFormat`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EpochUnit {
+    /// 1 ns ticks.
+    Nanoseconds,
+    /// 1 µs ticks.
+    Microseconds,
+    /// 1 ms ticks.
+    Milliseconds,
+    /// SI-second ticks.
+    Seconds,
+    /// 60 s ticks.
+    Minutes,
+    /// 3600 s ticks.
+    Hours,
+    /// 86 400 s ticks.
+    Days,
+    /// Arbitrary positive tick length.
+    Custom(ExactDuration),
+}
+
+impl EpochUnit {
+    /// Length of one tick.
+    pub const fn tick(self) -> ExactDuration {
+        match self {
+            Self::Nanoseconds => ExactDuration::NANOSECOND,
+            Self::Microseconds => ExactDuration::from_nanos(1_000),
+            Self::Milliseconds => ExactDuration::from_nanos(1_000_000),
+            Self::Seconds => ExactDuration::SECOND,
+            Self::Minutes => ExactDuration::from_nanos(60 * NANOS_PER_SECOND),
+            Self::Hours => ExactDuration::from_nanos(3_600 * NANOS_PER_SECOND),
+            Self::Days => ExactDuration::from_nanos(86_400 * NANOS_PER_SECOND),
+            Self::Custom(tick) => tick,
+        }
+    }
+}
+
+/// Runtime descriptor for "ticks of `unit` since `epoch`" on scale `S`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EpochFormat<S: CoordinateScale> {
+    epoch: Time<S>,
+    /// `epoch` as an exact offset from J2000.0 on the axis of `S`.
+    epoch_offset: ExactDuration,
+    unit: EpochUnit,
+}
+
+impl<S: CoordinateScale> EpochFormat<S> {
+    /// Build a descriptor from an epoch instant and a tick unit.
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for a non-positive custom tick
+    /// and [`ConversionError::NonFinite`] for a non-finite epoch.
+    pub fn new(epoch: Time<S>, unit: EpochUnit) -> Result<Self, ConversionError> {
+        if unit.tick().as_nanos_i128() <= 0 {
+            return Err(ConversionError::OutOfRange);
+        }
+        let epoch_offset = epoch.to_exact_j2000()?;
+        Ok(Self {
+            epoch: Time::try_from_exact_j2000(epoch_offset)?,
+            epoch_offset,
+            unit,
+        })
+    }
+
+    /// Epoch instant (count zero), rounded to the nearest nanosecond.
+    #[inline]
+    pub fn epoch(&self) -> Time<S> {
+        self.epoch
+    }
+
+    /// Tick unit.
+    #[inline]
+    pub fn unit(&self) -> EpochUnit {
+        self.unit
+    }
+
+    /// Exact length of `count` ticks, for use as a [`crate::TimeSeries`] step.
+    pub fn ticks(&self, count: i64) -> Result<ExactDuration, DurationError> {
+        self.unit
+            .tick()
+            .as_nanos_i128()
+            .checked_mul(count as i128)
+            .map(ExactDuration::from_nanos)
+            .ok_or(DurationError::Overflow)
+    }
+
+    /// Exact elapsed duration from the epoch to `time`.
+    pub fn duration_since_epoch(&self, time: Time<S>) -> Result<ExactDuration, ConversionError> {
+        time.to_exact_j2000()?
+            .checked_sub(self.epoch_offset)
+            .map_err(|_| ConversionError::OutOfRange)
+    }
+
+    /// Instant `offset` after the epoch.
+    pub fn decode_duration(&self, offset: ExactDuration) -> Result<Time<S>, ConversionError> {
+        let total = self
+            .epoch_offset
+            .checked_add(offset)
+            .map_err(|_| ConversionError::OutOfRange)?;
+        Time::try_from_exact_j2000(total)
+    }
+
+    /// Whole ticks since the epoch (floored) and the non-negative remainder.
+    pub fn encode_ticks(&self, time: Time<S>) -> Result<(i128, ExactDuration), ConversionError> {
+        let elapsed = self.duration_since_epoch(time)?.as_nanos_i128();
+        let tick = self.unit.tick().as_nanos_i128();
+        Ok((
+            elapsed.div_euclid(tick),
+            ExactDuration::from_nanos(elapsed.rem_euclid(tick)),
+        ))
+    }
+
+    /// Instant exactly `ticks` whole ticks after the epoch.
+    pub fn decode_ticks(&self, ticks: i128) -> Result<Time<S>, ConversionError> {
+        let offset = ticks
+            .checked_mul(self.unit.tick().as_nanos_i128())
+            .ok_or(ConversionError::OutOfRange)?;
+        self.decode_duration(ExactDuration::from_nanos(offset))
+    }
+
+    /// Fractional tick count since the epoch.
+    ///
+    /// The whole-tick part and the remainder are converted separately, so the
+    /// result is the nearest `f64` to the exact count.
+    pub fn encode(&self, time: Time<S>) -> Result<f64, ConversionError> {
+        let (ticks, remainder) = self.encode_ticks(time)?;
+        let tick = self.unit.tick().as_nanos_i128() as f64;
+        Ok(ticks as f64 + remainder.as_nanos_i128() as f64 / tick)
+    }
+
+    /// Instant for a fractional tick count.
+    ///
+    /// The integer part of `value` is applied exactly; the fractional part is
+    /// rounded to the nearest nanosecond.
+    pub fn decode(&self, value: f64) -> Result<Time<S>, ConversionError> {
+        if !value.is_finite() {
+            return Err(ConversionError::NonFinite);
+        }
+        let whole = value.floor();
+        if whole.abs() >= i128::MAX as f64 {
+            return Err(ConversionError::OutOfRange);
+        }
+        let tick = self.unit.tick().as_nanos_i128();
+        let fraction_nanos = ((value - whole) * tick as f64).round() as i128;
+        let offset = (whole as i128)
+            .checked_mul(tick)
+            .and_then(|nanos| nanos.checked_add(fraction_nanos))
+            .ok_or(ConversionError::OutOfRange)?;
+        self.decode_duration(ExactDuration::from_nanos(offset))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Period, TimeSeries, TAI, TT, UTC};
+    use qtty::Second;
+
+    /// 1958-01-01T00:00:00 TAI (CCSDS level-1 epoch) on the TAI axis.
+    fn tai_1958() -> Time<TAI> {
+        Time::from_raw_j2000_seconds(Second::new(-1_325_419_200.0)).unwrap()
+    }
+
+    #[test]
+    fn integer_ticks_round_trip_exactly_far_from_j2000() {
+        let fmt = EpochFormat::new(tai_1958(), EpochUnit::Nanoseconds).unwrap();
+        // ~2.1e18 ns: well past the precision of a single f64 total.
+        for ticks in [0_i128, 1, 2_097_152_000_123_456_789, -7] {
+            let t = fmt.decode_ticks(ticks).unwrap();
+            assert_eq!(fmt.encode_ticks(t).unwrap(), (ticks, ExactDuration::ZERO));
+        }
+    }
+
+    #[test]
+    fn encode_ticks_floors_and_returns_remainder() {
+        let fmt = EpochFormat::new(tai_1958(), EpochUnit::Seconds).unwrap();
+        let t = fmt
+            .decode_duration(ExactDuration::from_nanos(-1_250_000_000))
+            .unwrap();
+        assert_eq!(
+            fmt.encode_ticks(t).unwrap(),
+            (-2, ExactDuration::from_nanos(750_000_000))
+        );
+        assert_eq!(fmt.encode(t).unwrap(), -1.25);
+    }
+
+    #[test]
+    fn fractional_days_decode_to_nearest_nanosecond() {
+        let epoch = Time::<TT>::from_raw_j2000_seconds(Second::new(0.0)).unwrap();
+        let fmt = EpochFormat::new(epoch, EpochUnit::Days).unwrap();
+        let t = fmt.decode(8_766.25).unwrap();
+        assert_eq!(
+            fmt.duration_since_epoch(t).unwrap(),
+            ExactDuration::from_nanos((8_766 * 86_400 + 21_600) * NANOS_PER_SECOND)
+        );
+        assert_eq!(fmt.encode(t).unwrap(), 8_766.25);
+    }
+
+    #[test]
+    fn custom_tick_must_be_positive() {
+        let epoch = Time::<UTC>::from_raw_j2000_seconds(Second::new(0.0)).unwrap();
+        assert_eq!(
+            EpochFormat::new(epoch, EpochUnit::Custom(ExactDuration::ZERO)),
+            Err(ConversionError::OutOfRange)
+        );
+        let fmt = EpochFormat::new(
+            epoch,
+            EpochUnit::Custom(ExactDuration::from_nanos(250_000_000)),
+        )
+        .unwrap();
+        assert_eq!(fmt.encode(fmt.decode_ticks(10).unwrap()).unwrap(), 10.0);
+    }
+
+    #[test]
+    fn decode_rejects_non_finite() {
+        let fmt = EpochFormat::new(tai_1958(), EpochUnit::Seconds).unwrap();
+        assert_eq!(fmt.decode(f64::NAN), Err(ConversionError::NonFinite));
+        assert_eq!(fmt.decode(f64::INFINITY), Err(ConversionError::NonFinite));
+    }
+
+    #[test]
+    fn series_over_period_lands_on_whole_ticks() {
+        let fmt = EpochFormat::new(tai_1958(), EpochUnit::Milliseconds).unwrap();
+        let start = 2_000_000_000_000_i128;
+        let period = Period::try_new(
+            fmt.decode_ticks(start).unwrap(),
+            fmt.decode_ticks(start + 1_000).unwrap(),
+        )
+        .unwrap();
+        let series = TimeSeries::new(period.start, period.end, fmt.ticks(125).unwrap()).unwrap();
+        let encoded: Vec<_> = series.map(|t| fmt.encode_ticks(t).unwrap()).collect();
+        assert_eq!(encoded.len(), 8);
+        for (k, (ticks, rem)) in encoded.into_iter().enumerate() {
+            assert_eq!(ticks, start + 125 * k as i128);
+            assert_eq!(rem, ExactDuration::ZERO);
+        }
+    }
+}
@@ -0,0 +1,734 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! FITS time-coordinate keywords (FITS 4.0 §9).
+//!
+//! A FITS header fixes the meaning of its time values with a handful of
+//! keywords:
+//!
+//! - `TIMESYS` — the time scale (default `UTC`); see [`FitsTimeSys`];
+//! - `MJDREFI`/`MJDREFF`, `MJDREF`, `JDREFI`/`JDREFF`, `JDREF`, or `DATEREF`
+//!   — the reference epoch, in that order of precedence (default MJD 0);
+//! - `TIMEUNIT` — the unit of time values (default `s`);
+//! - `TIMEOFFS` — a constant added to every time value (default 0);
+//! - `DATE-OBS` or `MJD-OBS` — the observation start.
+//!
+//! [`FitsTimeHeader`] reads these from key/value pairs into a typed
+//! reference epoch on the scale `S` named by `TIMESYS`, and converts
+//! time-column values through an [`EpochFormat`]. [`FitsTimeHeader::to_cards`]
+//! writes the same keywords back out.
+//!
+//! Values may be given raw (`TT`, `51544.5`) or as FITS card values
+//! (`'TT      '`); numbers accept Fortran `D` exponents and are read as
+//! exact decimals, so `MJDREFF` keeps its full precision. Epoch values are
+//! labels on `TIMESYS`; column values count elapsed SI seconds on its axis
+//! (leap seconds included for `UTC`).
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{FitsTimeHeader, FitsTimeSys, TT};
+//!
+//! let cards = [
+//!     ("TIMESYS", "'TT      '"),
+//!     ("MJDREFI", "51544"),
+//!     ("MJDREFF", "0.5"),
+//!     ("TIMEUNIT", "'d'"),
+//!     ("DATE-OBS", "'2000-01-02T12:00:00'"),
+//! ];
+//! assert_eq!(FitsTimeSys::from_cards(cards).unwrap(), FitsTimeSys::Tt);
+//!
+//! let header = FitsTimeHeader::<TT>::from_cards(cards).unwrap();
+//! let obs = header.date_obs().unwrap();
+//! assert_eq!(header.decode(1.0).unwrap(), obs);
+//! assert_eq!(header.encode(obs).unwrap(), 1.0);
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+
+use super::iso::{parse_digits, parse_fraction_nanos, rounded_label, time_from_clock};
+use super::{CalendarScale, EpochFormat, EpochUnit, FormatOptions, MjdSod};
+
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+/// JD 0 expressed as nanoseconds since MJD 0 (`−2 400 000.5 d`).
+const JD_ZERO_NANOS_SINCE_MJD_ZERO: i128 = -207_360_043_200 * NANOS_PER_SECOND;
+/// Julian year (`a`, `yr`): 365.25 d.
+const JULIAN_YEAR: ExactDuration = ExactDuration::from_nanos(31_557_600 * NANOS_PER_SECOND);
+/// Julian century (`cy`): 36 525 d.
+const JULIAN_CENTURY: ExactDuration = ExactDuration::from_nanos(3_155_760_000 * NANOS_PER_SECOND);
+/// Significant digits accepted in a numeric value (keeps the exact
+/// arithmetic within `i128`).
+const MAX_SIGNIFICANT_DIGITS: usize = 24;
+/// Fraction digits written for `MJDREFF`/`TIMEOFFS` (1e-20 of a Julian
+/// century is 0.03 ns).
+const MAX_FORMATTED_FRACTION_DIGITS: usize = 20;
+
+/// Errors from reading or writing FITS time keywords.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FitsTimeError {
+    /// The named keyword has a malformed value.
+    InvalidValue(&'static str),
+    /// `TIMESYS` names a scale tempoch does not model (e.g. `LOCAL`).
+    UnsupportedTimeSys,
+    /// `TIMESYS` differs from the requested scale.
+    ScaleMismatch,
+    /// `TIMEUNIT` is not one of `s`, `min`, `h`, `d`, `a`, `yr`, `cy`.
+    UnsupportedUnit,
+    /// The keywords are well formed but the instant is not representable.
+    Conversion(ConversionError),
+}
+
+impl core::fmt::Display for FitsTimeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidValue(keyword) => write!(f, "invalid FITS {keyword} value"),
+            Self::UnsupportedTimeSys => f.write_str("unsupported FITS TIMESYS"),
+            Self::ScaleMismatch => f.write_str("FITS TIMESYS does not match the requested scale"),
+            Self::UnsupportedUnit => f.write_str("unsupported FITS TIMEUNIT"),
+            Self::Conversion(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for FitsTimeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for FitsTimeError {
+    fn from(err: ConversionError) -> Self {
+        Self::Conversion(err)
+    }
+}
+
+/// `TIMESYS` values tempoch can represent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FitsTimeSys {
+    /// `UTC` (also the deprecated `GMT`).
+    Utc,
+    /// `TAI` (also the deprecated `IAT`).
+    Tai,
+    /// `TT` (also the deprecated `TDT` and `ET`).
+    Tt,
+    /// `TDB`.
+    Tdb,
+    /// `TCG`.
+    Tcg,
+    /// `TCB`.
+    Tcb,
+    /// `GPS`, i.e. [`crate::GPST`].
+    Gps,
+    /// `UT1`.
+    Ut1,
+}
+
+impl FitsTimeSys {
+    /// Parse a `TIMESYS` value (case-insensitive, quotes optional).
+    pub fn parse(value: &str) -> Option<Self> {
+        Some(match card_string(value).to_ascii_uppercase().as_str() {
+            "UTC" | "GMT" => Self::Utc,
+            "TAI" | "IAT" => Self::Tai,
+            "TT" | "TDT" | "ET" => Self::Tt,
+            "TDB" => Self::Tdb,
+            "TCG" => Self::Tcg,
+            "TCB" => Self::Tcb,
+            "GPS" => Self::Gps,
+            "UT1" => Self::Ut1,
+            _ => return None,
+        })
+    }
+
+    /// `TIMESYS` declared by `cards` (`UTC` when absent).
+    pub fn from_cards<'a, I>(cards: I) -> Result<Self, FitsTimeError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        match lookup(cards, "TIMESYS") {
+            Some(value) => Self::parse(value).ok_or(FitsTimeError::UnsupportedTimeSys),
+            None => Ok(Self::Utc),
+        }
+    }
+
+    /// The `TIMESYS` value for scale `S`, if FITS defines one.
+    pub fn of<S: CalendarScale>() -> Option<Self> {
+        S::FITS_TIMESYS
+    }
+
+    /// Canonical `TIMESYS` value.
+    pub const fn keyword_value(self) -> &'static str {
+        match self {
+            Self::Gps => "GPS",
+            other => other.scale_name(),
+        }
+    }
+
+    /// [`Scale::NAME`](crate::Scale::NAME) of the corresponding tempoch scale.
+    pub const fn scale_name(self) -> &'static str {
+        match self {
+            Self::Utc => "UTC",
+            Self::Tai => "TAI",
+            Self::Tt => "TT",
+            Self::Tdb => "TDB",
+            Self::Tcg => "TCG",
+            Self::Tcb => "TCB",
+            Self::Gps => "GPST",
+            Self::Ut1 => "UT1",
+        }
+    }
+}
+
+/// FITS time reference frame on scale `S`: reference epoch, unit, offset,
+/// and optional observation start.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FitsTimeHeader<S: CalendarScale> {
+    reference: Time<S>,
+    offset: ExactDuration,
+    columns: EpochFormat<S>,
+    date_obs: Option<Time<S>>,
+}
+
+impl<S: CalendarScale> FitsTimeHeader<S> {
+    /// Header with reference epoch `reference` and column unit `unit`.
+    ///
+    /// Returns [`FitsTimeError::ScaleMismatch`] when FITS has no `TIMESYS`
+    /// for `S` and [`FitsTimeError::UnsupportedUnit`] for units without a
+    /// `TIMEUNIT` value.
+    pub fn new(reference: Time<S>, unit: EpochUnit) -> Result<Self, FitsTimeError> {
+        FitsTimeSys::of::<S>().ok_or(FitsTimeError::ScaleMismatch)?;
+        unit_keyword(unit).ok_or(FitsTimeError::UnsupportedUnit)?;
+        Ok(Self {
+            reference,
+            offset: ExactDuration::ZERO,
+            columns: EpochFormat::new(reference, unit)?,
+            date_obs: None,
+        })
+    }
+
+    /// Read the time keywords from `cards`.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn from_cards<'a, I>(cards: I) -> Result<Self, FitsTimeError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        Self::from_cards_with(cards, &TimeContext::new())
+    }
+
+    /// Like [`from_cards`](Self::from_cards), using `ctx` for UTC and UT1
+    /// labels.
+    pub fn from_cards_with<'a, I>(cards: I, ctx: &TimeContext) -> Result<Self, FitsTimeError>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let cards: Vec<(&str, &str)> = cards.into_iter().collect();
+        let get = |keyword| lookup(cards.iter().copied(), keyword);
+        if FitsTimeSys::of::<S>() != Some(FitsTimeSys::from_cards(cards.iter().copied())?) {
+            return Err(FitsTimeError::ScaleMismatch);
+        }
+
+        let reference = match (get("MJDREFI"), get("MJDREFF"), get("MJDREF")) {
+            (None, None, Some(mjd)) => day_label(decimal_days(mjd, "MJDREF")?, ctx)?,
+            (None, None, None) => match (get("JDREFI"), get("JDREFF"), get("JDREF")) {
+                (None, None, Some(jd)) => day_label(jd_nanos(decimal_days(jd, "JDREF")?), ctx)?,
+                (None, None, None) => match get("DATEREF") {
+                    Some(date) => parse_datetime(date, "DATEREF", ctx)?,
+                    None => day_label(0, ctx)?,
+                },
+                (whole, frac, _) => {
+                    day_label(jd_nanos(split_days(whole, frac, "JDREFI", "JDREFF")?), ctx)?
+                }
+            },
+            (whole, frac, _) => day_label(split_days(whole, frac, "MJDREFI", "MJDREFF")?, ctx)?,
+        };
+
+        let unit = match get("TIMEUNIT") {
+            Some(value) => parse_unit(value).ok_or(FitsTimeError::UnsupportedUnit)?,
+            None => EpochUnit::Seconds,
+        };
+        let offset = match get("TIMEOFFS") {
+            Some(value) => ExactDuration::from_nanos(
+                parse_decimal(value, unit.tick().as_nanos_i128())
+                    .ok_or(FitsTimeError::InvalidValue("TIMEOFFS"))?,
+            ),
+            None => ExactDuration::ZERO,
+        };
+        let date_obs = match (get("DATE-OBS"), get("MJD-OBS")) {
+            (Some(date), _) => Some(parse_datetime(date, "DATE-OBS", ctx)?),
+            (None, Some(mjd)) => Some(day_label(decimal_days(mjd, "MJD-OBS")?, ctx)?),
+            (None, None) => None,
+        };
+
+        Self::new(reference, unit)?
+            .with_offset(offset)
+            .map(|header| header.with_date_obs(date_obs))
+    }
+
+    /// Replace the `TIMEOFFS` constant added to every column value.
+    pub fn with_offset(self, offset: ExactDuration) -> Result<Self, FitsTimeError> {
+        let epoch = self
+            .reference
+            .try_add_exact(offset)
+            .map_err(|_| ConversionError::OutOfRange)?;
+        Ok(Self {
+            offset,
+            columns: EpochFormat::new(epoch, self.columns.unit())?,
+            ..self
+        })
+    }
+
+    /// Replace the observation start (`DATE-OBS`).
+    pub fn with_date_obs(self, date_obs: Option<Time<S>>) -> Self {
+        Self { date_obs, ..self }
+    }
+
+    /// Reference epoch (`MJDREF` and friends), without `TIMEOFFS`.
+    #[inline]
+    pub fn reference(&self) -> Time<S> {
+        self.reference
+    }
+
+    /// `TIMEOFFS` as a duration.
+    #[inline]
+    pub fn offset(&self) -> ExactDuration {
+        self.offset
+    }
+
+    /// `TIMEUNIT`.
+    #[inline]
+    pub fn unit(&self) -> EpochUnit {
+        self.columns.unit()
+    }
+
+    /// Observation start (`DATE-OBS` or `MJD-OBS`), if present.
+    #[inline]
+    pub fn date_obs(&self) -> Option<Time<S>> {
+        self.date_obs
+    }
+
+    /// Converter for time-column values: ticks of `TIMEUNIT` since the
+    /// reference epoch plus `TIMEOFFS`.
+    #[inline]
+    pub fn column_format(&self) -> EpochFormat<S> {
+        self.columns
+    }
+
+    /// Instant for a time-column value.
+    #[inline]
+    pub fn decode(&self, value: f64) -> Result<Time<S>, ConversionError> {
+        self.columns.decode(value)
+    }
+
+    /// Time-column value for an instant.
+    #[inline]
+    pub fn encode(&self, time: Time<S>) -> Result<f64, ConversionError> {
+        self.columns.encode(time)
+    }
+
+    /// FITS card values for this header, in canonical order: `TIMESYS`,
+    /// `MJDREFI`, `MJDREFF`, `TIMEUNIT`, then `TIMEOFFS` when non-zero and
+    /// `DATE-OBS` when set. Strings are quoted as FITS card values.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn to_cards(&self) -> Result<Vec<(&'static str, String)>, FitsTimeError> {
+        self.to_cards_with(&TimeContext::new())
+    }
+
+    /// Like [`to_cards`](Self::to_cards), using `ctx` for UTC and UT1 labels.
+    pub fn to_cards_with(
+        &self,
+        ctx: &TimeContext,
+    ) -> Result<Vec<(&'static str, String)>, FitsTimeError> {
+        let timesys = FitsTimeSys::of::<S>().ok_or(FitsTimeError::ScaleMismatch)?;
+        let unit = unit_keyword(self.unit()).ok_or(FitsTimeError::UnsupportedUnit)?;
+        let reference = self.reference.to_mjd_sod_with(ctx)?;
+        let day_fraction = format_ratio(reference.second_of_day.as_nanos_i128(), NANOS_PER_DAY);
+
+        let mut cards = vec![
+            ("TIMESYS", format!("'{}'", timesys.keyword_value())),
+            ("MJDREFI", reference.mjd.value().to_string()),
+            ("MJDREFF", day_fraction),
+            ("TIMEUNIT", format!("'{unit}'")),
+        ];
+        if self.offset != ExactDuration::ZERO {
+            let ticks = format_ratio(
+                self.offset.as_nanos_i128(),
+                self.unit().tick().as_nanos_i128(),
+            );
+            cards.push(("TIMEOFFS", ticks));
+        }
+        if let Some(date_obs) = self.date_obs {
+            cards.push(("DATE-OBS", format!("'{}'", format_datetime(date_obs, ctx)?)));
+        }
+        Ok(cards)
+    }
+}
+
+/// Value of `keyword` in `cards` (keywords compare case-insensitively).
+fn lookup<'a, I>(cards: I, keyword: &str) -> Option<&'a str>
+where
+    I: IntoIterator<Item = (&'a str, &'a str)>,
+{
+    cards
+        .into_iter()
+        .find(|(key, _)| key.trim().eq_ignore_ascii_case(keyword))
+        .map(|(_, value)| value)
+}
+
+/// Strip FITS string quoting and padding: `'TT      '` → `TT`.
+fn card_string(value: &str) -> &str {
+    let value = value.trim();
+    value
+        .strip_prefix('\'')
+        .and_then(|v| v.strip_suffix('\''))
+        .unwrap_or(value)
+        .trim()
+}
+
+fn parse_unit(value: &str) -> Option<EpochUnit> {
+    Some(match card_string(value) {
+        "s" => EpochUnit::Seconds,
+        "min" => EpochUnit::Minutes,
+        "h" => EpochUnit::Hours,
+        "d" => EpochUnit::Days,
+        "a" | "yr" => EpochUnit::Custom(JULIAN_YEAR),
+        "cy" => EpochUnit::Custom(JULIAN_CENTURY),
+        _ => return None,
+    })
+}
+
+fn unit_keyword(unit: EpochUnit) -> Option<&'static str> {
+    match unit {
+        EpochUnit::Seconds => Some("s"),
+        EpochUnit::Minutes => Some("min"),
+        EpochUnit::Hours => Some("h"),
+        EpochUnit::Days => Some("d"),
+        EpochUnit::Custom(tick) if tick == JULIAN_YEAR => Some("a"),
+        EpochUnit::Custom(tick) if tick == JULIAN_CENTURY => Some("cy"),
+        _ => None,
+    }
+}
+
+/// Exact decimal `value` × `unit_nanos`, rounded to the nearest nanosecond.
+///
+/// Accepts an optional sign, a decimal point, and an `E`/`D` exponent.
+fn parse_decimal(value: &str, unit_nanos: i128) -> Option<i128> {
+    let value = card_string(value);
+    let (negative, value) = match value.as_bytes().first()? {
+        b'-' => (true, &value[1..]),
+        b'+' => (false, &value[1..]),
+        _ => (false, value),
+    };
+    let (mantissa, exponent) = match value.find(['E', 'e', 'D', 'd']) {
+        Some(at) => (&value[..at], value[at + 1..].parse::<i32>().ok()?),
+        None => (value, 0),
+    };
+    let (whole, frac) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    if whole.is_empty() && frac.is_empty()
+        || !whole
+            .bytes()
+            .chain(frac.bytes())
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+    let digits = format!("{whole}{frac}");
+    let digits = digits.trim_start_matches('0');
+    let mut scale = exponent.checked_sub(frac.len() as i32)?;
+    // Drop digits beyond the exact range; they are far below 1 ns.
+    let kept = digits.len().min(MAX_SIGNIFICANT_DIGITS);
+    scale = scale.checked_add((digits.len() - kept) as i32)?;
+    let mut nanos = if kept == 0 {
+        0
+    } else {
+        digits[..kept]
+            .parse::<i128>()
+            .ok()?
+            .checked_mul(unit_nanos)?
+    };
+    if scale >= 0 {
+        nanos = nanos.checked_mul(10_i128.checked_pow(scale as u32)?)?;
+    } else {
+        // A divisor past `i128` exceeds any mantissa, so the value rounds to 0.
+        nanos = match 10_i128.checked_pow(scale.unsigned_abs()) {
+            Some(divisor) => nanos.checked_add(divisor / 2)? / divisor,
+            None => 0,
+        };
+    }
+    Some(if negative { -nanos } else { nanos })
+}
+
+/// `numerator / denominator` as a decimal, truncated after
+/// [`MAX_FORMATTED_FRACTION_DIGITS`] digits, so [`parse_decimal`] reads back
+/// the same nanoseconds. `denominator` must be positive.
+fn format_ratio(numerator: i128, denominator: i128) -> String {
+    let sign = if numerator < 0 { "-" } else { "" };
+    let numerator = numerator.unsigned_abs();
+    let denominator = denominator.unsigned_abs();
+    let mut out = format!("{sign}{}.", numerator / denominator);
+    let mut remainder = numerator % denominator;
+    for _ in 0..MAX_FORMATTED_FRACTION_DIGITS {
+        remainder *= 10;
+        out.push(char::from(b'0' + (remainder / denominator) as u8));
+        remainder %= denominator;
+        if remainder == 0 {
+            break;
+        }
+    }
+    let trimmed = out.trim_end_matches('0').len();
+    out.truncate(trimmed);
+    if out.ends_with('.') {
+        out.push('0');
+    }
+    out
+}
+
+/// Nanoseconds since MJD 0 for a decimal day count.
+fn decimal_days(value: &str, keyword: &'static str) -> Result<i128, FitsTimeError> {
+    parse_decimal(value, NANOS_PER_DAY).ok_or(FitsTimeError::InvalidValue(keyword))
+}
+
+/// Nanoseconds for an integer/fraction pair such as `MJDREFI`/`MJDREFF`.
+fn split_days(
+    whole: Option<&str>,
+    frac: Option<&str>,
+    whole_keyword: &'static str,
+    frac_keyword: &'static str,
+) -> Result<i128, FitsTimeError> {
+    let whole = whole.map_or(Ok(0), |v| decimal_days(v, whole_keyword))?;
+    let frac = frac.map_or(Ok(0), |v| decimal_days(v, frac_keyword))?;
+    Ok(whole + frac)
+}
+
+fn jd_nanos(nanos_since_jd_zero: i128) -> i128 {
+    nanos_since_jd_zero + JD_ZERO_NANOS_SINCE_MJD_ZERO
+}
+
+/// Instant labelled by nanoseconds since MJD 0 on the day grid of `S`.
+fn day_label<S: CalendarScale>(nanos: i128, ctx: &TimeContext) -> Result<Time<S>, FitsTimeError> {
+    let mjd =
+        i64::try_from(nanos.div_euclid(NANOS_PER_DAY)).map_err(|_| ConversionError::OutOfRange)?;
+    let label = MjdSod::new(
+        qtty::i64::Day::new(mjd),
+        ExactDuration::from_nanos(nanos.rem_euclid(NANOS_PER_DAY)),
+    )?;
+    Ok(Time::from_mjd_sod_with(label, ctx)?)
+}
+
+/// `YYYY-MM-DD[Thh:mm:ss[.s…]]`, or the pre-2000 `DD/MM/YY` (19YY) form.
+fn parse_datetime<S: CalendarScale>(
+    value: &str,
+    keyword: &'static str,
+    ctx: &TimeContext,
+) -> Result<Time<S>, FitsTimeError> {
+    let invalid = |_| FitsTimeError::InvalidValue(keyword);
+    let value = card_string(value);
+    let (date, time) = value.split_once('T').unwrap_or((value, ""));
+    let mjd = match date.as_bytes() {
+        [_, _, _, _, b'-', _, _, b'-', _, _] => encoding::mjd_from_gregorian(
+            parse_digits(&date[..4]).map_err(invalid)? as i64,
+            parse_digits(&date[5..7]).map_err(invalid)?,
+            parse_digits(&date[8..10]).map_err(invalid)?,
+        ),
+        [_, _, b'/', _, _, b'/', _, _] if time.is_empty() => encoding::mjd_from_gregorian(
+            1_900 + parse_digits(&date[6..8]).map_err(invalid)? as i64,
+            parse_digits(&date[3..5]).map_err(invalid)?,
+            parse_digits(&date[..2]).map_err(invalid)?,
+        ),
+        _ => None,
+    }
+    .ok_or(FitsTimeError::InvalidValue(keyword))?;
+
+    let mut clock = [0_u32; 3];
+    let mut frac_nanos = 0;
+    if value.contains('T') {
+        let (hms, frac) = time.split_once('.').unwrap_or((time, ""));
+        let parts: Vec<&str> = hms.split(':').collect();
+        if parts.len() != 3 || parts.iter().any(|p| p.len() != 2) || time.ends_with('.') {
+            return Err(FitsTimeError::InvalidValue(keyword));
+        }
+        for (field, part) in clock.iter_mut().zip(parts) {
+            *field = parse_digits(part).map_err(invalid)?;
+        }
+        frac_nanos = parse_fraction_nanos(frac).map_err(invalid)?;
+    }
+    time_from_clock(mjd, clock, frac_nanos, ctx).map_err(|err| match err {
+        ConversionError::OutOfRange => FitsTimeError::InvalidValue(keyword),
+        other => FitsTimeError::Conversion(other),
+    })
+}
+
+/// `YYYY-MM-DDThh:mm:ss[.s…]` with trailing subsecond zeros removed.
+fn format_datetime<S: CalendarScale>(
+    time: Time<S>,
+    ctx: &TimeContext,
+) -> Result<String, ConversionError> {
+    let label = rounded_label(time, FormatOptions::nanoseconds(), ctx)?;
+    let (year, month, day) = encoding::gregorian_from_mjd(label.mjd);
+    if !(0..=9_999).contains(&year) {
+        return Err(ConversionError::OutOfRange);
+    }
+    let clock = label.clock_string();
+    let clock = clock.trim_end_matches('0').trim_end_matches('.');
+    Ok(format!("{year:04}-{month:02}-{day:02}T{clock}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TAI, TDB, TT, UTC};
+
+    fn label<S: CalendarScale>(mjd: i64, nanos_of_day: i128) -> Time<S> {
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(nanos_of_day),
+        )
+        .unwrap();
+        Time::<S>::from_mjd_sod(label).unwrap()
+    }
+
+    #[test]
+    fn reference_keywords_follow_fits_precedence() {
+        // Fermi: MJDREFI + MJDREFF on TT, far below f64 day resolution.
+        let header = FitsTimeHeader::<TT>::from_cards([
+            ("TIMESYS", "'TT'"),
+            ("MJDREFI", "51910"),
+            ("MJDREFF", "7.428703703703703D-4"),
+            ("MJDREF", "1.0"),
+        ])
+        .unwrap();
+        assert_eq!(
+            header.reference(),
+            label(51_910, 64_184 * NANOS_PER_SECOND / 1_000)
+        );
+        let jd =
+            FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TT"), ("JDREF", "2451545.0")]).unwrap();
+        assert_eq!(jd.reference(), label(51_544, NANOS_PER_DAY / 2));
+        let dateref = FitsTimeHeader::<TAI>::from_cards([
+            ("timesys", "'IAT     '"),
+            ("DATEREF", "'1958-01-01'"),
+        ])
+        .unwrap();
+        assert_eq!(dateref.reference(), label(36_204, 0));
+        let tiny = FitsTimeHeader::<TT>::from_cards([
+            ("TIMESYS", "TT"),
+            ("MJDREF", "'999999999999999999999999E-60'"),
+        ])
+        .unwrap();
+        assert_eq!(tiny.reference(), label(0, 0));
+        assert_eq!(
+            FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TT"), ("MJDREF", "9E40")]),
+            Err(FitsTimeError::InvalidValue("MJDREF"))
+        );
+        let default = FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TDT")]).unwrap();
+        assert_eq!(default.reference(), label(0, 0));
+        assert_eq!(default.unit(), EpochUnit::Seconds);
+    }
+
+    #[test]
+    fn columns_apply_unit_and_offset() {
+        let header = FitsTimeHeader::<TDB>::from_cards([
+            ("TIMESYS", "TDB"),
+            ("MJDREF", "51544.5"),
+            ("TIMEUNIT", "'a'"),
+            ("TIMEOFFS", "0.5"),
+        ])
+        .unwrap();
+        assert_eq!(
+            header.offset(),
+            ExactDuration::from_nanos(15_778_800 * NANOS_PER_SECOND)
+        );
+        let t = header.decode(1.5).unwrap();
+        assert_eq!(t, label(51_544 + 731, 0));
+        assert_eq!(header.encode(t).unwrap(), 1.5);
+        assert_eq!(
+            FitsTimeHeader::<TDB>::from_cards([("TIMESYS", "TDB"), ("TIMEUNIT", "ta")]),
+            Err(FitsTimeError::UnsupportedUnit)
+        );
+    }
+
+    #[test]
+    fn date_obs_parses_on_timesys() {
+        let utc = FitsTimeHeader::<UTC>::from_cards([
+            ("MJDREF", "57754"),
+            ("DATE-OBS", "'2016-12-31T23:59:60.5'"),
+        ])
+        .unwrap();
+        let obs = utc.date_obs().unwrap();
+        assert_eq!(obs, label(57_753, 86_400 * NANOS_PER_SECOND + 500_000_000));
+        assert_eq!(utc.encode(obs).unwrap(), -0.5);
+
+        let tt = FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TT"), ("DATE-OBS", "31/12/98")])
+            .unwrap();
+        assert_eq!(tt.date_obs(), Some(label(51_178, 0)));
+        let mjd_obs =
+            FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TT"), ("MJD-OBS", "51178")]).unwrap();
+        assert_eq!(mjd_obs.date_obs(), tt.date_obs());
+        assert_eq!(
+            FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TT"), ("DATE-OBS", "2000-01-01T12")]),
+            Err(FitsTimeError::InvalidValue("DATE-OBS"))
+        );
+        assert_eq!(
+            FitsTimeHeader::<TT>::from_cards([
+                ("TIMESYS", "TT"),
+                ("DATE-OBS", "2016-12-31T23:59:60")
+            ]),
+            Err(FitsTimeError::InvalidValue("DATE-OBS"))
+        );
+    }
+
+    #[test]
+    fn timesys_must_match_the_requested_scale() {
+        assert_eq!(
+            FitsTimeHeader::<TT>::from_cards([("TIMESYS", "TDB")]),
+            Err(FitsTimeError::ScaleMismatch)
+        );
+        assert_eq!(
+            FitsTimeSys::from_cards([("TIMESYS", "LOCAL")]),
+            Err(FitsTimeError::UnsupportedTimeSys)
+        );
+        assert_eq!(FitsTimeSys::from_cards([]), Ok(FitsTimeSys::Utc));
+        assert_eq!(FitsTimeSys::of::<crate::GPST>(), Some(FitsTimeSys::Gps));
+        assert_eq!(FitsTimeSys::of::<crate::ET>(), None);
+        assert_eq!(FitsTimeSys::of::<crate::UTC>(), Some(FitsTimeSys::Utc));
+        assert_eq!(FitsTimeSys::of::<crate::UT1>(), Some(FitsTimeSys::Ut1));
+    }
+
+    #[test]
+    fn cards_round_trip() {
+        let reference = label::<TT>(51_910, 64_184_000_000);
+        let header = FitsTimeHeader::new(reference, EpochUnit::Days)
+            .unwrap()
+            .with_offset(ExactDuration::from_nanos(NANOS_PER_DAY / 4))
+            .unwrap()
+            .with_date_obs(Some(label(51_911, 1_500_000_000)));
+        let cards = header.to_cards().unwrap();
+        assert_eq!(
+            cards,
+            [
+                ("TIMESYS", "'TT'".to_string()),
+                ("MJDREFI", "51910".to_string()),
+                ("MJDREFF", "0.00074287037037037037".to_string()),
+                ("TIMEUNIT", "'d'".to_string()),
+                ("TIMEOFFS", "0.25".to_string()),
+                ("DATE-OBS", "'2001-01-02T00:00:01.5'".to_string()),
+            ]
+        );
+        let parsed =
+            FitsTimeHeader::<TT>::from_cards(cards.iter().map(|(k, v)| (*k, v.as_str()))).unwrap();
+        assert_eq!(parsed, header);
+        assert_eq!(
+            FitsTimeHeader::new(reference, EpochUnit::Milliseconds),
+            Err(FitsTimeError::UnsupportedUnit)
+        );
+    }
+}
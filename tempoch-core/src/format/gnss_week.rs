@@ -134,10 +134,10 @@ pub trait GnssWeekScale: CoordinateScale {
 // To regenerate: convert the published epoch from UTC into the target GNSS
 // scale via `Time::<S>::from(parse_rfc3339_utc(epoch)).to_j2000s()` and read
 // the total J2000 seconds.
-const GPST_EPOCH_J2000_SECONDS: f64 = -630_763_200.0;
-const GST_EPOCH_J2000_SECONDS: f64 = -11_447_987.0;
-const BDT_EPOCH_J2000_SECONDS: f64 = 189_345_600.0;
-const QZSST_EPOCH_J2000_SECONDS: f64 = GPST_EPOCH_J2000_SECONDS;
+pub(super) const GPST_EPOCH_J2000_SECONDS: f64 = -630_763_200.0;
+pub(super) const GST_EPOCH_J2000_SECONDS: f64 = -11_447_987.0;
+pub(super) const BDT_EPOCH_J2000_SECONDS: f64 = 189_345_600.0;
+pub(super) const QZSST_EPOCH_J2000_SECONDS: f64 = GPST_EPOCH_J2000_SECONDS;
 
 impl GnssWeekScale for GPST {
     fn epoch_j2000_seconds() -> f64 {
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `hifitime` interop: `Epoch ↔ Time<S>` and `Duration ↔ ExactDuration`.
+//!
+//! Both crates label each scale's axis by its own calendar, with J2000.0 at
+//! `2000-01-01T12:00:00` on that scale. An [`Epoch`] is read on the
+//! [`HifitimeScale::TIME_SCALE`] of the target scale and turned into an
+//! exact nanosecond offset from that label, so nanoseconds survive both
+//! ways. `Time<UTC>` shares the TAI axis, so UTC epochs go through
+//! `hifitime`'s TAI view and only the leap-second tables have to agree.
+//! A UTC [`Epoch`] cannot hold a leap second (`hifitime` reads
+//! `23:59:60` as `23:59:59`), so [`Time::try_to_hifitime`] rejects one with
+//! [`ConversionError::InvalidLeapSecond`]; convert to `TAI` first to keep it.
+//!
+//! Scale changes are made by whichever side holds the value:
+//! [`Time::try_from_hifitime`] lets `hifitime` move the epoch onto `S`,
+//! while `Time::to::<T>()` uses this crate's models. For the fixed TAI
+//! offsets (`TT`, `GPST`, `GST`, `BDT`, `QZSST`) and for UTC after 1972 the
+//! results have the same [`Time::to_exact_j2000`] nanosecond offset, though
+//! the stored `f64` pairs may differ in their last bits; for `TDB` and `ET`
+//! they differ by microseconds, because the periodic terms are modelled
+//! differently.
+//!
+//! # Examples
+//!
+//! ```
+//! use hifitime::{Epoch, TimeScale};
+//! use tempoch_core::{Time, TT, UTC};
+//!
+//! let epoch = Epoch::from_gregorian(2024, 6, 14, 12, 34, 56, 123_456_789, TimeScale::UTC);
+//! let utc = Time::<UTC>::try_from(epoch).unwrap();
+//! assert_eq!(utc, Time::<UTC>::parse_rfc3339("2024-06-14T12:34:56.123456789Z").unwrap());
+//!
+//! let tt = Time::<TT>::try_from(epoch).unwrap();
+//! assert_eq!(tt.to_exact_j2000(), utc.to::<TT>().to_exact_j2000());
+//! assert_eq!(Epoch::try_from(tt).unwrap(), epoch);
+//! ```
+
+use hifitime::{Duration, Epoch, TimeScale};
+
+use crate::foundation::duration::{DurationError, ExactDuration};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{CoordinateScale, BDT, ET, GPST, GST, QZSST, TAI, TDB, TT, UTC};
+use crate::model::time::Time;
+
+/// Scales shared with `hifitime`.
+pub trait HifitimeScale: CoordinateScale {
+    /// The same scale in `hifitime`.
+    const TIME_SCALE: TimeScale;
+    /// The `hifitime` scale whose calendar labels this scale's raw axis
+    /// (`TAI` for `UTC`, the scale itself otherwise).
+    const AXIS: TimeScale = Self::TIME_SCALE;
+}
+
+macro_rules! hifitime_scale {
+    ($($scale:ty => $ts:ident),+ $(,)?) => {
+        $(impl HifitimeScale for $scale {
+            const TIME_SCALE: TimeScale = TimeScale::$ts;
+        })+
+    };
+}
+hifitime_scale!(
+    TAI => TAI,
+    TT => TT,
+    TDB => TDB,
+    ET => ET,
+    GPST => GPST,
+    GST => GST,
+    BDT => BDT,
+    QZSST => QZSST,
+);
+
+impl HifitimeScale for UTC {
+    const TIME_SCALE: TimeScale = TimeScale::UTC;
+    const AXIS: TimeScale = TimeScale::TAI;
+}
+
+/// J2000.0 labelled on `axis`, as stored by `hifitime`.
+fn j2000(axis: TimeScale) -> Duration {
+    Epoch::from_gregorian(2000, 1, 1, 12, 0, 0, 0, axis).duration
+}
+
+impl<S: HifitimeScale> Time<S> {
+    /// Instant of `epoch` on this scale, moving it onto
+    /// [`HifitimeScale::TIME_SCALE`] with `hifitime`'s own models first.
+    pub fn try_from_hifitime(epoch: Epoch) -> Result<Self, ConversionError> {
+        let since = epoch.to_duration_in_time_scale(S::AXIS) - j2000(S::AXIS);
+        Self::try_from_exact_j2000(since.into())
+    }
+
+    /// The same instant as a `hifitime` [`Epoch`] in
+    /// [`HifitimeScale::TIME_SCALE`].
+    ///
+    /// A UTC leap second is [`ConversionError::InvalidLeapSecond`].
+    pub fn try_to_hifitime(self) -> Result<Epoch, ConversionError> {
+        let since =
+            Duration::try_from(self.to_exact_j2000()?).map_err(|_| ConversionError::OutOfRange)?;
+        let axis = j2000(S::AXIS) + since;
+        let epoch = Epoch::from_duration(axis, S::AXIS).to_time_scale(S::TIME_SCALE);
+        if epoch.to_duration_in_time_scale(S::AXIS) != axis {
+            return Err(ConversionError::InvalidLeapSecond);
+        }
+        Ok(epoch)
+    }
+}
+
+impl<S: HifitimeScale> TryFrom<Epoch> for Time<S> {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Epoch) -> Result<Self, Self::Error> {
+        Self::try_from_hifitime(value)
+    }
+}
+
+impl<S: HifitimeScale> TryFrom<Time<S>> for Epoch {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Time<S>) -> Result<Self, Self::Error> {
+        value.try_to_hifitime()
+    }
+}
+
+impl From<Duration> for ExactDuration {
+    #[inline]
+    fn from(value: Duration) -> Self {
+        Self::from_nanos(value.total_nanoseconds())
+    }
+}
+
+impl TryFrom<ExactDuration> for Duration {
+    type Error = DurationError;
+
+    /// Fails with [`DurationError::Overflow`] outside `hifitime`'s
+    /// ±32 768 centuries instead of saturating.
+    fn try_from(value: ExactDuration) -> Result<Self, Self::Error> {
+        let nanos = value.as_nanos_i128();
+        if nanos < Duration::MIN.total_nanoseconds() || nanos > Duration::MAX.total_nanoseconds() {
+            return Err(DurationError::Overflow);
+        }
+        Ok(Duration::from_total_nanoseconds(nanos))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn epoch(ts: TimeScale) -> Epoch {
+        Epoch::from_gregorian(2024, 6, 14, 12, 34, 56, 123_456_789, ts)
+    }
+
+    fn nanos_between<S: HifitimeScale>(a: Time<S>, b: Time<S>) -> i128 {
+        a.to_exact_j2000().unwrap().as_nanos_i128() - b.to_exact_j2000().unwrap().as_nanos_i128()
+    }
+
+    #[test]
+    fn durations_map_exactly() {
+        let exact = ExactDuration::from_nanos(-86_400_000_000_123);
+        let duration = Duration::try_from(exact).unwrap();
+        assert_eq!(duration.total_nanoseconds(), -86_400_000_000_123);
+        assert_eq!(ExactDuration::from(duration), exact);
+        assert_eq!(
+            Duration::try_from(ExactDuration::from_nanos(i128::MAX)),
+            Err(DurationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn fixed_offset_scales_have_the_same_nanosecond_offset() {
+        let tai = Time::<TAI>::try_from(epoch(TimeScale::TAI)).unwrap();
+        assert_eq!(
+            tai.to_civil().unwrap().to_string(),
+            "2024-06-14T12:34:56.123456789"
+        );
+        assert_eq!(Epoch::try_from(tai).unwrap(), epoch(TimeScale::TAI));
+
+        let source = epoch(TimeScale::TAI);
+        assert_eq!(
+            nanos_between(Time::<TT>::try_from(source).unwrap(), tai.to::<TT>()),
+            0
+        );
+        assert_eq!(
+            nanos_between(Time::<GPST>::try_from(source).unwrap(), tai.to::<GPST>()),
+            0
+        );
+        assert_eq!(
+            nanos_between(Time::<GST>::try_from(source).unwrap(), tai.to::<GST>()),
+            0
+        );
+        assert_eq!(
+            nanos_between(Time::<BDT>::try_from(source).unwrap(), tai.to::<BDT>()),
+            0
+        );
+        assert_eq!(
+            nanos_between(Time::<QZSST>::try_from(source).unwrap(), tai.to::<QZSST>()),
+            0
+        );
+        for ts in [TimeScale::GPST, TimeScale::BDT] {
+            let labelled = epoch(ts);
+            assert_eq!(
+                Epoch::try_from(Time::<GPST>::try_from(labelled).unwrap()).unwrap(),
+                labelled
+            );
+        }
+    }
+
+    #[test]
+    fn utc_agrees_after_1972_including_leap_seconds() {
+        let utc = Time::<UTC>::try_from(epoch(TimeScale::UTC)).unwrap();
+        assert_eq!(
+            utc,
+            Time::<UTC>::parse_rfc3339("2024-06-14T12:34:56.123456789Z").unwrap()
+        );
+        assert_eq!(Epoch::try_from(utc).unwrap(), epoch(TimeScale::UTC));
+
+        // The leap second 2016-12-31T23:59:60.25 UTC is 00:00:36.25 TAI,
+        // which hifitime labels 23:59:59.25 UTC.
+        let leap = Epoch::from_gregorian(2017, 1, 1, 0, 0, 36, 250_000_000, TimeScale::TAI);
+        assert_eq!(
+            leap.to_gregorian(TimeScale::UTC),
+            (2016, 12, 31, 23, 59, 59, 250_000_000)
+        );
+        let utc = Time::<UTC>::try_from(leap).unwrap();
+        assert_eq!(
+            utc,
+            Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60.25Z").unwrap()
+        );
+        assert_eq!(
+            Epoch::try_from(utc),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+        assert_eq!(Epoch::try_from(utc.to::<TAI>()), Ok(leap));
+    }
+
+    #[test]
+    fn tdb_and_et_models_differ_by_microseconds() {
+        // Same TT instant, moved to TDB/ET by each crate's own model.
+        let tt = Time::<TT>::try_from(epoch(TimeScale::TT)).unwrap();
+        let hifitime_tdb = Time::<TDB>::try_from(epoch(TimeScale::TT)).unwrap();
+        let hifitime_et = Time::<ET>::try_from(epoch(TimeScale::TT)).unwrap();
+        let tdb_gap = nanos_between(hifitime_tdb, tt.to::<TDB>()).abs();
+        let et_gap = nanos_between(hifitime_et, tt.to::<ET>()).abs();
+        assert!(tdb_gap > 0 && tdb_gap < 50_000, "TDB gap {tdb_gap} ns");
+        assert!(et_gap > 0 && et_gap < 50_000, "ET gap {et_gap} ns");
+
+        // A TDB label itself is carried over exactly.
+        let tdb = Time::<TDB>::try_from(epoch(TimeScale::TDB)).unwrap();
+        assert_eq!(Epoch::try_from(tdb).unwrap(), epoch(TimeScale::TDB));
+    }
+}
@@ -3,7 +3,7 @@
 
 //! `FormatForScale` / `InfallibleFormatForScale` for built-in format markers.
 
-use super::markers::{J2000s, Unix, GPS, JD, MJD};
+use super::markers::{J2000s, Unix, UnixRight, GPS, JD, MJD};
 use super::traits::{FormatForScale, InfallibleFormatForScale};
 use crate::earth::context::TimeContext;
 use crate::encoding::{day_to_j2000_seconds, j2000_seconds_to_day};
@@ -141,3 +141,61 @@ impl InfallibleFormatForScale<TAI> for GPS {
             .reinterpret()
     }
 }
+
+impl FormatForScale<TAI> for UnixRight {
+    #[inline]
+    fn try_from_time<Fin: TimeFormat>(
+        time: Time<TAI, Fin>,
+        _ctx: &TimeContext,
+    ) -> Result<Second, ConversionError> {
+        Ok(<Self as InfallibleFormatForScale<TAI>>::from_time(time))
+    }
+
+    #[inline]
+    fn try_into_time(raw: Second, _ctx: &TimeContext) -> Result<Time<TAI, Self>, ConversionError> {
+        Ok(<Self as InfallibleFormatForScale<TAI>>::into_time(raw))
+    }
+}
+
+impl InfallibleFormatForScale<TAI> for UnixRight {
+    #[inline]
+    fn from_time<Fin: TimeFormat>(time: Time<TAI, Fin>) -> Second {
+        time.raw_unix_right_seconds()
+    }
+
+    #[inline]
+    fn into_time(raw: Second) -> Time<TAI, Self> {
+        Time::<TAI, J2000s>::from_raw_unix_right_seconds(raw)
+            .expect("finite right/ Unix seconds must decode")
+            .reinterpret()
+    }
+}
+
+/// `Time<UTC>` shares the TAI storage axis, so `right/` seconds need no
+/// leap-second table lookup and `23:59:60` round-trips.
+impl FormatForScale<UTC> for UnixRight {
+    #[inline]
+    fn try_from_time<Fin: TimeFormat>(
+        time: Time<UTC, Fin>,
+        _ctx: &TimeContext,
+    ) -> Result<Second, ConversionError> {
+        Ok(<Self as InfallibleFormatForScale<UTC>>::from_time(time))
+    }
+
+    #[inline]
+    fn try_into_time(raw: Second, _ctx: &TimeContext) -> Result<Time<UTC, Self>, ConversionError> {
+        Ok(<Self as InfallibleFormatForScale<UTC>>::into_time(raw))
+    }
+}
+
+impl InfallibleFormatForScale<UTC> for UnixRight {
+    #[inline]
+    fn from_time<Fin: TimeFormat>(time: Time<UTC, Fin>) -> Second {
+        time.to_scale::<TAI>().raw_unix_right_seconds()
+    }
+
+    #[inline]
+    fn into_time(raw: Second) -> Time<UTC, Self> {
+        <Self as InfallibleFormatForScale<TAI>>::into_time(raw).to_scale::<UTC>()
+    }
+}
@@ -2,10 +2,12 @@
 // Copyright (C) 2026 Vallés Puig, Ramon
 
 //! ISO 8601 / RFC 3339 / RFC 2822 parsing and formatting for `Time<UTC>`
-//! and (via scale conversion) `Time<TAI>`.
+//! and (via scale conversion) `Time<TAI>`, plus the CCSDS ASCII time codes
+//! A (calendar date) and B (day of year).
 //!
-//! The civil layer is `chrono`-backed today (chrono is a hard dependency
-//! of `tempoch-core`); this module wraps the conversion to provide:
+//! Labels come from [`crate::MjdSod`] and the UTC-TAI table, not `chrono`
+//! (which is an optional dependency), so parsing and formatting are exact
+//! to the nanosecond. This module provides:
 //!
 //! * Subsecond precision configurable from 0..9 digits.
 //! * `FormatPrecision::{Truncate, RoundHalfToEven}` rounding policy.
@@ -13,8 +15,19 @@
 //!   instant lands during an announced positive leap second, and accepted on
 //!   parse.
 //! * A small `FormatOptions` value type so callers can opt into different
-//!   subsecond/leap-second/timezone formatting policies without affecting
-//!   the existing `chrono` bridge.
+//!   subsecond/leap-second/timezone formatting policies.
+//! * Numeric UTC offsets (`+05:30`, `-08:00`, and `-00:00` for "offset
+//!   unknown") on parse and format via [`UtcOffset`]. A leap second keeps
+//!   its `:60` label at whatever local minute it falls on.
+//!
+//! The CCSDS ASCII codes share the same labelling, so they round-trip
+//! nanoseconds exactly.
+//!
+//! Every [`ContinuousScale`] (TT, TAI, TDB, GPST, …) also gets
+//! [`Time::format_iso`] / [`Time::parse_iso`]: a proleptic Gregorian
+//! breakdown of the scale's own axis into 86 400-second days, written as
+//! `2024-01-01T00:00:00 TT`. These labels have no leap seconds, so `:60` is
+//! rejected.
 //!
 //! The conversion goes through `Time<UTC, J2000s>` storage, so the
 //! resulting instants are usable on any scale via the unified
@@ -31,12 +44,12 @@
 //! assert!(s.starts_with("2024-06-15T12:34:56.789"));
 //! ```
 
-use chrono::{DateTime, NaiveDateTime, Utc};
-
-use crate::data::runtime_data::time_data_tai_seconds_is_in_leap_window;
 use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::format::{CalendarScale, MjdSod};
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
 use crate::foundation::error::ConversionError;
-use crate::model::scale::UTC;
+use crate::model::scale::{ContinuousScale, UTC};
 use crate::model::time::Time;
 
 /// Subsecond rounding policy used by the formatter.
@@ -57,9 +70,13 @@ pub struct FormatOptions {
     /// Rounding policy when truncating below the requested precision.
     pub precision: FormatPrecision,
     /// When true, emit the trailing `Z` (RFC 3339); when false, emit no
-    /// timezone suffix (bare ISO 8601 naive datetime). UTC offsets other
-    /// than `Z` are not supported because the underlying scale is UTC.
+    /// timezone suffix (bare ISO 8601 naive datetime). Ignored when
+    /// [`utc_offset`](Self::utc_offset) is set. Continuous-scale labels
+    /// ([`Time::format_iso`]) emit ` <SCALE>` instead of `Z`.
     pub include_zulu: bool,
+    /// When set, RFC 3339 output is written as local time at this offset
+    /// followed by `±hh:mm` instead of `Z`. Other formatters ignore it.
+    pub utc_offset: Option<UtcOffset>,
 }
 
 impl FormatOptions {
@@ -68,6 +85,7 @@ impl FormatOptions {
         subsecond_digits: 0,
         precision: FormatPrecision::Truncate,
         include_zulu: true,
+        utc_offset: None,
     };
 
     /// Milliseconds resolution (3 fractional digits).
@@ -76,6 +94,7 @@ impl FormatOptions {
             subsecond_digits: 3,
             precision: FormatPrecision::RoundHalfToEven,
             include_zulu: true,
+            utc_offset: None,
         }
     }
 
@@ -85,6 +104,7 @@ impl FormatOptions {
             subsecond_digits: 6,
             precision: FormatPrecision::RoundHalfToEven,
             include_zulu: true,
+            utc_offset: None,
         }
     }
 
@@ -94,6 +114,15 @@ impl FormatOptions {
             subsecond_digits: 9,
             precision: FormatPrecision::RoundHalfToEven,
             include_zulu: true,
+            utc_offset: None,
+        }
+    }
+
+    /// Write RFC 3339 output at `offset` instead of `Z`.
+    pub const fn with_utc_offset(self, offset: UtcOffset) -> Self {
+        Self {
+            utc_offset: Some(offset),
+            ..self
         }
     }
 }
@@ -104,10 +133,96 @@ impl Default for FormatOptions {
     }
 }
 
+/// Numeric UTC offset of an RFC 3339 timestamp, in whole minutes.
+///
+/// [`UtcOffset::UNKNOWN`] is the RFC 3339 §4.3 `-00:00` form: the time is
+/// UTC but the local offset is not known.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UtcOffset {
+    minutes: i16,
+    unknown: bool,
+}
+
+impl UtcOffset {
+    /// `+00:00`.
+    pub const UTC: Self = Self {
+        minutes: 0,
+        unknown: false,
+    };
+
+    /// `-00:00`: UTC with an unknown local offset.
+    pub const UNKNOWN: Self = Self {
+        minutes: 0,
+        unknown: true,
+    };
+
+    /// Offset of `minutes` east of UTC.
+    ///
+    /// Returns [`ConversionError::OutOfRange`] unless `|minutes| < 1440`.
+    pub const fn from_minutes(minutes: i32) -> Result<Self, ConversionError> {
+        if minutes <= -1_440 || minutes >= 1_440 {
+            return Err(ConversionError::OutOfRange);
+        }
+        Ok(Self {
+            minutes: minutes as i16,
+            unknown: false,
+        })
+    }
+
+    /// Minutes east of UTC (zero for [`UtcOffset::UNKNOWN`]).
+    #[inline]
+    pub const fn minutes(self) -> i32 {
+        self.minutes as i32
+    }
+
+    /// `true` for the `-00:00` form.
+    #[inline]
+    pub const fn is_unknown(self) -> bool {
+        self.unknown
+    }
+}
+
+impl core::fmt::Display for UtcOffset {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let sign = if self.minutes < 0 || self.unknown {
+            '-'
+        } else {
+            '+'
+        };
+        let abs = self.minutes.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", abs / 60, abs % 60)
+    }
+}
+
+/// Parse an RFC 3339 zone designator: `Z`/`z` or `±hh:mm`.
+fn parse_utc_offset(zone: &str) -> Result<UtcOffset, ConversionError> {
+    if zone == "Z" || zone == "z" {
+        return Ok(UtcOffset::UTC);
+    }
+    if zone == "-00:00" {
+        return Ok(UtcOffset::UNKNOWN);
+    }
+    let (sign, hhmm) = match zone.as_bytes().first() {
+        Some(b'+') => (1, &zone[1..]),
+        Some(b'-') => (-1, &zone[1..]),
+        _ => return Err(ConversionError::OutOfRange),
+    };
+    let (hh, mm) = hhmm.split_once(':').ok_or(ConversionError::OutOfRange)?;
+    if hh.len() != 2 || mm.len() != 2 {
+        return Err(ConversionError::OutOfRange);
+    }
+    let (hours, minutes) = (parse_digits(hh)?, parse_digits(mm)?);
+    if hours >= 24 || minutes >= 60 {
+        return Err(ConversionError::OutOfRange);
+    }
+    UtcOffset::from_minutes(sign * (hours * 60 + minutes) as i32)
+}
+
 /// Parse an RFC 3339 timestamp into the canonical UTC `J2000s` storage.
 ///
-/// Accepts the leap-second form `23:59:60[.x]` during announced positive
-/// leap seconds; rejects it otherwise.
+/// The zone may be `Z` or a numeric offset (`+05:30`, `-08:00`, `-00:00`).
+/// Accepts a `:60` second wherever the offset puts an announced positive
+/// leap second (e.g. `2017-01-01T05:29:60+05:30`); rejects it otherwise.
 #[inline]
 pub fn parse_rfc3339_utc(s: &str) -> Result<Time<UTC>, ConversionError> {
     parse_rfc3339_utc_with(s, &TimeContext::new())
@@ -115,30 +230,11 @@ pub fn parse_rfc3339_utc(s: &str) -> Result<Time<UTC>, ConversionError> {
 
 /// Like [`parse_rfc3339_utc`], but uses an explicit [`TimeContext`].
 pub fn parse_rfc3339_utc_with(s: &str, ctx: &TimeContext) -> Result<Time<UTC>, ConversionError> {
-    // Pre-validate: reject more than 9 fractional digits before passing to chrono.
-    if let Some(after_dot) = s.find('.') {
-        // Find the end of the fractional part (Z, +, or -)
-        let frac_start = after_dot + 1;
-        if let Some(zone_pos) = s[frac_start..].find(['Z', '+', '-']) {
-            let frac_len = zone_pos;
-            if frac_len == 0 {
-                return Err(ConversionError::OutOfRange);
-            }
-            if frac_len > 9 {
-                return Err(ConversionError::OutOfRange);
-            }
-        }
-    }
-
-    // Try `chrono::DateTime::parse_from_rfc3339` first; it accepts a wide range of valid forms.
-    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
-        let utc = dt.with_timezone(&Utc);
-        return Time::<UTC>::try_from_chrono_with(utc, ctx);
+    // `YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)`, labelled exactly through
+    // `MjdSod` so leap seconds and nanoseconds survive.
+    if !s.is_ascii() {
+        return Err(ConversionError::OutOfRange);
     }
-
-    // chrono rejects ":60" in the seconds field except via try_parse paths;
-    // fall back to a manual leap-second-aware parser for the standard form
-    //   YYYY-MM-DDTHH:MM:SS[.fraction](Z|±HH:MM)
     parse_rfc3339_manual(s, ctx)
 }
 
@@ -150,84 +246,55 @@ fn parse_rfc3339_manual(s: &str, ctx: &TimeContext) -> Result<Time<UTC>, Convers
     let bytes = s.as_bytes();
     if bytes[4] != b'-'
         || bytes[7] != b'-'
-        || (bytes[10] != b'T' && bytes[10] != b' ')
+        || !matches!(bytes[10], b'T' | b't' | b' ')
         || bytes[13] != b':'
         || bytes[16] != b':'
     {
         return Err(ConversionError::OutOfRange);
     }
-    let year: i32 = s[..4].parse().map_err(|_| ConversionError::OutOfRange)?;
-    let month: u32 = s[5..7].parse().map_err(|_| ConversionError::OutOfRange)?;
-    let day: u32 = s[8..10].parse().map_err(|_| ConversionError::OutOfRange)?;
-    let hour: u32 = s[11..13].parse().map_err(|_| ConversionError::OutOfRange)?;
-    let minute: u32 = s[14..16].parse().map_err(|_| ConversionError::OutOfRange)?;
-    let second_str = &s[17..19];
-    let second: u32 = second_str
-        .parse()
-        .map_err(|_| ConversionError::OutOfRange)?;
+    let mjd = encoding::mjd_from_gregorian(
+        parse_digits(&s[..4])? as i64,
+        parse_digits(&s[5..7])?,
+        parse_digits(&s[8..10])?,
+    )
+    .ok_or(ConversionError::OutOfRange)?;
+    let hour = parse_digits(&s[11..13])?;
+    let minute = parse_digits(&s[14..16])?;
+    let second = parse_digits(s.get(17..19).ok_or(ConversionError::OutOfRange)?)?;
+    if hour >= 24 || minute >= 60 {
+        return Err(ConversionError::OutOfRange);
+    }
 
     // Trailing portion may be: [.fraction][Z|±HH:MM]
-    let tail = &s[19..];
+    let tail = s.get(19..).ok_or(ConversionError::OutOfRange)?;
     let (frac_str, zone_str) = split_fraction_and_zone(tail)?;
     let frac_nanos = parse_fraction_nanos(frac_str)?;
+    let offset = parse_utc_offset(zone_str)?;
 
-    if zone_str != "Z" {
-        // Only Z is supported in this path (the chrono fast path covers the
-        // general timezone case).
-        return Err(ConversionError::OutOfRange);
-    }
-
-    // Handle leap-second labelling: second == 60 must occur during an
-    // announced positive leap second on this UTC date.
-    if second == 60 {
-        // Construct the instant at HH:59:59.999999999 and add 1s−frac.
-        let base = NaiveDateTime::parse_from_str(
-            &format!("{year:04}-{month:02}-{day:02}T{hour:02}:59:59.999999999"),
-            "%Y-%m-%dT%H:%M:%S%.9f",
-        )
-        .map_err(|_| ConversionError::OutOfRange)?
-        .and_utc();
-        let utc_almost = Time::<UTC>::try_from_chrono_with(base, ctx)?;
-        // 1ns nudge → instant equivalent to HH:60:00, leap-second second; then add `frac_nanos` ns.
-        let shifted =
-            utc_almost.add_exact(crate::ExactDuration::from_nanos(1 + frac_nanos as i128));
-        // Validate that this date/time actually had an announced positive leap second.
-        if !time_data_tai_seconds_is_in_leap_window(
-            ctx.time_data(),
-            shifted.to_j2000s().total_seconds(),
-        ) {
-            return Err(ConversionError::InvalidLeapSecond);
-        }
-        return Ok(shifted);
-    }
-
-    if second >= 60 || minute >= 60 || hour >= 24 {
-        return Err(ConversionError::OutOfRange);
-    }
+    let (mjd, clock) = shift_minutes(mjd, [hour, minute, second], -offset.minutes() as i64);
+    time_from_clock(mjd, clock, frac_nanos, ctx)
+}
 
-    let naive_str = if frac_nanos == 0 {
-        format!("{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}")
-    } else {
-        format!(
-            "{year:04}-{month:02}-{day:02}T{hour:02}:{minute:02}:{second:02}.{:09}",
-            frac_nanos
-        )
-    };
-    let parsed = if frac_nanos == 0 {
-        NaiveDateTime::parse_from_str(&naive_str, "%Y-%m-%dT%H:%M:%S")
-    } else {
-        NaiveDateTime::parse_from_str(&naive_str, "%Y-%m-%dT%H:%M:%S%.9f")
-    }
-    .map_err(|_| ConversionError::OutOfRange)?
-    .and_utc();
-    Time::<UTC>::try_from_chrono_with(parsed, ctx)
+/// Move the `hh:mm` of a label on day `mjd` by `minutes`, keeping the
+/// second (including `:60`) so a leap second stays on its shifted minute.
+pub(crate) fn shift_minutes(
+    mjd: i64,
+    [hour, minute, second]: [u32; 3],
+    minutes: i64,
+) -> (i64, [u32; 3]) {
+    let total = mjd * 1_440 + (hour * 60 + minute) as i64 + minutes;
+    let minute_of_day = total.rem_euclid(1_440) as u32;
+    (
+        total.div_euclid(1_440),
+        [minute_of_day / 60, minute_of_day % 60, second],
+    )
 }
 
 fn split_fraction_and_zone(tail: &str) -> Result<(&str, &str), ConversionError> {
     if let Some(stripped) = tail.strip_prefix('.') {
         // fraction up to the timezone delimiter (Z, +, -)
         let zone_pos = stripped
-            .find(['Z', '+', '-'])
+            .find(['Z', 'z', '+', '-'])
             .ok_or(ConversionError::OutOfRange)?;
         let frac = &stripped[..zone_pos];
         // Reject empty fraction: "2024-06-15T12:34:56.Z" is not valid RFC 3339.
@@ -241,7 +308,7 @@ fn split_fraction_and_zone(tail: &str) -> Result<(&str, &str), ConversionError>
     }
 }
 
-fn parse_fraction_nanos(s: &str) -> Result<u32, ConversionError> {
+pub(crate) fn parse_fraction_nanos(s: &str) -> Result<u32, ConversionError> {
     if s.is_empty() {
         return Ok(0);
     }
@@ -258,9 +325,9 @@ fn parse_fraction_nanos(s: &str) -> Result<u32, ConversionError> {
 }
 
 impl Time<UTC> {
-    /// Parse an RFC 3339 / ISO 8601 timestamp (UTC, `Z` suffix or named
-    /// offset). Accepts the leap-second form `23:59:60[.x]` during
-    /// announced positive leap seconds.
+    /// Parse an RFC 3339 / ISO 8601 timestamp (UTC, `Z` suffix or numeric
+    /// offset). Accepts a `:60` second during announced positive leap
+    /// seconds, at whatever local minute the offset puts them.
     #[inline]
     pub fn parse_rfc3339(s: &str) -> Result<Self, ConversionError> {
         parse_rfc3339_utc(s)
@@ -276,7 +343,10 @@ impl Time<UTC> {
     /// Format this UTC instant as RFC 3339 with the given options.
     ///
     /// Emits `23:59:60[.fraction]Z` when the instant lies during an announced
-    /// positive leap second according to the default [`TimeContext`].
+    /// positive leap second according to the default [`TimeContext`]. With
+    /// [`FormatOptions::utc_offset`] set, the local time and `±hh:mm` are
+    /// written instead, and the leap second stays `:60` (e.g.
+    /// `2017-01-01T05:29:60+05:30`).
     pub fn format_rfc3339(&self, opts: FormatOptions) -> String {
         self.format_rfc3339_with(opts, &TimeContext::new())
     }
@@ -296,19 +366,332 @@ impl Time<UTC> {
 
     /// Fallible variant of [`format_rfc3339_with`](Self::format_rfc3339_with).
     ///
-    /// Returns [`ConversionError`] if the underlying UTC↔chrono conversion
-    /// fails (e.g. out-of-range dates).
+    /// Returns [`ConversionError`] if the instant cannot be labelled in UTC,
+    /// and [`ConversionError::OutOfRange`] for (local) years outside
+    /// `0000..=9999`.
     pub fn try_format_rfc3339_with(
         &self,
         opts: FormatOptions,
         ctx: &TimeContext,
     ) -> Result<String, ConversionError> {
-        // Use the explicit table/context-driven check as the authoritative source
-        // for leap-second detection. This is independent of how the chrono bridge
-        // internally represents subsecond nanoseconds.
-        let is_leap = self.is_leap_second_with(ctx);
-        let dt = self.try_to_chrono_with(ctx)?;
-        format_utc_datetime_rfc3339(dt, is_leap, opts)
+        format_rfc3339_label(*self, opts, ctx)
+    }
+}
+
+/// RFC 3339 label in UTC, or local time at `opts.utc_offset`. Only the
+/// minute is shifted, so a leap second keeps its `:60` label.
+fn format_rfc3339_label(
+    time: Time<UTC>,
+    opts: FormatOptions,
+    ctx: &TimeContext,
+) -> Result<String, ConversionError> {
+    let label = rounded_label(time, opts, ctx)?;
+    let shift = opts.utc_offset.map_or(0, |offset| offset.minutes() as i64);
+    let (mjd, clock) = shift_minutes(label.mjd, label.clock, shift);
+    let local = RoundedLabel {
+        mjd,
+        clock,
+        ..label
+    };
+    let (year, month, day) = encoding::gregorian_from_mjd(local.mjd);
+    if !(0..=9_999).contains(&year) {
+        return Err(ConversionError::OutOfRange);
+    }
+    let zone = match opts.utc_offset {
+        Some(offset) => offset.to_string(),
+        None if opts.include_zulu => "Z".to_string(),
+        None => String::new(),
+    };
+    Ok(format!(
+        "{year:04}-{month:02}-{day:02}T{}{zone}",
+        local.clock_string()
+    ))
+}
+
+/// CCSDS ASCII time code variant (CCSDS 301.0-B-4 §3.5).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CcsdsAsciiCode {
+    /// Code A, calendar date: `YYYY-MM-DDThh:mm:ss.d→dZ`.
+    A,
+    /// Code B, day of year: `YYYY-DDDThh:mm:ss.d→dZ`.
+    B,
+}
+
+/// Parse a CCSDS ASCII time code (A or B, detected from the date field).
+///
+/// The time of day may be truncated on the right (`hh`, `hh:mm`, or omitted
+/// together with the `T`), and the trailing `Z` is optional. Accepts
+/// `23:59:60[.x]` during announced positive leap seconds; rejects it
+/// otherwise.
+#[inline]
+pub fn parse_ccsds_ascii_utc(s: &str) -> Result<Time<UTC>, ConversionError> {
+    parse_ccsds_ascii_utc_with(s, &TimeContext::new())
+}
+
+/// Like [`parse_ccsds_ascii_utc`], but uses an explicit [`TimeContext`].
+pub fn parse_ccsds_ascii_utc_with(
+    s: &str,
+    ctx: &TimeContext,
+) -> Result<Time<UTC>, ConversionError> {
+    let s = s.strip_suffix('Z').unwrap_or(s);
+    let (mjd, clock, frac_nanos) = parse_calendar_fields(s)?;
+    time_from_clock(mjd, clock, frac_nanos, ctx)
+}
+
+/// Split `YYYY-MM-DD[Thh[:mm[:ss[.f]]]]` or `YYYY-DDD[T…]` into the day,
+/// clock fields, and subsecond nanoseconds. No zone designator is accepted.
+fn parse_calendar_fields(s: &str) -> Result<(i64, [u32; 3], u32), ConversionError> {
+    let (date, time) = s.split_once('T').unwrap_or((s, ""));
+    let mjd = match date.as_bytes() {
+        [_, _, _, _, b'-', _, _, b'-', _, _] => encoding::mjd_from_gregorian(
+            parse_digits(&date[..4])? as i64,
+            parse_digits(&date[5..7])?,
+            parse_digits(&date[8..10])?,
+        ),
+        [_, _, _, _, b'-', _, _, _] => encoding::mjd_from_year_day(
+            parse_digits(&date[..4])? as i64,
+            parse_digits(&date[5..8])?,
+        ),
+        _ => None,
+    }
+    .ok_or(ConversionError::OutOfRange)?;
+
+    let (clock, frac_str) = time.split_once('.').unwrap_or((time, ""));
+    if time.contains('.') && (frac_str.is_empty() || clock.len() != 8) {
+        return Err(ConversionError::OutOfRange);
+    }
+    let mut fields = [0_u32; 3];
+    if s.contains('T') {
+        let parts: Vec<&str> = clock.split(':').collect();
+        if parts.len() > 3 || parts.iter().any(|p| p.len() != 2) {
+            return Err(ConversionError::OutOfRange);
+        }
+        for (field, part) in fields.iter_mut().zip(parts) {
+            *field = parse_digits(part)?;
+        }
+    }
+    Ok((mjd, fields, parse_fraction_nanos(frac_str)?))
+}
+
+/// Build the instant labelled `hh:mm:ss` plus `frac_nanos` on day `mjd`.
+///
+/// `ss = 60` is accepted only at `23:59`; whether the day has a leap second
+/// is checked by [`CalendarScale`].
+pub(crate) fn time_from_clock<S: CalendarScale>(
+    mjd: i64,
+    [hour, minute, second]: [u32; 3],
+    frac_nanos: u32,
+    ctx: &TimeContext,
+) -> Result<Time<S>, ConversionError> {
+    if hour >= 24 || minute >= 60 || second > 60 {
+        return Err(ConversionError::OutOfRange);
+    }
+    if second == 60 && (hour, minute) != (23, 59) {
+        return Err(ConversionError::InvalidLeapSecond);
+    }
+    let second_of_day =
+        (hour * 3_600 + minute * 60 + second) as i128 * NANOS_PER_SECOND + frac_nanos as i128;
+    let label = MjdSod::new(
+        qtty::i64::Day::new(mjd),
+        ExactDuration::from_nanos(second_of_day),
+    )?;
+    Time::<S>::from_mjd_sod_with(label, ctx)
+}
+
+/// Calendar label of an instant after subsecond rounding.
+pub(crate) struct RoundedLabel {
+    pub(crate) mjd: i64,
+    /// `[hour, minute, second]`, with `second = 60` during a leap second.
+    pub(crate) clock: [u32; 3],
+    /// Subsecond value at `digits` digits.
+    pub(crate) frac: u32,
+    pub(crate) digits: usize,
+}
+
+impl RoundedLabel {
+    /// `hh:mm:ss[.f]` followed by nothing else.
+    pub(crate) fn clock_string(&self) -> String {
+        let [hour, minute, second] = self.clock;
+        let digits = self.digits;
+        if digits == 0 {
+            format!("{hour:02}:{minute:02}:{second:02}")
+        } else {
+            format!("{hour:02}:{minute:02}:{second:02}.{:0digits$}", self.frac)
+        }
+    }
+}
+
+/// Label `time` on its scale's calendar, rounding the subsecond part per
+/// `opts`. A carry moves to the next labelled second, which may be `:60`.
+pub(crate) fn rounded_label<S: CalendarScale>(
+    time: Time<S>,
+    opts: FormatOptions,
+    ctx: &TimeContext,
+) -> Result<RoundedLabel, ConversionError> {
+    let digits = opts.subsecond_digits.min(9) as usize;
+    let label = time.to_mjd_sod_with(ctx)?;
+    let nanos_of_second = (label.second_of_day.as_nanos_i128() % NANOS_PER_SECOND) as u32;
+    let (frac, carry) = round_subsecond(nanos_of_second, digits, opts.precision);
+    let label = if carry {
+        time.try_add_exact(ExactDuration::from_nanos(
+            (NANOS_PER_SECOND as u32 - nanos_of_second) as i128,
+        ))
+        .map_err(|_| ConversionError::OutOfRange)?
+        .to_mjd_sod_with(ctx)?
+    } else {
+        label
+    };
+    let second_of_day = (label.second_of_day.as_nanos_i128() / NANOS_PER_SECOND) as u32;
+    let clock = if second_of_day >= 86_400 {
+        [23, 59, 60]
+    } else {
+        [
+            second_of_day / 3_600,
+            second_of_day / 60 % 60,
+            second_of_day % 60,
+        ]
+    };
+    Ok(RoundedLabel {
+        mjd: label.mjd.value(),
+        clock,
+        frac,
+        digits,
+    })
+}
+
+/// Parse an unsigned all-digit field.
+pub(crate) fn parse_digits(s: &str) -> Result<u32, ConversionError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ConversionError::OutOfRange);
+    }
+    s.parse().map_err(|_| ConversionError::OutOfRange)
+}
+
+impl Time<UTC> {
+    /// Parse a CCSDS ASCII time code A or B. See [`parse_ccsds_ascii_utc`].
+    #[inline]
+    pub fn parse_ccsds_ascii(s: &str) -> Result<Self, ConversionError> {
+        parse_ccsds_ascii_utc(s)
+    }
+
+    /// Like [`parse_ccsds_ascii`](Self::parse_ccsds_ascii), with an explicit
+    /// [`TimeContext`].
+    #[inline]
+    pub fn parse_ccsds_ascii_with(s: &str, ctx: &TimeContext) -> Result<Self, ConversionError> {
+        parse_ccsds_ascii_utc_with(s, ctx)
+    }
+
+    /// Format as CCSDS ASCII time code A or B with the given options.
+    ///
+    /// Subseconds follow the same [`FormatOptions`] policy as
+    /// [`format_rfc3339`](Self::format_rfc3339), and leap-second instants are
+    /// emitted as `23:59:60[.fraction]`.
+    pub fn format_ccsds_ascii(&self, code: CcsdsAsciiCode, opts: FormatOptions) -> String {
+        self.format_ccsds_ascii_with(code, opts, &TimeContext::new())
+    }
+
+    /// Like [`format_ccsds_ascii`](Self::format_ccsds_ascii), with an
+    /// explicit [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the instant cannot be labelled; use
+    /// [`try_format_ccsds_ascii_with`](Self::try_format_ccsds_ascii_with) to
+    /// handle that case explicitly.
+    pub fn format_ccsds_ascii_with(
+        &self,
+        code: CcsdsAsciiCode,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> String {
+        match self.try_format_ccsds_ascii_with(code, opts, ctx) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of
+    /// [`format_ccsds_ascii_with`](Self::format_ccsds_ascii_with).
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for years outside `0000..=9999`.
+    pub fn try_format_ccsds_ascii_with(
+        &self,
+        code: CcsdsAsciiCode,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> Result<String, ConversionError> {
+        let label = rounded_label(*self, opts, ctx)?;
+        let (year, month, day) = encoding::gregorian_from_mjd(label.mjd);
+        if !(0..=9_999).contains(&year) {
+            return Err(ConversionError::OutOfRange);
+        }
+        let mut out = match code {
+            CcsdsAsciiCode::A => format!("{year:04}-{month:02}-{day:02}"),
+            CcsdsAsciiCode::B => {
+                let (_, ordinal) = encoding::year_day_from_mjd(label.mjd);
+                format!("{year:04}-{ordinal:03}")
+            }
+        };
+        out.push('T');
+        out.push_str(&label.clock_string());
+        if opts.include_zulu {
+            out.push('Z');
+        }
+        Ok(out)
+    }
+}
+
+impl<S: ContinuousScale + CalendarScale> Time<S> {
+    /// Parse an ISO 8601 calendar label on this scale's own axis, e.g.
+    /// `2024-01-01T00:00:00 TT`.
+    ///
+    /// Accepts the same date and time fields as
+    /// [`parse_ccsds_ascii_utc`] (calendar or ordinal date, truncated time,
+    /// up to 9 fractional digits), followed by an optional space and the
+    /// scale name. Days are exactly 86 400 s on the proleptic Gregorian
+    /// calendar, so `23:59:60` is rejected, as are `Z` and other scales'
+    /// names.
+    pub fn parse_iso(s: &str) -> Result<Self, ConversionError> {
+        let label = match s.rsplit_once(' ') {
+            Some((label, name)) if name == S::NAME => label,
+            Some(_) => return Err(ConversionError::OutOfRange),
+            None => s,
+        };
+        let (mjd, clock, frac_nanos) = parse_calendar_fields(label)?;
+        if clock[2] >= 60 {
+            return Err(ConversionError::OutOfRange);
+        }
+        time_from_clock(mjd, clock, frac_nanos, &TimeContext::new())
+    }
+
+    /// Format as an ISO 8601 calendar label on this scale's own axis.
+    ///
+    /// Subseconds follow [`FormatOptions`]; `include_zulu` selects the
+    /// ` <SCALE>` suffix (e.g. ` TT`) in place of `Z`.
+    ///
+    /// Returns `"<invalid>"` if the instant cannot be labelled; use
+    /// [`try_format_iso`](Self::try_format_iso) to handle that case
+    /// explicitly.
+    pub fn format_iso(&self, opts: FormatOptions) -> String {
+        match self.try_format_iso(opts) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of [`format_iso`](Self::format_iso).
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for years outside `0000..=9999`.
+    pub fn try_format_iso(&self, opts: FormatOptions) -> Result<String, ConversionError> {
+        let label = rounded_label(*self, opts, &TimeContext::new())?;
+        let (year, month, day) = encoding::gregorian_from_mjd(label.mjd);
+        if !(0..=9_999).contains(&year) {
+            return Err(ConversionError::OutOfRange);
+        }
+        let mut out = format!("{year:04}-{month:02}-{day:02}T{}", label.clock_string());
+        if opts.include_zulu {
+            out.push(' ');
+            out.push_str(S::NAME);
+        }
+        Ok(out)
     }
 }
 
@@ -337,74 +720,6 @@ fn round_subsecond(nanos: u32, digits: usize, precision: FormatPrecision) -> (u3
     (result, carry)
 }
 
-/// Format a `DateTime<Utc>` as RFC 3339, applying uniform rounding and
-/// emitting `23:59:60` for leap-second instants.
-///
-/// The `is_leap` flag is the authoritative signal: it must be supplied by the
-/// caller via `Time<UTC>::is_leap_second_with(ctx)`, which consults the compiled
-/// UTC–TAI table. Chrono must represent the leap-second instant with
-/// `timestamp_subsec_nanos() >= 1_000_000_000`; if it does not, the function
-/// returns `Err(ConversionError::InvalidLeapSecond)` to avoid silently producing
-/// an incorrect fractional value.
-fn format_utc_datetime_rfc3339(
-    dt: DateTime<Utc>,
-    is_leap: bool,
-    opts: FormatOptions,
-) -> Result<String, crate::foundation::error::ConversionError> {
-    use crate::foundation::error::ConversionError;
-    let digits = opts.subsecond_digits.min(9) as usize;
-    let raw_nanos = dt.timestamp_subsec_nanos();
-
-    if is_leap {
-        // chrono encodes leap-second instants with timestamp_subsec_nanos() ≥ 1_000_000_000.
-        // If that invariant is violated, the fractional position within the leap second
-        // cannot be reliably derived; return an error rather than silently producing
-        // a wrong value.
-        if raw_nanos < 1_000_000_000 {
-            return Err(ConversionError::InvalidLeapSecond);
-        }
-        let leap_nanos = raw_nanos - 1_000_000_000;
-        let (frac, carry) = round_subsecond(leap_nanos, digits, opts.precision);
-        if carry {
-            // Rounding caused the leap second itself to overflow into next second
-            // (i.e. 23:59:60.999999500 rounded up to 23:59:61 → 2017-01-01T00:00:00).
-            let next = dt + chrono::TimeDelta::try_seconds(1).unwrap_or_default();
-            return Ok(format_normal_dt(next, 0, digits, opts));
-        }
-        let date = dt.format("%Y-%m-%d");
-        if digits == 0 {
-            let zulu = if opts.include_zulu { "Z" } else { "" };
-            Ok(format!("{date}T23:59:60{zulu}"))
-        } else {
-            let zulu = if opts.include_zulu { "Z" } else { "" };
-            Ok(format!(
-                "{date}T23:59:60.{:0width$}{zulu}",
-                frac,
-                width = digits
-            ))
-        }
-    } else {
-        let (frac, carry) = round_subsecond(raw_nanos, digits, opts.precision);
-        let effective_dt = if carry {
-            dt + chrono::TimeDelta::try_seconds(1).unwrap_or_default()
-        } else {
-            dt
-        };
-        Ok(format_normal_dt(effective_dt, frac, digits, opts))
-    }
-}
-
-fn format_normal_dt(dt: DateTime<Utc>, frac: u32, digits: usize, opts: FormatOptions) -> String {
-    let base = dt.format("%Y-%m-%dT%H:%M:%S");
-    if digits == 0 {
-        let zulu = if opts.include_zulu { "Z" } else { "" };
-        format!("{base}{zulu}")
-    } else {
-        let zulu = if opts.include_zulu { "Z" } else { "" };
-        format!("{base}.{:0width$}{zulu}", frac, width = digits)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -466,6 +781,53 @@ mod tests {
         assert_eq!(s, "2016-12-31T23:59:60.500Z");
     }
 
+    #[test]
+    fn numeric_offsets_keep_leap_seconds_at_local_minute() {
+        let utc = Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60.5Z").unwrap();
+        let ist = Time::<UTC>::parse_rfc3339("2017-01-01T05:29:60.5+05:30").unwrap();
+        let pst = Time::<UTC>::parse_rfc3339("2016-12-31T15:59:60.5-08:00").unwrap();
+        assert_eq!(ist, utc);
+        assert_eq!(pst, utc);
+        assert!(matches!(
+            Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60+05:30"),
+            Err(ConversionError::InvalidLeapSecond)
+        ));
+        assert!(Time::<UTC>::parse_rfc3339("2016-12-31T12:34:60Z").is_err());
+
+        let opts = FormatOptions::milliseconds();
+        let offset = UtcOffset::from_minutes(330).unwrap();
+        assert_eq!(
+            utc.format_rfc3339(opts.with_utc_offset(offset)),
+            "2017-01-01T05:29:60.500+05:30"
+        );
+        let offset = UtcOffset::from_minutes(-480).unwrap();
+        assert_eq!(
+            utc.format_rfc3339(opts.with_utc_offset(offset)),
+            "2016-12-31T15:59:60.500-08:00"
+        );
+    }
+
+    #[test]
+    fn numeric_offsets_round_trip_and_unknown_offset() {
+        let t = Time::<UTC>::parse_rfc3339("2024-06-15T12:34:56-00:00").unwrap();
+        assert_eq!(
+            t,
+            Time::<UTC>::parse_rfc3339("2024-06-15T12:34:56Z").unwrap()
+        );
+        let opts = FormatOptions::SECONDS.with_utc_offset(UtcOffset::UNKNOWN);
+        assert_eq!(t.format_rfc3339(opts), "2024-06-15T12:34:56-00:00");
+
+        let offset = UtcOffset::from_minutes(-570).unwrap();
+        let s = t.format_rfc3339(FormatOptions::SECONDS.with_utc_offset(offset));
+        assert_eq!(s, "2024-06-15T03:04:56-09:30");
+        assert_eq!(Time::<UTC>::parse_rfc3339(&s).unwrap(), t);
+
+        assert_eq!(UtcOffset::UTC.to_string(), "+00:00");
+        assert!(UtcOffset::from_minutes(1_440).is_err());
+        assert!(parse_utc_offset("+24:00").is_err());
+        assert!(parse_utc_offset("+0530").is_err());
+    }
+
     #[test]
     fn reject_malformed_input() {
         assert!(Time::<UTC>::parse_rfc3339("not a date").is_err());
@@ -511,6 +873,7 @@ mod tests {
             subsecond_digits: 4,
             precision: FormatPrecision::Truncate,
             include_zulu: true,
+            utc_offset: None,
         };
         let s = t.format_rfc3339(opts);
         // 4-digit subsecond resolution survives the chrono-bridge drift (~150 ns).
@@ -525,11 +888,13 @@ mod tests {
             subsecond_digits: 1,
             precision: FormatPrecision::Truncate,
             include_zulu: true,
+            utc_offset: None,
         };
         let round = FormatOptions {
             subsecond_digits: 1,
             precision: FormatPrecision::RoundHalfToEven,
             include_zulu: true,
+            utc_offset: None,
         };
         let st = t.format_rfc3339(trunc);
         let sr = t.format_rfc3339(round);
@@ -545,6 +910,7 @@ mod tests {
             subsecond_digits: 0,
             precision: FormatPrecision::Truncate,
             include_zulu: false,
+            utc_offset: None,
         };
         let s = t.format_rfc3339(opts);
         assert_eq!(s, "2024-06-15T12:34:56");
@@ -574,6 +940,7 @@ mod tests {
             subsecond_digits: 0,
             precision: FormatPrecision::Truncate,
             include_zulu: true,
+            utc_offset: None,
         };
         let s = t.format_rfc3339(opts);
         assert_eq!(s, "2000-01-01T12:34:56Z");
@@ -587,6 +954,7 @@ mod tests {
             subsecond_digits: 0,
             precision: FormatPrecision::RoundHalfToEven,
             include_zulu: true,
+            utc_offset: None,
         };
         let s = t.format_rfc3339(opts);
         // .999 rounds to 1.0 → carry → 12:34:57
@@ -604,6 +972,7 @@ mod tests {
             subsecond_digits: 3,
             precision: FormatPrecision::RoundHalfToEven,
             include_zulu: true,
+            utc_offset: None,
         };
         let s = t.format_rfc3339(opts);
         assert_eq!(s, "2000-01-01T12:00:00.500Z", "got {s}");
@@ -644,4 +1013,126 @@ mod tests {
         assert!(!carry9);
         assert_eq!(v9, 999_999_999);
     }
+    #[test]
+    fn ccsds_ascii_a_and_b_round_trip_nanoseconds() {
+        let t = Time::<UTC>::parse_ccsds_ascii("2024-06-15T12:34:56.123456789Z").unwrap();
+        let b = t.format_ccsds_ascii(CcsdsAsciiCode::B, FormatOptions::nanoseconds());
+        assert_eq!(b, "2024-167T12:34:56.123456789Z");
+        assert_eq!(Time::<UTC>::parse_ccsds_ascii(&b).unwrap(), t);
+        let a = t.format_ccsds_ascii(CcsdsAsciiCode::A, FormatOptions::nanoseconds());
+        assert_eq!(a, "2024-06-15T12:34:56.123456789Z");
+        assert_eq!(
+            Time::<UTC>::parse_ccsds_ascii("2024-12-31T00:00Z").unwrap(),
+            Time::<UTC>::parse_ccsds_ascii("2024-366").unwrap()
+        );
+    }
+
+    #[test]
+    fn ccsds_ascii_accepts_leap_seconds_like_rfc3339() {
+        let t = Time::<UTC>::parse_ccsds_ascii("2016-366T23:59:60.5").unwrap();
+        assert!(t.is_leap_second());
+        assert_eq!(
+            t.format_ccsds_ascii(CcsdsAsciiCode::A, FormatOptions::milliseconds()),
+            "2016-12-31T23:59:60.500Z"
+        );
+        assert_eq!(
+            Time::<UTC>::parse_ccsds_ascii("2023-166T23:59:60Z"),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+        assert_eq!(
+            Time::<UTC>::parse_ccsds_ascii("2016-366T12:59:60Z"),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+    }
+
+    #[test]
+    fn ccsds_ascii_rounding_carries_into_leap_second() {
+        let t = Time::<UTC>::parse_ccsds_ascii("2016-366T23:59:59.9996").unwrap();
+        let opts = FormatOptions {
+            subsecond_digits: 3,
+            precision: FormatPrecision::RoundHalfToEven,
+            include_zulu: false,
+            utc_offset: None,
+        };
+        assert_eq!(
+            t.format_ccsds_ascii(CcsdsAsciiCode::B, opts),
+            "2016-366T23:59:60.000"
+        );
+        let opts = FormatOptions {
+            precision: FormatPrecision::Truncate,
+            ..opts
+        };
+        assert_eq!(
+            t.format_ccsds_ascii(CcsdsAsciiCode::B, opts),
+            "2016-366T23:59:59.999"
+        );
+    }
+
+    #[test]
+    fn ccsds_ascii_rejects_malformed_input() {
+        for s in [
+            "2023-366T00:00:00Z",
+            "2024-000",
+            "2024-02-30",
+            "2024-167T1:00",
+            "2024-167T12:00:00.",
+            "2024-167T12:00.5",
+            "2024-167T12:00:00.1234567890",
+            "24-167",
+            "2024-167T24:00:00",
+        ] {
+            assert!(Time::<UTC>::parse_ccsds_ascii(s).is_err(), "accepted {s}");
+        }
+    }
+
+    #[test]
+    fn continuous_scale_iso_labels_round_trip() {
+        use crate::{GPST, TT};
+
+        let j2000 = Time::<TT>::parse_iso("2000-01-01T12:00:00 TT").unwrap();
+        assert_eq!(j2000.to_exact_j2000().unwrap(), ExactDuration::ZERO);
+        assert_eq!(Time::<TT>::parse_iso("2000-01-01T12:00:00").unwrap(), j2000);
+
+        let s = "2024-02-29T23:59:59.123456789 TT";
+        let t = Time::<TT>::parse_iso(s).unwrap();
+        assert_eq!(t.format_iso(FormatOptions::nanoseconds()), s);
+
+        let utc = Time::<UTC>::parse_rfc3339("2024-01-01T00:00:00Z").unwrap();
+        assert_eq!(
+            utc.to::<TT>().format_iso(FormatOptions::SECONDS),
+            "2024-01-01T00:01:09 TT"
+        );
+
+        let gpst = Time::<GPST>::parse_iso("2024-001T06 GPST").unwrap();
+        let opts = FormatOptions {
+            include_zulu: false,
+            ..FormatOptions::SECONDS
+        };
+        assert_eq!(gpst.format_iso(opts), "2024-01-01T06:00:00");
+    }
+
+    #[test]
+    fn continuous_scale_iso_has_no_leap_seconds() {
+        use crate::TT;
+
+        for bad in [
+            "2016-12-31T23:59:60 TT",
+            "2016-12-31T12:00:60",
+            "2016-12-31T23:59:59Z",
+            "2016-12-31T23:59:59 TAI",
+            "2016-12-31T23:59:59  TT",
+        ] {
+            assert_eq!(
+                Time::<TT>::parse_iso(bad),
+                Err(ConversionError::OutOfRange),
+                "{bad}"
+            );
+        }
+
+        let t = Time::<TT>::parse_iso("2016-12-31T23:59:59.999999999 TT").unwrap();
+        assert_eq!(
+            t.format_iso(FormatOptions::milliseconds()),
+            "2017-01-01T00:00:00.000 TT"
+        );
+    }
 }
@@ -0,0 +1,625 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! ISO 8601 profiles beyond RFC 3339 for `Time<UTC>` and other calendar
+//! scales.
+//!
+//! An [`Iso8601Profile`] selects one representation and is used both to
+//! parse (strictly) and to format:
+//!
+//! | Notation / date form | Extended                  | Basic                 |
+//! |----------------------|---------------------------|-----------------------|
+//! | Calendar             | `2024-06-15T12:34:56Z`    | `20240615T123456Z`    |
+//! | Ordinal              | `2024-167T12:34:56Z`      | `2024167T123456Z`     |
+//! | Week                 | `2024-W24-6T12:34:56Z`    | `2024W246T123456Z`    |
+//!
+//! The profile's [`Iso8601Resolution`] is the finest time component. On
+//! parse, coarser reduced-precision forms (`2024-06-15T12Z`,
+//! `2024-06-15T12:34Z`, or the date alone) are accepted, and the last
+//! component may carry a decimal fraction (`T12,5Z` is 12:30) written with
+//! the profile's [`DecimalSign`]. The zone designator is `Z`, `±hh`, or
+//! `±hh:mm` (`±hhmm` in basic notation); without one the label is read as
+//! UTC.
+//!
+//! Leap seconds follow [`Time::parse_rfc3339`]: second 60 is accepted only
+//! where the offset puts it on UTC `23:59` of a day with an announced
+//! positive leap second. Labels come from [`crate::MjdSod`], so parsing and
+//! formatting are exact to the nanosecond.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::format::iso8601::{DecimalSign, Iso8601Profile};
+//! use tempoch_core::{FormatOptions, Time, UTC};
+//!
+//! let basic = Time::<UTC>::parse_iso8601("20240615T123456Z", Iso8601Profile::BASIC).unwrap();
+//! let week = Time::<UTC>::parse_iso8601("2024-W24-6T12:34:56Z", Iso8601Profile::WEEK).unwrap();
+//! assert_eq!(basic, week);
+//!
+//! let comma = Iso8601Profile::EXTENDED.with_decimal_sign(DecimalSign::Comma);
+//! let half_past = Time::<UTC>::parse_iso8601("2024-06-15T12,5Z", comma).unwrap();
+//! assert_eq!(
+//!     half_past.format_iso8601(Iso8601Profile::ORDINAL, FormatOptions::SECONDS),
+//!     "2024-167T12:30:00Z"
+//! );
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::format::CalendarScale;
+use crate::foundation::duration::NANOS_PER_SECOND;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::UTC;
+use crate::model::time::Time;
+
+use super::iso::{
+    parse_digits, rounded_label, shift_minutes, time_from_clock, FormatOptions, FormatPrecision,
+    UtcOffset,
+};
+
+/// Extended (`-` and `:` separators) or basic (no separators) notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Iso8601Notation {
+    /// `2024-06-15T12:34:56`.
+    Extended,
+    /// `20240615T123456`.
+    Basic,
+}
+
+/// How the date is written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Iso8601DateForm {
+    /// Year, month, and day: `2024-06-15`.
+    Calendar,
+    /// Year and day of year: `2024-167`.
+    Ordinal,
+    /// ISO week-numbering year, week, and weekday (Monday = 1): `2024-W24-6`.
+    Week,
+}
+
+/// Finest component of the time of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Iso8601Resolution {
+    /// Date only, no time of day.
+    Day,
+    /// `hh`.
+    Hour,
+    /// `hh:mm`.
+    Minute,
+    /// `hh:mm:ss`, with subseconds per [`FormatOptions`] on output.
+    Second,
+}
+
+/// Decimal separator of fractional components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecimalSign {
+    /// `.` (as in RFC 3339).
+    Point,
+    /// `,` (preferred by ISO 8601).
+    Comma,
+}
+
+impl DecimalSign {
+    const fn as_char(self) -> char {
+        match self {
+            Self::Point => '.',
+            Self::Comma => ',',
+        }
+    }
+}
+
+/// One ISO 8601 representation, used for both parsing and formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Iso8601Profile {
+    /// Extended or basic notation.
+    pub notation: Iso8601Notation,
+    /// Calendar, ordinal, or week date.
+    pub date_form: Iso8601DateForm,
+    /// Finest time component written, and accepted on parse.
+    pub resolution: Iso8601Resolution,
+    /// Separator before a decimal fraction.
+    pub decimal_sign: DecimalSign,
+}
+
+impl Iso8601Profile {
+    /// `2024-06-15T12:34:56Z`.
+    pub const EXTENDED: Self = Self {
+        notation: Iso8601Notation::Extended,
+        date_form: Iso8601DateForm::Calendar,
+        resolution: Iso8601Resolution::Second,
+        decimal_sign: DecimalSign::Point,
+    };
+
+    /// `20240615T123456Z`.
+    pub const BASIC: Self = Self::EXTENDED.with_notation(Iso8601Notation::Basic);
+
+    /// `2024-167T12:34:56Z`.
+    pub const ORDINAL: Self = Self::EXTENDED.with_date_form(Iso8601DateForm::Ordinal);
+
+    /// `2024-W24-6T12:34:56Z`.
+    pub const WEEK: Self = Self::EXTENDED.with_date_form(Iso8601DateForm::Week);
+
+    /// Same profile with another notation.
+    pub const fn with_notation(self, notation: Iso8601Notation) -> Self {
+        Self { notation, ..self }
+    }
+
+    /// Same profile with another date form.
+    pub const fn with_date_form(self, date_form: Iso8601DateForm) -> Self {
+        Self { date_form, ..self }
+    }
+
+    /// Same profile with another finest time component.
+    pub const fn with_resolution(self, resolution: Iso8601Resolution) -> Self {
+        Self { resolution, ..self }
+    }
+
+    /// Same profile with another decimal separator.
+    pub const fn with_decimal_sign(self, decimal_sign: DecimalSign) -> Self {
+        Self {
+            decimal_sign,
+            ..self
+        }
+    }
+
+    const fn extended(self) -> bool {
+        matches!(self.notation, Iso8601Notation::Extended)
+    }
+}
+
+impl Default for Iso8601Profile {
+    fn default() -> Self {
+        Self::EXTENDED
+    }
+}
+
+/// Parse an ISO 8601 timestamp written in `profile`.
+#[inline]
+pub fn parse_iso8601_utc(s: &str, profile: Iso8601Profile) -> Result<Time<UTC>, ConversionError> {
+    parse_iso8601_utc_with(s, profile, &TimeContext::new())
+}
+
+/// Like [`parse_iso8601_utc`], but uses an explicit [`TimeContext`].
+pub fn parse_iso8601_utc_with(
+    s: &str,
+    profile: Iso8601Profile,
+    ctx: &TimeContext,
+) -> Result<Time<UTC>, ConversionError> {
+    parse_label(s, profile, ctx)
+}
+
+/// Parse a label on any calendar scale. Only UTC labels take a zone
+/// designator; other scales accept an optional ` <SCALE>` suffix instead.
+fn parse_label<S: CalendarScale>(
+    s: &str,
+    profile: Iso8601Profile,
+    ctx: &TimeContext,
+) -> Result<Time<S>, ConversionError> {
+    if !s.is_ascii() {
+        return Err(ConversionError::OutOfRange);
+    }
+    let s = match s.rsplit_once(' ') {
+        Some((label, name)) if name == S::NAME && !S::IS_UTC => label,
+        Some(_) => return Err(ConversionError::OutOfRange),
+        None => s,
+    };
+    let (date, time) = match s.split_once('T') {
+        Some((date, time)) if profile.resolution > Iso8601Resolution::Day => (date, Some(time)),
+        Some(_) => return Err(ConversionError::OutOfRange),
+        None => (s, None),
+    };
+    let mjd = parse_date(date, profile)?;
+    let Some(time) = time else {
+        return time_from_clock(mjd, [0, 0, 0], 0, ctx);
+    };
+    let (clock, zone) = match time.find(['Z', '+', '-']) {
+        Some(_) if !S::IS_UTC => return Err(ConversionError::OutOfRange),
+        Some(pos) => time.split_at(pos),
+        None => (time, ""),
+    };
+    let (clock, frac_nanos) = parse_clock(clock, profile)?;
+    let offset = parse_zone(zone, profile)?;
+    let (mjd, clock) = shift_minutes(mjd, clock, -offset.minutes() as i64);
+    time_from_clock(mjd, clock, frac_nanos, ctx)
+}
+
+fn parse_date(date: &str, profile: Iso8601Profile) -> Result<i64, ConversionError> {
+    let b = date.as_bytes();
+    let year = || Ok::<_, ConversionError>(parse_digits(&date[..4])? as i64);
+    let mjd = match (profile.date_form, profile.extended()) {
+        (Iso8601DateForm::Calendar, true) if b.len() == 10 && b[4] == b'-' && b[7] == b'-' => {
+            encoding::mjd_from_gregorian(
+                year()?,
+                parse_digits(&date[5..7])?,
+                parse_digits(&date[8..10])?,
+            )
+        }
+        (Iso8601DateForm::Calendar, false) if b.len() == 8 => encoding::mjd_from_gregorian(
+            year()?,
+            parse_digits(&date[4..6])?,
+            parse_digits(&date[6..8])?,
+        ),
+        (Iso8601DateForm::Ordinal, true) if b.len() == 8 && b[4] == b'-' => {
+            encoding::mjd_from_year_day(year()?, parse_digits(&date[5..8])?)
+        }
+        (Iso8601DateForm::Ordinal, false) if b.len() == 7 => {
+            encoding::mjd_from_year_day(year()?, parse_digits(&date[4..7])?)
+        }
+        (Iso8601DateForm::Week, true)
+            if b.len() == 10 && b[4] == b'-' && b[5] == b'W' && b[8] == b'-' =>
+        {
+            encoding::mjd_from_iso_week(
+                year()?,
+                parse_digits(&date[6..8])?,
+                parse_digits(&date[9..10])?,
+            )
+        }
+        (Iso8601DateForm::Week, false) if b.len() == 8 && b[4] == b'W' => {
+            encoding::mjd_from_iso_week(
+                year()?,
+                parse_digits(&date[5..7])?,
+                parse_digits(&date[7..8])?,
+            )
+        }
+        _ => None,
+    };
+    mjd.ok_or(ConversionError::OutOfRange)
+}
+
+/// `hh[:mm[:ss]][,f]` into clock fields and subsecond nanoseconds. A
+/// fraction on the hour or minute is spread over the lower fields.
+fn parse_clock(clock: &str, profile: Iso8601Profile) -> Result<([u32; 3], u32), ConversionError> {
+    let (whole, frac) = match clock.split_once(['.', ',']) {
+        Some((whole, frac)) if clock.contains(profile.decimal_sign.as_char()) => {
+            (whole, Some(frac))
+        }
+        Some(_) => return Err(ConversionError::OutOfRange),
+        None => (clock, None),
+    };
+    let parts: Vec<&str> = if profile.extended() {
+        whole.split(':').collect()
+    } else if whole.len().is_multiple_of(2) {
+        (0..whole.len())
+            .step_by(2)
+            .map(|i| &whole[i..i + 2])
+            .collect()
+    } else {
+        return Err(ConversionError::OutOfRange);
+    };
+    let max_parts = match profile.resolution {
+        Iso8601Resolution::Day => 0,
+        Iso8601Resolution::Hour => 1,
+        Iso8601Resolution::Minute => 2,
+        Iso8601Resolution::Second => 3,
+    };
+    if parts.is_empty() || parts.len() > max_parts || parts.iter().any(|p| p.len() != 2) {
+        return Err(ConversionError::OutOfRange);
+    }
+    let mut fields = [0_u32; 3];
+    for (field, part) in fields.iter_mut().zip(&parts) {
+        *field = parse_digits(part)?;
+    }
+    let [hour, minute, second] = fields;
+    if hour >= 24 || minute >= 60 || second > 60 {
+        return Err(ConversionError::OutOfRange);
+    }
+
+    let frac_nanos = match frac {
+        None => 0,
+        Some(digits) if digits.is_empty() || digits.len() > 9 => {
+            return Err(ConversionError::OutOfRange)
+        }
+        Some(digits) => {
+            let unit = [3_600, 60, 1][parts.len() - 1] * NANOS_PER_SECOND;
+            parse_digits(digits)? as i128 * unit / 10_i128.pow(digits.len() as u32)
+        }
+    };
+    if parts.len() == 3 {
+        return Ok((fields, frac_nanos as u32));
+    }
+    let nanos = (hour * 3_600 + minute * 60) as i128 * NANOS_PER_SECOND + frac_nanos;
+    let seconds = (nanos / NANOS_PER_SECOND) as u32;
+    Ok((
+        [seconds / 3_600, seconds / 60 % 60, seconds % 60],
+        (nanos % NANOS_PER_SECOND) as u32,
+    ))
+}
+
+/// `Z`, `±hh`, `±hh:mm` (extended), or `±hhmm` (basic); empty is UTC.
+fn parse_zone(zone: &str, profile: Iso8601Profile) -> Result<UtcOffset, ConversionError> {
+    let sign = match zone.as_bytes().first() {
+        None => return Ok(UtcOffset::UTC),
+        Some(b'Z') if zone.len() == 1 => return Ok(UtcOffset::UTC),
+        Some(b'+') => 1,
+        Some(b'-') => -1,
+        _ => return Err(ConversionError::OutOfRange),
+    };
+    let body = &zone[1..];
+    let (hh, mm) = match (body.len(), profile.extended()) {
+        (2, _) => (body, "00"),
+        (5, true) if body.as_bytes()[2] == b':' => (&body[..2], &body[3..]),
+        (4, false) => body.split_at(2),
+        _ => return Err(ConversionError::OutOfRange),
+    };
+    let (hours, minutes) = (parse_digits(hh)?, parse_digits(mm)?);
+    if hours >= 24 || minutes >= 60 {
+        return Err(ConversionError::OutOfRange);
+    }
+    if sign < 0 && hours == 0 && minutes == 0 {
+        return Ok(UtcOffset::UNKNOWN);
+    }
+    UtcOffset::from_minutes(sign * (hours * 60 + minutes) as i32)
+}
+
+impl<S: CalendarScale> Time<S> {
+    /// Parse an ISO 8601 timestamp written in `profile`. See
+    /// [`parse_iso8601_utc`]; labels on other scales take no zone
+    /// designator and may end in ` <SCALE>` (`2024-06-15T12:00 TT`).
+    #[inline]
+    pub fn parse_iso8601(s: &str, profile: Iso8601Profile) -> Result<Self, ConversionError> {
+        Self::parse_iso8601_with(s, profile, &TimeContext::new())
+    }
+
+    /// Like [`parse_iso8601`](Self::parse_iso8601), with an explicit
+    /// [`TimeContext`].
+    #[inline]
+    pub fn parse_iso8601_with(
+        s: &str,
+        profile: Iso8601Profile,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        parse_label(s, profile, ctx)
+    }
+
+    /// Format in `profile`.
+    ///
+    /// Subseconds, the zone designator, and any UTC offset follow
+    /// [`FormatOptions`] as for [`Time::format_rfc3339`];
+    /// basic notation writes the offset as `±hhmm`. On scales other than
+    /// UTC, `include_zulu` appends ` <SCALE>` instead of `Z`. Below
+    /// [`Iso8601Resolution::Second`] the label is truncated, and date-only
+    /// output has no zone designator.
+    pub fn format_iso8601(&self, profile: Iso8601Profile, opts: FormatOptions) -> String {
+        self.format_iso8601_with(profile, opts, &TimeContext::new())
+    }
+
+    /// Like [`format_iso8601`](Self::format_iso8601), with an explicit
+    /// [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the instant cannot be labelled; use
+    /// [`try_format_iso8601_with`](Self::try_format_iso8601_with) to handle
+    /// that case explicitly.
+    pub fn format_iso8601_with(
+        &self,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> String {
+        match self.try_format_iso8601_with(profile, opts, ctx) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of
+    /// [`format_iso8601_with`](Self::format_iso8601_with).
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for (week-numbering) years
+    /// outside `0000..=9999`, or for a UTC offset on a scale other than
+    /// UTC.
+    pub fn try_format_iso8601_with(
+        &self,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> Result<String, ConversionError> {
+        if opts.utc_offset.is_some() && !S::IS_UTC {
+            return Err(ConversionError::OutOfRange);
+        }
+        let rounding = if profile.resolution == Iso8601Resolution::Second {
+            opts
+        } else {
+            FormatOptions {
+                subsecond_digits: 0,
+                precision: FormatPrecision::Truncate,
+                ..opts
+            }
+        };
+        let label = rounded_label(*self, rounding, ctx)?;
+        let shift = opts.utc_offset.map_or(0, |offset| offset.minutes() as i64);
+        let (mjd, [hour, minute, second]) = shift_minutes(label.mjd, label.clock, shift);
+
+        let ext = profile.extended();
+        let dash = if ext { "-" } else { "" };
+        let (year, date) = match profile.date_form {
+            Iso8601DateForm::Calendar => {
+                let (year, month, day) = encoding::gregorian_from_mjd(mjd);
+                (year, format!("{dash}{month:02}{dash}{day:02}"))
+            }
+            Iso8601DateForm::Ordinal => {
+                let (year, ordinal) = encoding::year_day_from_mjd(mjd);
+                (year, format!("{dash}{ordinal:03}"))
+            }
+            Iso8601DateForm::Week => {
+                let (year, week) = encoding::iso_week(mjd);
+                let weekday = encoding::iso_weekday(mjd);
+                (year, format!("{dash}W{week:02}{dash}{weekday}"))
+            }
+        };
+        if !(0..=9_999).contains(&year) {
+            return Err(ConversionError::OutOfRange);
+        }
+        let mut out = format!("{year:04}{date}");
+        if profile.resolution == Iso8601Resolution::Day {
+            return Ok(out);
+        }
+
+        let colon = if ext { ":" } else { "" };
+        out.push_str(&format!("T{hour:02}"));
+        if profile.resolution >= Iso8601Resolution::Minute {
+            out.push_str(&format!("{colon}{minute:02}"));
+        }
+        if profile.resolution == Iso8601Resolution::Second {
+            out.push_str(&format!("{colon}{second:02}"));
+            let digits = label.digits;
+            if digits > 0 {
+                out.push(profile.decimal_sign.as_char());
+                out.push_str(&format!("{:0digits$}", label.frac));
+            }
+        }
+        match opts.utc_offset {
+            Some(offset) if ext => out.push_str(&offset.to_string()),
+            Some(offset) => out.push_str(&offset.to_string().replace(':', "")),
+            None if opts.include_zulu && S::IS_UTC => out.push('Z'),
+            None if opts.include_zulu => {
+                out.push(' ');
+                out.push_str(S::NAME);
+            }
+            None => {}
+        }
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> Time<UTC> {
+        Time::<UTC>::parse_ccsds_ascii(s).unwrap()
+    }
+
+    #[test]
+    fn profiles_and_reduced_precision_parse_to_the_same_instant() {
+        let noon = utc("2024-06-15T12:00:00");
+        let comma = Iso8601Profile::EXTENDED.with_decimal_sign(DecimalSign::Comma);
+        let cases = [
+            ("20240615T12Z", Iso8601Profile::BASIC),
+            ("20240615T1200+0000", Iso8601Profile::BASIC),
+            ("2024-06-15T12Z", Iso8601Profile::EXTENDED),
+            ("2024-06-15T14:00+02", Iso8601Profile::EXTENDED),
+            ("2024-W24-6T12:00Z", Iso8601Profile::WEEK),
+            (
+                "2024W246T120000",
+                Iso8601Profile::WEEK.with_notation(Iso8601Notation::Basic),
+            ),
+            (
+                "2024-167T12,25+00:15",
+                Iso8601Profile::ORDINAL.with_decimal_sign(DecimalSign::Comma),
+            ),
+            ("2024-06-15T11:59,5-00:00", comma),
+        ];
+        for (s, profile) in cases {
+            let t = Time::<UTC>::parse_iso8601(s, profile).unwrap_or_else(|e| panic!("{s}: {e}"));
+            let expected = if s.contains("59,5") {
+                utc("2024-06-15T11:59:30")
+            } else {
+                noon
+            };
+            assert_eq!(t, expected, "{s}");
+        }
+        assert_eq!(
+            Time::<UTC>::parse_iso8601("2024-06-15T11:59:59,999999999Z", comma).unwrap(),
+            utc("2024-06-15T11:59:59.999999999")
+        );
+        assert_eq!(
+            Time::<UTC>::parse_iso8601("2024-167", Iso8601Profile::ORDINAL).unwrap(),
+            utc("2024-06-15T00:00:00")
+        );
+    }
+
+    #[test]
+    fn profiles_are_strict() {
+        let rejected = [
+            ("2024-06-15T12:00:00Z", Iso8601Profile::BASIC),
+            ("20240615T120000Z", Iso8601Profile::EXTENDED),
+            ("2024-06-15T12:00:00,5Z", Iso8601Profile::EXTENDED),
+            (
+                "2024-06-15T12:00:00Z",
+                Iso8601Profile::EXTENDED.with_resolution(Iso8601Resolution::Minute),
+            ),
+            (
+                "2024-06-15T12Z",
+                Iso8601Profile::EXTENDED.with_resolution(Iso8601Resolution::Day),
+            ),
+            ("2024-W53-1", Iso8601Profile::WEEK),
+            ("2024-W24-8", Iso8601Profile::WEEK),
+            ("2023-366T00Z", Iso8601Profile::ORDINAL),
+            ("20240615T120000+02:00", Iso8601Profile::BASIC),
+            ("2024-06-15T12:00:00.Z", Iso8601Profile::EXTENDED),
+            ("2024-06-15T12:60Z", Iso8601Profile::EXTENDED),
+        ];
+        for (s, profile) in rejected {
+            assert!(Time::<UTC>::parse_iso8601(s, profile).is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn leap_seconds_follow_rfc3339_rules() {
+        let leap = utc("2016-12-31T23:59:60.5");
+        let basic = Iso8601Profile::BASIC;
+        assert_eq!(
+            Time::<UTC>::parse_iso8601("20161231T235960.5Z", basic).unwrap(),
+            leap
+        );
+        assert_eq!(
+            Time::<UTC>::parse_iso8601("2016-W52-7T05:29:60.5+05:30", Iso8601Profile::WEEK)
+                .unwrap(),
+            leap
+        );
+        assert!(matches!(
+            Time::<UTC>::parse_iso8601("20161231T125960Z", basic),
+            Err(ConversionError::InvalidLeapSecond)
+        ));
+        assert!(matches!(
+            Time::<UTC>::parse_iso8601("20230630T235960Z", basic),
+            Err(ConversionError::InvalidLeapSecond)
+        ));
+
+        let opts = FormatOptions::milliseconds();
+        assert_eq!(leap.format_iso8601(basic, opts), "20161231T235960.500Z");
+        let ist = opts.with_utc_offset(UtcOffset::from_minutes(330).unwrap());
+        assert_eq!(
+            leap.format_iso8601(basic.with_date_form(Iso8601DateForm::Ordinal), ist),
+            "2017001T052960.500+0530"
+        );
+    }
+
+    #[test]
+    fn formatting_matches_profiles_and_round_trips() {
+        let t = utc("2024-06-15T12:34:56.789");
+        let opts = FormatOptions::milliseconds();
+        let comma = DecimalSign::Comma;
+        let cases = [
+            (Iso8601Profile::EXTENDED, "2024-06-15T12:34:56.789Z"),
+            (
+                Iso8601Profile::BASIC.with_decimal_sign(comma),
+                "20240615T123456,789Z",
+            ),
+            (Iso8601Profile::ORDINAL, "2024-167T12:34:56.789Z"),
+            (Iso8601Profile::WEEK, "2024-W24-6T12:34:56.789Z"),
+            (
+                Iso8601Profile::EXTENDED.with_resolution(Iso8601Resolution::Minute),
+                "2024-06-15T12:34Z",
+            ),
+            (
+                Iso8601Profile::BASIC.with_resolution(Iso8601Resolution::Hour),
+                "20240615T12Z",
+            ),
+            (
+                Iso8601Profile::WEEK.with_resolution(Iso8601Resolution::Day),
+                "2024-W24-6",
+            ),
+        ];
+        for (profile, expected) in cases {
+            let s = t.format_iso8601(profile, opts);
+            assert_eq!(s, expected);
+            let back = Time::<UTC>::parse_iso8601(&s, profile).unwrap();
+            assert_eq!(back.format_iso8601(profile, opts), expected);
+        }
+        // The ISO week-numbering year differs from the calendar year here.
+        let new_year = utc("2021-01-01T00:00:00");
+        let day = Iso8601Profile::WEEK.with_resolution(Iso8601Resolution::Day);
+        assert_eq!(new_year.format_iso8601(day, opts), "2020-W53-5");
+    }
+}
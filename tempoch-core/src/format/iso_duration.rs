@@ -0,0 +1,433 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! ISO 8601 durations (`PT1H30M0.000000001S`, `-P3DT4H`) for
+//! [`ExactDuration`].
+//!
+//! Accepted form: an optional sign, `P`, then any of `nY nM nW nD` and, after
+//! `T`, any of `nH nM nS`, in that order and at least one in total. The last
+//! component may carry up to 9 fractional digits with `.` or `,`. Weeks,
+//! days, hours, minutes, and seconds are exact (a day is 86 400 s), so the
+//! result is exact to the nanosecond.
+//!
+//! Years and months are nominal: their length depends on where they start.
+//! [`ExactDuration::parse_iso8601`] and [`core::str::FromStr`] reject them
+//! with [`Iso8601DurationError::Nominal`];
+//! [`ExactDuration::parse_iso8601_from`] resolves them on the calendar of a
+//! start instant.
+//!
+//! [`ExactDuration::to_iso8601`] writes the canonical form with days, hours,
+//! minutes, and seconds, and no trailing fractional zeros.
+//! [`Iso8601Duration`] wraps an [`ExactDuration`] whose `Display`, `FromStr`,
+//! and (with the `serde` feature) serde form is that string.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::ExactDuration;
+//!
+//! let d: ExactDuration = "PT1H30M0.000000001S".parse().unwrap();
+//! assert_eq!(d.as_nanos_i128(), 5_400_000_000_001);
+//! assert_eq!(d.to_iso8601(), "PT1H30M0.000000001S");
+//!
+//! let back = ExactDuration::parse_iso8601("-P3DT4H").unwrap();
+//! assert_eq!(back.to_iso8601(), "-P3DT4H");
+//! assert!(ExactDuration::parse_iso8601("P1M").is_err());
+//! ```
+
+use core::str::FromStr;
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::format::{CalendarScale, MjdSod};
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+
+const NANOS_PER_MINUTE: i128 = 60 * NANOS_PER_SECOND;
+const NANOS_PER_HOUR: i128 = 60 * NANOS_PER_MINUTE;
+const NANOS_PER_DAY: i128 = 24 * NANOS_PER_HOUR;
+
+/// Error returned when parsing an ISO 8601 duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iso8601DurationError {
+    /// The text is not an ISO 8601 duration.
+    Invalid,
+    /// Years or months were given without a start instant.
+    Nominal,
+    /// The value does not fit in an [`ExactDuration`].
+    Overflow,
+    /// The start instant or the shifted end could not be labelled.
+    Conversion(ConversionError),
+}
+
+impl core::fmt::Display for Iso8601DurationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid ISO 8601 duration"),
+            Self::Nominal => {
+                f.write_str("ISO 8601 duration has years or months but no start instant")
+            }
+            Self::Overflow => f.write_str("ISO 8601 duration overflows ExactDuration"),
+            Self::Conversion(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Iso8601DurationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for Iso8601DurationError {
+    fn from(err: ConversionError) -> Self {
+        Self::Conversion(err)
+    }
+}
+
+/// Parsed components; all magnitudes, with the sign kept apart.
+struct Components {
+    negative: bool,
+    months: i128,
+    nanos: i128,
+}
+
+fn parse_components(s: &str) -> Result<Components, Iso8601DurationError> {
+    use Iso8601DurationError::{Invalid, Overflow};
+
+    let (negative, rest) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let rest = rest.strip_prefix('P').ok_or(Invalid)?;
+    let (date, time) = match rest.split_once('T') {
+        Some((_, "")) => return Err(Invalid),
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+
+    // (designator, months per unit, nanoseconds per unit), in order.
+    const DATE: [(u8, i128, i128); 4] = [
+        (b'Y', 12, 0),
+        (b'M', 1, 0),
+        (b'W', 0, 7 * NANOS_PER_DAY),
+        (b'D', 0, NANOS_PER_DAY),
+    ];
+    const TIME: [(u8, i128, i128); 3] = [
+        (b'H', 0, NANOS_PER_HOUR),
+        (b'M', 0, NANOS_PER_MINUTE),
+        (b'S', 0, NANOS_PER_SECOND),
+    ];
+
+    let mut out = Components {
+        negative,
+        months: 0,
+        nanos: 0,
+    };
+    let mut seen = 0;
+    let mut fraction_seen = false;
+    for (text, units) in [(date, &DATE[..]), (time, &TIME[..])] {
+        let mut text = text;
+        let mut next_unit = 0;
+        while !text.is_empty() {
+            if fraction_seen {
+                return Err(Invalid);
+            }
+            let end = text
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+                .ok_or(Invalid)?;
+            let (number, tail) = text.split_at(end);
+            let designator = tail.as_bytes()[0];
+            let offset = units[next_unit..]
+                .iter()
+                .position(|&(d, _, _)| d == designator)
+                .ok_or(Invalid)?;
+            let (_, months, nanos) = units[next_unit + offset];
+            next_unit += offset + 1;
+            text = &tail[1..];
+            seen += 1;
+
+            let (whole, frac) = match number.split_once(['.', ',']) {
+                Some((whole, frac)) => (whole, Some(frac)),
+                None => (number, None),
+            };
+            let whole = digits(whole)?;
+            out.months = whole
+                .checked_mul(months)
+                .and_then(|m| out.months.checked_add(m))
+                .ok_or(Overflow)?;
+            out.nanos = whole
+                .checked_mul(nanos)
+                .and_then(|n| out.nanos.checked_add(n))
+                .ok_or(Overflow)?;
+            if let Some(frac) = frac {
+                if frac.len() > 9 || months != 0 {
+                    return Err(Invalid);
+                }
+                fraction_seen = true;
+                out.nanos = out
+                    .nanos
+                    .checked_add(digits(frac)? * nanos / 10_i128.pow(frac.len() as u32))
+                    .ok_or(Overflow)?;
+            }
+        }
+    }
+    if seen == 0 {
+        return Err(Invalid);
+    }
+    Ok(out)
+}
+
+fn digits(s: &str) -> Result<i128, Iso8601DurationError> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Iso8601DurationError::Invalid);
+    }
+    s.parse().map_err(|_| Iso8601DurationError::Overflow)
+}
+
+impl ExactDuration {
+    /// Parse an ISO 8601 duration made of exact components only (weeks,
+    /// days, hours, minutes, seconds).
+    ///
+    /// Returns [`Iso8601DurationError::Nominal`] for years or months; use
+    /// [`parse_iso8601_from`](Self::parse_iso8601_from) for those.
+    pub fn parse_iso8601(s: &str) -> Result<Self, Iso8601DurationError> {
+        let c = parse_components(s)?;
+        if c.months != 0 {
+            return Err(Iso8601DurationError::Nominal);
+        }
+        Ok(Self::from_nanos(if c.negative {
+            -c.nanos
+        } else {
+            c.nanos
+        }))
+    }
+
+    /// Parse an ISO 8601 duration, resolving years and months on the
+    /// calendar of `start`'s scale.
+    ///
+    /// Years and months move the calendar date of `start` (clamping the day
+    /// to the end of the target month) and keep its time of day; the exact
+    /// components are then added. The result is the elapsed time from
+    /// `start` to that end, negative for a `-` duration.
+    pub fn parse_iso8601_from<S: CalendarScale>(
+        s: &str,
+        start: Time<S>,
+        ctx: &TimeContext,
+    ) -> Result<Self, Iso8601DurationError> {
+        let c = parse_components(s)?;
+        let sign = if c.negative { -1 } else { 1 };
+        let exact = Self::from_nanos(sign * c.nanos);
+        if c.months == 0 {
+            return Ok(exact);
+        }
+
+        let label = start.to_mjd_sod_with(ctx)?;
+        let (year, month, day) = encoding::gregorian_from_mjd(label.mjd.value());
+        let months = (year as i128 * 12 + month as i128 - 1)
+            .checked_add(sign * c.months)
+            .ok_or(Iso8601DurationError::Overflow)?;
+        let year = i64::try_from(months.div_euclid(12)).map_err(|_| ConversionError::OutOfRange)?;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let day = day.min(encoding::days_in_month(year, month));
+        let mjd =
+            encoding::mjd_from_gregorian(year, month, day).ok_or(ConversionError::OutOfRange)?;
+        let shifted = MjdSod::new(qtty::i64::Day::new(mjd), label.second_of_day)?;
+        let end = Time::<S>::from_mjd_sod_with(shifted, ctx)?
+            .try_add_exact(exact)
+            .map_err(|_| Iso8601DurationError::Overflow)?;
+        end.to_exact_j2000()?
+            .checked_sub(start.to_exact_j2000()?)
+            .map_err(|_| Iso8601DurationError::Overflow)
+    }
+
+    /// Canonical ISO 8601 form: `[-]P[nD][T[nH][nM][n[.f]S]]`, or `PT0S`.
+    pub fn to_iso8601(self) -> String {
+        let nanos = self.as_nanos_i128();
+        let mut rest = nanos.unsigned_abs();
+        let mut out = String::from(if nanos < 0 { "-P" } else { "P" });
+        let days = rest / NANOS_PER_DAY as u128;
+        rest %= NANOS_PER_DAY as u128;
+        if days > 0 {
+            out.push_str(&format!("{days}D"));
+        }
+        if rest > 0 || days == 0 {
+            out.push('T');
+            let hours = rest / NANOS_PER_HOUR as u128;
+            let minutes = rest / NANOS_PER_MINUTE as u128 % 60;
+            let seconds = rest / NANOS_PER_SECOND as u128 % 60;
+            let frac = rest % NANOS_PER_SECOND as u128;
+            if hours > 0 {
+                out.push_str(&format!("{hours}H"));
+            }
+            if minutes > 0 {
+                out.push_str(&format!("{minutes}M"));
+            }
+            if frac > 0 {
+                let frac = format!("{frac:09}");
+                out.push_str(&format!("{seconds}.{}S", frac.trim_end_matches('0')));
+            } else if seconds > 0 || rest == 0 {
+                out.push_str(&format!("{seconds}S"));
+            }
+        }
+        out
+    }
+}
+
+/// Exact ISO 8601 durations only; see [`ExactDuration::parse_iso8601`].
+impl FromStr for ExactDuration {
+    type Err = Iso8601DurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_iso8601(s)
+    }
+}
+
+/// [`ExactDuration`] whose text and serde form is its ISO 8601 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Iso8601Duration(pub ExactDuration);
+
+impl From<ExactDuration> for Iso8601Duration {
+    #[inline]
+    fn from(value: ExactDuration) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Iso8601Duration> for ExactDuration {
+    #[inline]
+    fn from(value: Iso8601Duration) -> Self {
+        value.0
+    }
+}
+
+impl core::fmt::Display for Iso8601Duration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(&self.0.to_iso8601())
+    }
+}
+
+impl FromStr for Iso8601Duration {
+    type Err = Iso8601DurationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ExactDuration::parse_iso8601(s).map(Self)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::Iso8601Duration;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    impl Serialize for Iso8601Duration {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_str(self)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for Iso8601Duration {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+            s.parse().map_err(serde::de::Error::custom)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::scale::{TT, UTC};
+
+    #[test]
+    fn exact_components_parse_and_round_trip() {
+        let cases = [
+            ("PT1H30M0.000000001S", 5_400 * NANOS_PER_SECOND + 1),
+            ("P3DT4H", 3 * NANOS_PER_DAY + 4 * NANOS_PER_HOUR),
+            ("-PT0.5S", -NANOS_PER_SECOND / 2),
+            ("P2W", 14 * NANOS_PER_DAY),
+            ("PT0S", 0),
+            ("PT1,25M", 75 * NANOS_PER_SECOND),
+            ("+P1DT0.000000001S", NANOS_PER_DAY + 1),
+        ];
+        for (s, nanos) in cases {
+            let d: ExactDuration = s.parse().unwrap();
+            assert_eq!(d.as_nanos_i128(), nanos, "{s}");
+            let canonical = d.to_iso8601();
+            assert_eq!(ExactDuration::parse_iso8601(&canonical).unwrap(), d);
+        }
+        assert_eq!(
+            ExactDuration::from_nanos(-(NANOS_PER_DAY + 61 * NANOS_PER_SECOND)).to_iso8601(),
+            "-P1DT1M1S"
+        );
+        assert_eq!(ExactDuration::ZERO.to_iso8601(), "PT0S");
+    }
+
+    #[test]
+    fn rejects_malformed_and_nominal_durations() {
+        for s in [
+            "",
+            "P",
+            "PT",
+            "1H",
+            "PT1H2H",
+            "PT1M1H",
+            "P1DT",
+            "PT1.5H2M",
+            "PT0.0000000001S",
+            "P1.5Y",
+            "PT-1S",
+            "P1H",
+        ] {
+            assert_eq!(
+                ExactDuration::parse_iso8601(s),
+                Err(Iso8601DurationError::Invalid),
+                "{s}"
+            );
+        }
+        assert_eq!(
+            ExactDuration::parse_iso8601("P1M"),
+            Err(Iso8601DurationError::Nominal)
+        );
+        assert_eq!(
+            "P1Y".parse::<ExactDuration>(),
+            Err(Iso8601DurationError::Nominal)
+        );
+        assert_eq!(
+            ExactDuration::parse_iso8601("PT99999999999999999999999999999999999999999S"),
+            Err(Iso8601DurationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn nominal_components_resolve_on_the_start_calendar() {
+        let ctx = TimeContext::new();
+        let day = |n: i128| ExactDuration::from_nanos(n * NANOS_PER_DAY);
+
+        let jan31 = Time::<TT>::parse_iso("2024-01-31T06:00:00").unwrap();
+        let d = ExactDuration::parse_iso8601_from("P1M", jan31, &ctx).unwrap();
+        assert_eq!(d, day(29)); // Clamped to 2024-02-29.
+        let d = ExactDuration::parse_iso8601_from("-P1Y1M", jan31, &ctx).unwrap();
+        assert_eq!(d, -day(365 + 31)); // 2022-12-31.
+
+        // A UTC month containing a leap second is one second longer.
+        let dec = Time::<UTC>::parse_ccsds_ascii("2016-12-01T00:00:00").unwrap();
+        let d = ExactDuration::parse_iso8601_from("P1MT1S", dec, &ctx).unwrap();
+        assert_eq!(d, day(31) + ExactDuration::from_nanos(2 * NANOS_PER_SECOND));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_uses_the_iso_string() {
+        let d = Iso8601Duration(ExactDuration::from_nanos(-90 * NANOS_PER_MINUTE));
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(json, "\"-PT1H30M\"");
+        assert_eq!(serde_json::from_str::<Iso8601Duration>(&json).unwrap(), d);
+        assert!(serde_json::from_str::<Iso8601Duration>("\"P1M\"").is_err());
+    }
+}
@@ -0,0 +1,532 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! ISO 8601 time intervals (`start/end`, `start/PT2H`, `PT2H/end`) for
+//! [`Period`] and repeating intervals (`R10/2024-01-01T00:00Z/PT1H`) for
+//! [`TimeSeries`].
+//!
+//! Endpoints are read and written with [`Time::parse_iso8601`] and
+//! [`Time::format_iso8601`] in a chosen [`Iso8601Profile`], so any
+//! [`CalendarScale`] works: UTC endpoints carry a zone designator, other
+//! scales an optional ` <SCALE>` suffix (`2024-01-01T00:00 TT/PT1H`).
+//! Durations are [`ExactDuration`]s in the form of
+//! [`ExactDuration::to_iso8601`]; in a single interval, years and months are
+//! resolved on the calendar of the explicit endpoint.
+//!
+//! # Half-open semantics
+//!
+//! ISO 8601 does not say whether an interval contains its end. Here every
+//! interval is an [`Interval`], i.e. half-open `[start, end)`: the end
+//! written or read is the first instant *not* in the interval, so
+//! `2024-01-01T00:00Z/2024-01-02T00:00Z` is exactly one day and abuts the
+//! next day's interval without overlapping it.
+//!
+//! A repeating interval `Rn/…` is `n` consecutive intervals of one exact
+//! duration. As a [`TimeSeries`] it yields the start of each repetition;
+//! the series range `[start, start + n·step)` excludes the end of the last
+//! one. With the `duration/end` form the last repetition ends at `end`.
+//! Unbounded repetitions (`R/…`, `R-1/…`) have no finite series and are
+//! rejected, as are years and months, which have no fixed step.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::format::iso_interval::Iso8601IntervalForm;
+//! use tempoch_core::{FormatOptions, Iso8601Profile, Period, TimeSeries, UTC};
+//!
+//! let period = Period::<UTC>::parse_iso8601(
+//!     "2024-01-01T00:00Z/PT2H",
+//!     Iso8601Profile::EXTENDED,
+//! )
+//! .unwrap();
+//! assert_eq!(
+//!     period.format_iso8601(
+//!         Iso8601IntervalForm::StartEnd,
+//!         Iso8601Profile::EXTENDED,
+//!         FormatOptions::SECONDS,
+//!     ),
+//!     "2024-01-01T00:00:00Z/2024-01-01T02:00:00Z"
+//! );
+//!
+//! let hours =
+//!     TimeSeries::<UTC>::parse_iso8601("R10/2024-01-01T00:00Z/PT1H", Iso8601Profile::EXTENDED)
+//!         .unwrap();
+//! assert_eq!(hours.len_total(), 10);
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::format::CalendarScale;
+use crate::foundation::duration::ExactDuration;
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+use crate::period::{Interval, InvalidIntervalError, Period, TimeSeries, TimeSeriesError};
+
+use super::iso::FormatOptions;
+use super::iso8601::Iso8601Profile;
+use super::iso_duration::Iso8601DurationError;
+
+/// Which two of start, end, and duration an interval is written with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Iso8601IntervalForm {
+    /// `start/end`.
+    #[default]
+    StartEnd,
+    /// `start/duration`.
+    StartDuration,
+    /// `duration/end`.
+    DurationEnd,
+}
+
+/// Error returned when parsing or formatting an ISO 8601 interval.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iso8601IntervalError {
+    /// The text is not an ISO 8601 (repeating) interval.
+    Invalid,
+    /// An endpoint could not be parsed or labelled.
+    Conversion(ConversionError),
+    /// The duration is invalid, nominal where an exact step is needed, or
+    /// out of range.
+    Duration(Iso8601DurationError),
+    /// The end precedes the start.
+    Interval(InvalidIntervalError),
+    /// The repetition count is unbounded.
+    Unbounded,
+    /// The repetitions do not form a valid [`TimeSeries`].
+    Series(TimeSeriesError),
+}
+
+impl core::fmt::Display for Iso8601IntervalError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Invalid => f.write_str("invalid ISO 8601 interval"),
+            Self::Conversion(err) => write!(f, "{err}"),
+            Self::Duration(err) => write!(f, "{err}"),
+            Self::Interval(err) => write!(f, "{err}"),
+            Self::Unbounded => f.write_str("unbounded ISO 8601 repeating interval"),
+            Self::Series(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Iso8601IntervalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Conversion(err) => Some(err),
+            Self::Duration(err) => Some(err),
+            Self::Interval(err) => Some(err),
+            Self::Series(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for Iso8601IntervalError {
+    fn from(err: ConversionError) -> Self {
+        Self::Conversion(err)
+    }
+}
+
+impl From<Iso8601DurationError> for Iso8601IntervalError {
+    fn from(err: Iso8601DurationError) -> Self {
+        Self::Duration(err)
+    }
+}
+
+impl From<InvalidIntervalError> for Iso8601IntervalError {
+    fn from(err: InvalidIntervalError) -> Self {
+        Self::Interval(err)
+    }
+}
+
+impl From<TimeSeriesError> for Iso8601IntervalError {
+    fn from(err: TimeSeriesError) -> Self {
+        Self::Series(err)
+    }
+}
+
+const OVERFLOW: Iso8601IntervalError =
+    Iso8601IntervalError::Duration(Iso8601DurationError::Overflow);
+
+/// Exact elapsed time from `start` to `end`.
+fn span<S: CalendarScale>(
+    start: Time<S>,
+    end: Time<S>,
+) -> Result<ExactDuration, Iso8601IntervalError> {
+    end.to_exact_j2000()?
+        .checked_sub(start.to_exact_j2000()?)
+        .map_err(|_| OVERFLOW)
+}
+
+/// Parse `start/end`, `start/duration`, or `duration/end`. With `exact`,
+/// durations with years or months are rejected.
+fn parse_interval<S: CalendarScale>(
+    s: &str,
+    profile: Iso8601Profile,
+    exact: bool,
+    ctx: &TimeContext,
+) -> Result<(Period<S>, Iso8601IntervalForm), Iso8601IntervalError> {
+    let (first, second) = s.split_once('/').ok_or(Iso8601IntervalError::Invalid)?;
+    let label = |part: &str| Time::<S>::parse_iso8601_with(part, profile, ctx);
+    let shift = |anchor: Time<S>, duration: &str| {
+        let step = if exact {
+            ExactDuration::parse_iso8601(duration)?
+        } else {
+            ExactDuration::parse_iso8601_from(duration, anchor, ctx)?
+        };
+        anchor.try_add_exact(step).map_err(|_| OVERFLOW)
+    };
+    let (start, end, form) = match (first.starts_with('P'), second.starts_with('P')) {
+        (false, false) => (label(first)?, label(second)?, Iso8601IntervalForm::StartEnd),
+        (false, true) => {
+            let start = label(first)?;
+            (
+                start,
+                shift(start, second)?,
+                Iso8601IntervalForm::StartDuration,
+            )
+        }
+        (true, false) => {
+            let end = label(second)?;
+            let start = shift(end, &format!("-{first}"))?;
+            (start, end, Iso8601IntervalForm::DurationEnd)
+        }
+        (true, true) => return Err(Iso8601IntervalError::Invalid),
+    };
+    Ok((Interval::try_new(start, end)?, form))
+}
+
+impl<S: CalendarScale> Period<S> {
+    /// Parse an ISO 8601 interval whose endpoints are written in `profile`.
+    ///
+    /// The result is half-open: the parsed end is excluded.
+    #[inline]
+    pub fn parse_iso8601(s: &str, profile: Iso8601Profile) -> Result<Self, Iso8601IntervalError> {
+        Self::parse_iso8601_with(s, profile, &TimeContext::new())
+    }
+
+    /// Like [`parse_iso8601`](Self::parse_iso8601), with an explicit
+    /// [`TimeContext`].
+    pub fn parse_iso8601_with(
+        s: &str,
+        profile: Iso8601Profile,
+        ctx: &TimeContext,
+    ) -> Result<Self, Iso8601IntervalError> {
+        parse_interval(s, profile, false, ctx).map(|(period, _)| period)
+    }
+
+    /// Format as an ISO 8601 interval in `form`.
+    ///
+    /// The written end is the exclusive bound of `[start, end)`. Endpoints
+    /// follow `profile` and `opts` as in [`Time::format_iso8601`]; the
+    /// duration is exact and not affected by `opts`.
+    pub fn format_iso8601(
+        &self,
+        form: Iso8601IntervalForm,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+    ) -> String {
+        self.format_iso8601_with(form, profile, opts, &TimeContext::new())
+    }
+
+    /// Like [`format_iso8601`](Self::format_iso8601), with an explicit
+    /// [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the interval cannot be written; use
+    /// [`try_format_iso8601_with`](Self::try_format_iso8601_with) to handle
+    /// that case explicitly.
+    pub fn format_iso8601_with(
+        &self,
+        form: Iso8601IntervalForm,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> String {
+        match self.try_format_iso8601_with(form, profile, opts, ctx) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of
+    /// [`format_iso8601_with`](Self::format_iso8601_with).
+    ///
+    /// Returns [`Iso8601IntervalError::Interval`] if the end precedes the
+    /// start.
+    pub fn try_format_iso8601_with(
+        &self,
+        form: Iso8601IntervalForm,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> Result<String, Iso8601IntervalError> {
+        Self::try_new(self.start, self.end)?;
+        let label = |time: Time<S>| time.try_format_iso8601_with(profile, opts, ctx);
+        let duration = || span(self.start, self.end).map(ExactDuration::to_iso8601);
+        Ok(match form {
+            Iso8601IntervalForm::StartEnd => {
+                format!("{}/{}", label(self.start)?, label(self.end)?)
+            }
+            Iso8601IntervalForm::StartDuration => {
+                format!("{}/{}", label(self.start)?, duration()?)
+            }
+            Iso8601IntervalForm::DurationEnd => {
+                format!("{}/{}", duration()?, label(self.end)?)
+            }
+        })
+    }
+}
+
+impl<S: CalendarScale> TimeSeries<S> {
+    /// Parse an ISO 8601 repeating interval `Rn/<interval>` into the starts
+    /// of its `n` repetitions.
+    ///
+    /// The step is the exact length of the interval. Returns
+    /// [`Iso8601IntervalError::Unbounded`] for `R/…` and `R-1/…`, and
+    /// [`Iso8601DurationError::Nominal`] for years or months.
+    #[inline]
+    pub fn parse_iso8601(s: &str, profile: Iso8601Profile) -> Result<Self, Iso8601IntervalError> {
+        Self::parse_iso8601_with(s, profile, &TimeContext::new())
+    }
+
+    /// Like [`parse_iso8601`](Self::parse_iso8601), with an explicit
+    /// [`TimeContext`].
+    pub fn parse_iso8601_with(
+        s: &str,
+        profile: Iso8601Profile,
+        ctx: &TimeContext,
+    ) -> Result<Self, Iso8601IntervalError> {
+        let (count, interval) = s
+            .strip_prefix('R')
+            .and_then(|rest| rest.split_once('/'))
+            .ok_or(Iso8601IntervalError::Invalid)?;
+        if count.is_empty() || count == "-1" {
+            return Err(Iso8601IntervalError::Unbounded);
+        }
+        if !count.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(Iso8601IntervalError::Invalid);
+        }
+        let count: i128 = count.parse().map_err(|_| Iso8601IntervalError::Invalid)?;
+        let (first, form) = parse_interval::<S>(interval, profile, true, ctx)?;
+        let step = span(first.start, first.end)?;
+        let total = step
+            .as_nanos_i128()
+            .checked_mul(count)
+            .map(ExactDuration::from_nanos)
+            .ok_or(TimeSeriesError::DurationOverflow)?;
+        let (start, end) = match form {
+            Iso8601IntervalForm::DurationEnd => (
+                first
+                    .end
+                    .try_add_exact(-total)
+                    .map_err(TimeSeriesError::from)?,
+                first.end,
+            ),
+            _ => (
+                first.start,
+                first
+                    .start
+                    .try_add_exact(total)
+                    .map_err(TimeSeriesError::from)?,
+            ),
+        };
+        Ok(Self::new(start, end, step)?)
+    }
+
+    /// Format as an ISO 8601 repeating interval `Rn/<interval>`, with `n`
+    /// the total number of items (independent of the cursor).
+    ///
+    /// The interval is the first repetition `[start, start + step)`, or the
+    /// last one for [`Iso8601IntervalForm::DurationEnd`]; see
+    /// [`Period::format_iso8601`].
+    pub fn format_iso8601(
+        &self,
+        form: Iso8601IntervalForm,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+    ) -> String {
+        self.format_iso8601_with(form, profile, opts, &TimeContext::new())
+    }
+
+    /// Like [`format_iso8601`](Self::format_iso8601), with an explicit
+    /// [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the series cannot be written; use
+    /// [`try_format_iso8601_with`](Self::try_format_iso8601_with) to handle
+    /// that case explicitly.
+    pub fn format_iso8601_with(
+        &self,
+        form: Iso8601IntervalForm,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> String {
+        match self.try_format_iso8601_with(form, profile, opts, ctx) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of
+    /// [`format_iso8601_with`](Self::format_iso8601_with).
+    ///
+    /// Returns [`Iso8601IntervalError::Interval`] for a descending series,
+    /// which ISO 8601 cannot express.
+    pub fn try_format_iso8601_with(
+        &self,
+        form: Iso8601IntervalForm,
+        profile: Iso8601Profile,
+        opts: FormatOptions,
+        ctx: &TimeContext,
+    ) -> Result<String, Iso8601IntervalError> {
+        let count = self.len_total();
+        let offset = match form {
+            Iso8601IntervalForm::DurationEnd => count.saturating_sub(1),
+            _ => 0,
+        };
+        let step = self.step();
+        let start = self
+            .step()
+            .as_nanos_i128()
+            .checked_mul(offset as i128)
+            .and_then(|nanos| {
+                self.start()
+                    .try_add_exact(ExactDuration::from_nanos(nanos))
+                    .ok()
+            })
+            .ok_or(OVERFLOW)?;
+        let end = start.try_add_exact(step).map_err(|_| OVERFLOW)?;
+        let interval =
+            Interval::new(start, end).try_format_iso8601_with(form, profile, opts, ctx)?;
+        Ok(format!("R{count}/{interval}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::iso8601::Iso8601Resolution;
+    use crate::model::scale::{TT, UTC};
+
+    fn utc(s: &str) -> Time<UTC> {
+        Time::<UTC>::parse_ccsds_ascii(s).unwrap()
+    }
+
+    #[test]
+    fn interval_forms_parse_to_the_same_half_open_period() {
+        let expected = Interval::new(utc("2024-01-01T00:00:00"), utc("2024-01-01T02:00:00"));
+        let profile = Iso8601Profile::EXTENDED;
+        for s in [
+            "2024-01-01T00:00Z/2024-01-01T02:00Z",
+            "2024-01-01T00:00Z/PT2H",
+            "PT2H/2024-01-01T02:00Z",
+            "2023-12-31T22:00-02:00/PT120M",
+        ] {
+            assert_eq!(
+                Period::<UTC>::parse_iso8601(s, profile).unwrap(),
+                expected,
+                "{s}"
+            );
+        }
+        let minutes = profile.with_resolution(Iso8601Resolution::Minute);
+        let opts = FormatOptions::SECONDS;
+        assert_eq!(
+            expected.format_iso8601(Iso8601IntervalForm::StartDuration, minutes, opts),
+            "2024-01-01T00:00Z/PT2H"
+        );
+        assert_eq!(
+            expected.format_iso8601(Iso8601IntervalForm::DurationEnd, minutes, opts),
+            "PT2H/2024-01-01T02:00Z"
+        );
+
+        // Adjacent intervals share a bound without overlapping.
+        let next = Period::<UTC>::parse_iso8601("PT1H/2024-01-01T03:00Z", profile).unwrap();
+        assert_eq!(expected.intersection(&next), None);
+    }
+
+    #[test]
+    fn nominal_durations_resolve_on_the_explicit_endpoint() {
+        let profile = Iso8601Profile::EXTENDED;
+        let forward = Period::<UTC>::parse_iso8601("2024-01-31T00:00Z/P1M", profile).unwrap();
+        assert_eq!(forward.end, utc("2024-02-29T00:00:00"));
+        let backward = Period::<UTC>::parse_iso8601("P1M/2024-03-31T00:00Z", profile).unwrap();
+        assert_eq!(backward.start, utc("2024-02-29T00:00:00"));
+
+        let tt = Period::<TT>::parse_iso8601("2024-01-01T00:00 TT/P1D", profile).unwrap();
+        assert_eq!(
+            tt.format_iso8601(
+                Iso8601IntervalForm::StartEnd,
+                profile,
+                FormatOptions::SECONDS
+            ),
+            "2024-01-01T00:00:00 TT/2024-01-02T00:00:00 TT"
+        );
+    }
+
+    #[test]
+    fn repeating_intervals_round_trip_through_time_series() {
+        let profile = Iso8601Profile::EXTENDED;
+        let series =
+            TimeSeries::<UTC>::parse_iso8601("R10/2024-01-01T00:00Z/PT1H", profile).unwrap();
+        assert_eq!(series.len_total(), 10);
+        assert_eq!(series.nth_item(9), Some(utc("2024-01-01T09:00:00")));
+        let opts = FormatOptions::SECONDS;
+        assert_eq!(
+            series.format_iso8601(Iso8601IntervalForm::StartDuration, profile, opts),
+            "R10/2024-01-01T00:00:00Z/PT1H"
+        );
+        assert_eq!(
+            series.format_iso8601(Iso8601IntervalForm::DurationEnd, profile, opts),
+            "R10/PT1H/2024-01-01T10:00:00Z"
+        );
+
+        let backward =
+            TimeSeries::<UTC>::parse_iso8601("R10/PT1H/2024-01-01T10:00Z", profile).unwrap();
+        assert_eq!(backward.start(), series.start());
+        assert_eq!(backward.len_total(), 10);
+        let explicit =
+            TimeSeries::<UTC>::parse_iso8601("R2/2024-01-01T00:00Z/2024-01-01T00:30Z", profile)
+                .unwrap();
+        assert_eq!(
+            explicit.step(),
+            ExactDuration::from_nanos(1_800_000_000_000)
+        );
+    }
+
+    #[test]
+    fn malformed_and_unbounded_intervals_are_rejected() {
+        let profile = Iso8601Profile::EXTENDED;
+        let period = |s| Period::<UTC>::parse_iso8601(s, profile);
+        assert_eq!(
+            period("2024-01-01T00:00Z"),
+            Err(Iso8601IntervalError::Invalid)
+        );
+        assert_eq!(period("PT1H/PT2H"), Err(Iso8601IntervalError::Invalid));
+        assert_eq!(
+            period("2024-01-02T00:00Z/2024-01-01T00:00Z"),
+            Err(Iso8601IntervalError::Interval(
+                InvalidIntervalError::StartAfterEnd
+            ))
+        );
+
+        let series = |s| TimeSeries::<UTC>::parse_iso8601(s, profile).map(|s| s.len_total());
+        assert_eq!(
+            series("R/2024-01-01T00:00Z/PT1H"),
+            Err(Iso8601IntervalError::Unbounded)
+        );
+        assert_eq!(
+            series("R-1/2024-01-01T00:00Z/PT1H"),
+            Err(Iso8601IntervalError::Unbounded)
+        );
+        assert_eq!(
+            series("R3/2024-01-01T00:00Z/P1M"),
+            Err(Iso8601IntervalError::Duration(
+                Iso8601DurationError::Nominal
+            ))
+        );
+        assert_eq!(
+            series("R3/2024-01-01T00:00Z/PT0S"),
+            Err(Iso8601IntervalError::Series(TimeSeriesError::ZeroStep))
+        );
+        assert_eq!(series("R0/2024-01-01T00:00Z/PT1H"), Ok(0));
+    }
+}
@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Two-part Julian Dates (`jd1 + jd2`), as used by SOFA/ERFA.
+//!
+//! A single `f64` Julian Date resolves only ~40 µs at present-day epochs.
+//! SOFA-style APIs therefore accept the date as two doubles whose sum is the
+//! Julian Date, with the split chosen so that the second part keeps the
+//! fine detail. [`JdSplit`] selects one of the three common conventions:
+//!
+//! | Convention              | `jd1`                        | `jd2`                      |
+//! |-------------------------|------------------------------|----------------------------|
+//! | [`JdSplit::J2000`]      | `2451545.0`                  | days since J2000.0         |
+//! | [`JdSplit::DateFraction`] | JD of the preceding midnight | fraction of day in `[0, 1)` |
+//! | [`JdSplit::Mjd`]        | `2400000.5`                  | Modified Julian Date       |
+//!
+//! [`JdSplit::DateFraction`] gives the best resolution (≈ 10 ps). Decoding
+//! with [`Time::from_jd_pair`] accepts any split: the two parts are folded
+//! into the compensated `(hi, lo)` storage without first summing them in a
+//! single `f64`.
+
+use crate::format::TimeFormat;
+use crate::foundation::compensated::two_sum;
+use crate::foundation::constats::{J2000_JD_TT_DAY, JD_MINUS_MJD};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::CoordinateScale;
+use crate::model::time::Time;
+use qtty::Second;
+
+const SECONDS_PER_DAY: f64 = 86_400.0;
+/// Seconds from JD 2451544.5 (midnight before J2000.0) to J2000.0.
+const J2000_SECONDS_AFTER_MIDNIGHT: i64 = 43_200;
+
+/// Splitting convention for [`Time::to_jd_pair`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JdSplit {
+    /// `jd1 = 2451545.0`, `jd2` = days since J2000.0.
+    #[default]
+    J2000,
+    /// `jd1` = Julian Date of the preceding midnight (`N + 0.5`),
+    /// `jd2` = fraction of the day in `[0, 1)`.
+    DateFraction,
+    /// `jd1 = 2400000.5`, `jd2` = Modified Julian Date.
+    Mjd,
+}
+
+impl<S: CoordinateScale, F: TimeFormat> Time<S, F> {
+    /// Two-part Julian Date `(jd1, jd2)` on this scale's axis.
+    ///
+    /// `jd1 + jd2` is the Julian Date; see [`JdSplit`] for how the value is
+    /// divided. Non-finite instants and instants beyond ±2⁵³ s of J2000 fall
+    /// back to the [`JdSplit::J2000`] layout.
+    pub fn to_jd_pair(self, split: JdSplit) -> (f64, f64) {
+        let j2000_jd = J2000_JD_TT_DAY.value();
+        let Ok((whole, fraction)) = self.j2000_whole_and_fraction() else {
+            let (hi, lo) = self.split_seconds();
+            return (
+                j2000_jd,
+                hi.value() / SECONDS_PER_DAY + lo.value() / SECONDS_PER_DAY,
+            );
+        };
+        match split {
+            JdSplit::J2000 => (j2000_jd, (whole as f64 + fraction) / SECONDS_PER_DAY),
+            JdSplit::DateFraction => {
+                let since_midnight = whole + J2000_SECONDS_AFTER_MIDNIGHT;
+                let day = since_midnight.div_euclid(86_400);
+                let second_of_day = since_midnight.rem_euclid(86_400) as f64 + fraction;
+                (j2000_jd - 0.5 + day as f64, second_of_day / SECONDS_PER_DAY)
+            }
+            JdSplit::Mjd => {
+                let mjd_zero_jd = JD_MINUS_MJD.value();
+                // J2000.0 is MJD 51544.5, an integer number of seconds.
+                let offset = ((j2000_jd - mjd_zero_jd) * SECONDS_PER_DAY) as i64;
+                (
+                    mjd_zero_jd,
+                    ((whole + offset) as f64 + fraction) / SECONDS_PER_DAY,
+                )
+            }
+        }
+    }
+}
+
+impl<S: CoordinateScale> Time<S> {
+    /// Build from a two-part Julian Date `jd1 + jd2` on this scale's axis.
+    ///
+    /// Any split is accepted; the conversion keeps the rounding error of each
+    /// step in the compensation word instead of summing the parts first.
+    /// Non-finite parts return [`ConversionError::NonFinite`].
+    pub fn from_jd_pair(jd1: f64, jd2: f64) -> Result<Self, ConversionError> {
+        if !jd1.is_finite() || !jd2.is_finite() {
+            return Err(ConversionError::NonFinite);
+        }
+        let (days, err1) = two_sum(jd1, -J2000_JD_TT_DAY.value());
+        let (days, err2) = two_sum(days, jd2);
+        let hi = days * SECONDS_PER_DAY;
+        let hi_err = days.mul_add(SECONDS_PER_DAY, -hi);
+        let lo = hi_err + (err1 + err2) * SECONDS_PER_DAY;
+        Self::try_from_raw_j2000_seconds_split(Second::new(hi), Second::new(lo))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExactDuration, JD, TAI, TT};
+
+    /// An instant on 2024-06-14 TT with a nanosecond-exact offset.
+    fn sample() -> Time<TT> {
+        Time::try_from_exact_j2000(ExactDuration::from_nanos(771_640_496_123_456_789)).unwrap()
+    }
+
+    #[test]
+    fn conventions_sum_to_the_julian_date() {
+        let t = sample();
+        let jd = t.to::<JD>().raw().value();
+        for split in [JdSplit::J2000, JdSplit::DateFraction, JdSplit::Mjd] {
+            let (jd1, jd2) = t.to_jd_pair(split);
+            assert!((jd1 + jd2 - jd).abs() < 1e-9, "{split:?}: {jd1} + {jd2}");
+        }
+        assert_eq!(t.to_jd_pair(JdSplit::J2000).0, 2_451_545.0);
+        assert_eq!(t.to_jd_pair(JdSplit::Mjd).0, 2_400_000.5);
+    }
+
+    #[test]
+    fn date_fraction_starts_at_midnight() {
+        let (jd1, jd2) = sample().to_jd_pair(JdSplit::DateFraction);
+        assert_eq!(jd1, 2_460_475.5);
+        assert!((0.0..1.0).contains(&jd2));
+        let noon = Time::<TT>::from_jd_pair(2_451_545.0, 0.0).unwrap();
+        assert_eq!(noon.to_jd_pair(JdSplit::DateFraction), (2_451_544.5, 0.5));
+        let before = Time::<TT>::from_jd_pair(2_451_544.5, -0.25).unwrap();
+        assert_eq!(
+            before.to_jd_pair(JdSplit::DateFraction),
+            (2_451_543.5, 0.75)
+        );
+    }
+
+    #[test]
+    fn date_fraction_round_trips_to_the_nanosecond() {
+        let t = sample();
+        let (jd1, jd2) = t.to_jd_pair(JdSplit::DateFraction);
+        let back = Time::<TT>::from_jd_pair(jd1, jd2).unwrap();
+        assert_eq!(back.to_exact_j2000(), t.to_exact_j2000());
+    }
+
+    #[test]
+    fn from_jd_pair_keeps_detail_lost_by_a_single_jd() {
+        // 1 µs after the midnight of 2024-06-14.
+        let jd2 = 1e-6 / SECONDS_PER_DAY;
+        let paired = Time::<TAI>::from_jd_pair(2_460_475.5, jd2).unwrap();
+        let midnight = Time::<TAI>::from_jd_pair(2_460_475.5, 0.0).unwrap();
+        let delta = paired.to_exact_j2000().unwrap() - midnight.to_exact_j2000().unwrap();
+        assert_eq!(delta, ExactDuration::from_nanos(1_000));
+        // The same instant as one f64 JD cannot resolve the microsecond.
+        assert_eq!(2_460_475.5 + jd2, 2_460_475.5);
+    }
+
+    #[test]
+    fn from_jd_pair_rejects_non_finite() {
+        assert_eq!(
+            Time::<TT>::from_jd_pair(f64::NAN, 0.0),
+            Err(ConversionError::NonFinite)
+        );
+        assert_eq!(
+            Time::<TT>::from_jd_pair(2_451_545.0, f64::INFINITY),
+            Err(ConversionError::NonFinite)
+        );
+    }
+}
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `jiff` interop for `Time<UTC>`.
+//!
+//! [`jiff::Timestamp`] and [`jiff::Zoned`] convert through the civil label
+//! ([`CivilDateTime`]), so nanoseconds are kept exactly. UTC before 1961
+//! follows the `chrono` bridge: it is
+//! [`ConversionError::UtcBeforeDefinition`] unless the [`TimeContext`] allows
+//! it. `jiff` cannot label `23:59:60`, so converting a leap-second instant
+//! out is [`ConversionError::InvalidLeapSecond`].
+
+use jiff::civil::DateTime;
+use jiff::tz::TimeZone;
+use jiff::{Timestamp, Zoned};
+
+use crate::earth::context::TimeContext;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::UTC;
+use crate::model::time::Time;
+
+use super::CivilDateTime;
+
+fn timestamp_from_civil(civil: CivilDateTime) -> Result<Timestamp, ConversionError> {
+    let year = i16::try_from(civil.year).map_err(|_| ConversionError::OutOfRange)?;
+    let dt = DateTime::new(
+        year,
+        civil.month as i8,
+        civil.day as i8,
+        civil.hour as i8,
+        civil.minute as i8,
+        civil.second as i8,
+        civil.nanosecond as i32,
+    )
+    .map_err(|_| ConversionError::OutOfRange)?;
+    dt.to_zoned(TimeZone::UTC)
+        .map(|zoned| zoned.timestamp())
+        .map_err(|_| ConversionError::OutOfRange)
+}
+
+impl Time<UTC> {
+    /// Build a UTC instant from a `jiff::Timestamp` using the context's
+    /// captured time-data bundle.
+    pub fn try_from_jiff_timestamp_with(
+        ts: Timestamp,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        let dt = TimeZone::UTC.to_datetime(ts);
+        let civil = CivilDateTime::new(
+            dt.year() as i64,
+            dt.month() as u32,
+            dt.day() as u32,
+            dt.hour() as u32,
+            dt.minute() as u32,
+            dt.second() as u32,
+            dt.subsec_nanosecond() as u32,
+        )?;
+        Self::from_civil_with(civil, ctx)
+    }
+
+    /// Build a UTC instant from a `jiff::Timestamp`.
+    ///
+    /// Snapshots the active time-data bundle at call time via
+    /// [`TimeContext::new`].
+    #[inline]
+    pub fn try_from_jiff_timestamp(ts: Timestamp) -> Result<Self, ConversionError> {
+        Self::try_from_jiff_timestamp_with(ts, &TimeContext::new())
+    }
+
+    /// Convert to a `jiff::Timestamp` using the context's captured
+    /// time-data bundle.
+    pub fn try_to_jiff_timestamp_with(
+        self,
+        ctx: &TimeContext,
+    ) -> Result<Timestamp, ConversionError> {
+        timestamp_from_civil(self.civil_without_leap_with(ctx)?)
+    }
+
+    /// Convert to a `jiff::Timestamp`.
+    ///
+    /// Snapshots the active time-data bundle at call time via
+    /// [`TimeContext::new`].
+    #[inline]
+    pub fn try_to_jiff_timestamp(self) -> Result<Timestamp, ConversionError> {
+        self.try_to_jiff_timestamp_with(&TimeContext::new())
+    }
+
+    /// Build a UTC instant from the timestamp of a `jiff::Zoned` using the
+    /// context's captured time-data bundle.
+    #[inline]
+    pub fn try_from_jiff_zoned_with(
+        zoned: &Zoned,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        Self::try_from_jiff_timestamp_with(zoned.timestamp(), ctx)
+    }
+
+    /// Build a UTC instant from the timestamp of a `jiff::Zoned`.
+    ///
+    /// Snapshots the active time-data bundle at call time via
+    /// [`TimeContext::new`].
+    #[inline]
+    pub fn try_from_jiff_zoned(zoned: &Zoned) -> Result<Self, ConversionError> {
+        Self::try_from_jiff_zoned_with(zoned, &TimeContext::new())
+    }
+
+    /// Convert to a `jiff::Zoned` in `tz`, using the context's captured
+    /// time-data bundle.
+    #[inline]
+    pub fn try_to_jiff_zoned_with(
+        self,
+        tz: TimeZone,
+        ctx: &TimeContext,
+    ) -> Result<Zoned, ConversionError> {
+        Ok(self.try_to_jiff_timestamp_with(ctx)?.to_zoned(tz))
+    }
+
+    /// Convert to a `jiff::Zoned` in `tz`.
+    ///
+    /// Snapshots the active time-data bundle at call time via
+    /// [`TimeContext::new`].
+    #[inline]
+    pub fn try_to_jiff_zoned(self, tz: TimeZone) -> Result<Zoned, ConversionError> {
+        self.try_to_jiff_zoned_with(tz, &TimeContext::new())
+    }
+}
+
+impl TryFrom<Timestamp> for Time<UTC> {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Timestamp) -> Result<Self, Self::Error> {
+        Self::try_from_jiff_timestamp(value)
+    }
+}
+
+impl TryFrom<&Zoned> for Time<UTC> {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: &Zoned) -> Result<Self, Self::Error> {
+        Self::try_from_jiff_zoned(value)
+    }
+}
+
+impl TryFrom<Time<UTC>> for Timestamp {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Time<UTC>) -> Result<Self, Self::Error> {
+        value.try_to_jiff_timestamp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timestamps_and_zoned_round_trip_with_nanoseconds() {
+        let expected = Time::<UTC>::parse_rfc3339("2024-06-14T12:34:56.123456789Z").unwrap();
+        let ts = Timestamp::try_from(expected).unwrap();
+        assert_eq!(ts, Timestamp::new(1_718_368_496, 123_456_789).unwrap());
+        assert_eq!(Time::<UTC>::try_from(ts).unwrap(), expected);
+
+        let tz = TimeZone::fixed(jiff::tz::offset(-7));
+        let zoned = expected.try_to_jiff_zoned(tz).unwrap();
+        assert_eq!(zoned.hour(), 5);
+        assert_eq!(Time::<UTC>::try_from(&zoned).unwrap(), expected);
+    }
+
+    #[test]
+    fn leap_seconds_and_pre_1961_follow_the_chrono_policy() {
+        let leap = Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60.5Z").unwrap();
+        assert_eq!(
+            Timestamp::try_from(leap),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+
+        let ts = Timestamp::from_second(-631_152_000).unwrap();
+        assert_eq!(
+            Time::<UTC>::try_from(ts),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+        let ctx = TimeContext::new().allow_pre_definition_utc();
+        let utc = Time::<UTC>::try_from_jiff_timestamp_with(ts, &ctx).unwrap();
+        assert_eq!(utc.try_to_jiff_timestamp_with(&ctx), Ok(ts));
+    }
+
+    #[test]
+    fn zoned_offsets_move_the_local_date_not_the_instant() {
+        // 20:00 at -09:30 on the 14th is 05:30 UTC on the 15th.
+        let tz = TimeZone::fixed(jiff::tz::Offset::from_seconds(-34_200).unwrap());
+        let local = DateTime::new(2024, 6, 14, 20, 0, 0, 1).unwrap();
+        let zoned = local.to_zoned(tz.clone()).unwrap();
+        let expected = Time::<UTC>::parse_rfc3339("2024-06-15T05:30:00.000000001Z").unwrap();
+        assert_eq!(Time::<UTC>::try_from_jiff_zoned(&zoned), Ok(expected));
+        let back = expected.try_to_jiff_zoned(tz).unwrap();
+        assert_eq!((back.datetime(), back.offset()), (local, zoned.offset()));
+
+        // East of Greenwich the local date runs a day ahead of UTC.
+        let nepal = TimeZone::fixed(jiff::tz::Offset::from_seconds(20_700).unwrap());
+        let late = Time::<UTC>::parse_rfc3339("2024-12-31T18:20:00Z").unwrap();
+        let zoned = late.try_to_jiff_zoned(nepal).unwrap();
+        assert_eq!(
+            zoned.datetime(),
+            DateTime::new(2025, 1, 1, 0, 5, 0, 0).unwrap()
+        );
+        assert_eq!(Time::<UTC>::try_from(&zoned), Ok(late));
+
+        let leap = Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60Z").unwrap();
+        assert_eq!(
+            leap.try_to_jiff_zoned(TimeZone::fixed(jiff::tz::offset(9))),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn pre_1961_zoned_labels_match_the_chrono_bridge() {
+        // 1950-12-31T22:00-05:00 is 1951-01-01T03:00Z.
+        let tz = TimeZone::fixed(jiff::tz::offset(-5));
+        let zoned = DateTime::new(1950, 12, 31, 22, 0, 0, 0)
+            .unwrap()
+            .to_zoned(tz.clone())
+            .unwrap();
+        let dt = ::chrono::DateTime::<::chrono::Utc>::from_timestamp(-599_605_200, 0).unwrap();
+        assert_eq!(
+            Time::<UTC>::try_from(&zoned),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+        assert_eq!(
+            Time::<UTC>::try_from_chrono(dt).map(|_| ()),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+
+        let ctx = TimeContext::new().allow_pre_definition_utc();
+        let utc = Time::<UTC>::try_from_jiff_zoned_with(&zoned, &ctx).unwrap();
+        let via_chrono = Time::<UTC>::try_from_chrono_with(dt, &ctx).unwrap();
+        // The chrono bridge works in `f64` seconds: it agrees on the way in
+        // to well under 1 µs, and on the way out to the 50 µs it is tested to.
+        let nanos = |t: Time<UTC>| t.to_exact_j2000().unwrap().as_nanos_i128();
+        assert!((nanos(utc) - nanos(via_chrono)).abs() < 1_000);
+        let out = utc.try_to_chrono_with(&ctx).unwrap() - dt;
+        assert!(out.num_microseconds().unwrap().abs() < 50);
+        let back = utc.try_to_jiff_zoned_with(tz.clone(), &ctx).unwrap();
+        assert_eq!(back, zoned);
+        assert_eq!(
+            utc.try_to_jiff_zoned(tz),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+    }
+}
@@ -45,6 +45,19 @@ impl TimeFormat for Unix {
     const NAME: &'static str = "Unix";
 }
 
+/// Leap-second-counting POSIX seconds, as used by `right/` zoneinfo:
+/// TAI seconds since 1970-01-01T00:00:00 TAI minus 10 s.
+///
+/// Unlike [`Unix`], the count advances through `23:59:60`, so every UTC
+/// instant (leap seconds included) has exactly one value.
+#[derive(Debug, Copy, Clone)]
+pub struct UnixRight;
+impl Sealed for UnixRight {}
+impl TimeFormat for UnixRight {
+    type Unit = SecondUnit;
+    const NAME: &'static str = "UnixRight";
+}
+
 /// GPS seconds since 1980-01-06T00:00:00 TAI.
 #[derive(Debug, Copy, Clone)]
 pub struct GPS;
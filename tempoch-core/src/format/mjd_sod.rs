@@ -0,0 +1,441 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Integer Modified Julian Date plus seconds of day.
+//!
+//! SP3, RINEX clock, and IERS products label epochs as an integer MJD and
+//! the seconds elapsed since that day's midnight. [`MjdSod`] keeps both parts
+//! exact (the seconds of day are an [`ExactDuration`]), where the scalar
+//! [`crate::MJD`] format resolves only ~1 µs at present-day epochs.
+//!
+//! The day boundaries depend on the scale, which is abstracted by
+//! [`CalendarScale`]:
+//!
+//! - on every [`ContinuousScale`] a day is exactly 86 400 SI seconds of the
+//!   scale's own axis, so the decomposition is pure integer arithmetic, like
+//!   [`crate::GnssWeek`];
+//! - on [`UTC`] the days are those of the active UTC-TAI table. A day that
+//!   ends with a positive leap second is 86 401 s long and its last second is
+//!   labelled with `second_of_day ∈ [86 400 s, 86 401 s)`.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{ExactDuration, MjdSod, Time, UTC};
+//!
+//! // 2016-12-31T23:59:60.5 UTC
+//! let leap = MjdSod::new(
+//!     qtty::i64::Day::new(57_753),
+//!     ExactDuration::from_nanos(86_400_500_000_000),
+//! )
+//! .unwrap();
+//! let t = Time::<UTC>::from_mjd_sod(leap).unwrap();
+//! assert!(t.is_leap_second());
+//! assert_eq!(t.to_mjd_sod().unwrap(), leap);
+//! ```
+
+use crate::data::runtime_data::{
+    time_data_tai_nanos_from_utc_day_nanos, time_data_utc_day_nanos_from_tai_nanos,
+};
+use crate::earth::context::TimeContext;
+use crate::format::TimeFormat;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{
+    ContinuousScale, CoordinateScale, BDT, ET, GPST, GST, QZSST, TAI, TCB, TCG, TDB, TT, UT1, UTC,
+};
+use crate::model::time::Time;
+
+use super::gnss_week::{
+    BDT_EPOCH_J2000_SECONDS, GPST_EPOCH_J2000_SECONDS, GST_EPOCH_J2000_SECONDS,
+    QZSST_EPOCH_J2000_SECONDS,
+};
+use super::{FitsTimeSys, GnssTimeSystem};
+
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+/// J2000.0 expressed as nanoseconds since MJD 0 (MJD 51 544.5).
+pub(super) const J2000_NANOS_SINCE_MJD_ZERO: i128 = 4_453_444_800 * NANOS_PER_SECOND;
+
+/// Integer MJD plus exact seconds of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MjdSod {
+    /// Modified Julian Date of the day's midnight.
+    pub mjd: qtty::i64::Day,
+    /// Time elapsed since that midnight, in `[0, 86 401 s)`.
+    pub second_of_day: ExactDuration,
+}
+
+impl MjdSod {
+    /// Construct, validating `0 ≤ second_of_day < 86 401 s`.
+    ///
+    /// The upper bound admits the leap second of a UTC day; whether a value at
+    /// or beyond 86 400 s exists is checked when it is converted to a
+    /// [`Time`] on a concrete scale.
+    pub fn new(mjd: qtty::i64::Day, second_of_day: ExactDuration) -> Result<Self, ConversionError> {
+        let nanos = second_of_day.as_nanos_i128();
+        if !(0..NANOS_PER_DAY + NANOS_PER_SECOND).contains(&nanos) {
+            return Err(ConversionError::OutOfRange);
+        }
+        Ok(Self { mjd, second_of_day })
+    }
+
+    /// Nominal duration since MJD 0 (`86 400 s · mjd + second_of_day`).
+    ///
+    /// This is elapsed time only on continuous scales; on UTC it ignores the
+    /// leap seconds inserted before `mjd`.
+    pub fn to_nominal_duration(&self) -> ExactDuration {
+        ExactDuration::from_nanos(
+            self.mjd.value() as i128 * NANOS_PER_DAY + self.second_of_day.as_nanos_i128(),
+        )
+    }
+
+    fn from_day_nanos(mjd: i128, nanos_of_day: i128) -> Result<Self, ConversionError> {
+        let mjd = i64::try_from(mjd).map_err(|_| ConversionError::OutOfRange)?;
+        Self::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(nanos_of_day),
+        )
+    }
+}
+
+/// Scales whose instants can be labelled by calendar days.
+///
+/// Implemented for [`UTC`] (days from the UTC-TAI table, including leap
+/// seconds) and for every [`ContinuousScale`] (uniform 86 400 s days on the
+/// scale's own axis).
+pub trait CalendarScale: CoordinateScale {
+    /// Whether this is [`UTC`], whose labels may carry leap seconds and
+    /// zone offsets.
+    const IS_UTC: bool = false;
+
+    /// Start of GNSS week 0 in J2000 seconds on this scale (the
+    /// [`GnssWeekScale`](super::GnssWeekScale) epoch), or `None` for scales
+    /// without GNSS weeks.
+    const GNSS_WEEK_EPOCH_J2000_SECONDS: Option<f64> = None;
+
+    /// FITS `TIMESYS` value for this scale, if FITS defines one.
+    const FITS_TIMESYS: Option<FitsTimeSys> = None;
+
+    /// RINEX/SP3 time system written for epochs on this scale, if any.
+    const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = None;
+
+    /// Decompose `time` into an integer MJD and seconds of day.
+    fn time_to_mjd_sod(time: Time<Self>, ctx: &TimeContext) -> Result<MjdSod, ConversionError>;
+
+    /// Build the instant labelled by `value`.
+    fn mjd_sod_to_time(value: MjdSod, ctx: &TimeContext) -> Result<Time<Self>, ConversionError>;
+}
+
+/// Uniform 86 400 s days on a [`ContinuousScale`]'s own axis.
+fn continuous_mjd_sod<S: ContinuousScale>(time: Time<S>) -> Result<MjdSod, ConversionError> {
+    let nanos = time.to_exact_j2000()?.as_nanos_i128() + J2000_NANOS_SINCE_MJD_ZERO;
+    MjdSod::from_day_nanos(
+        nanos.div_euclid(NANOS_PER_DAY),
+        nanos.rem_euclid(NANOS_PER_DAY),
+    )
+}
+
+/// Inverse of [`continuous_mjd_sod`].
+fn continuous_time<S: ContinuousScale>(value: MjdSod) -> Result<Time<S>, ConversionError> {
+    if value.second_of_day.as_nanos_i128() >= NANOS_PER_DAY {
+        return Err(ConversionError::OutOfRange);
+    }
+    let nanos = value.to_nominal_duration().as_nanos_i128() - J2000_NANOS_SINCE_MJD_ZERO;
+    Time::try_from_exact_j2000(ExactDuration::from_nanos(nanos))
+}
+
+macro_rules! continuous_calendar {
+    ($($scale:ident { $($item:item)* })+) => {
+        $(
+            impl CalendarScale for $scale {
+                $($item)*
+
+                fn time_to_mjd_sod(
+                    time: Time<Self>,
+                    _ctx: &TimeContext,
+                ) -> Result<MjdSod, ConversionError> {
+                    continuous_mjd_sod(time)
+                }
+
+                fn mjd_sod_to_time(
+                    value: MjdSod,
+                    _ctx: &TimeContext,
+                ) -> Result<Time<Self>, ConversionError> {
+                    continuous_time(value)
+                }
+            }
+        )+
+    };
+}
+
+continuous_calendar! {
+    TAI {
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Tai);
+        const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = Some(GnssTimeSystem::Tai);
+    }
+    TT {
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Tt);
+    }
+    TDB {
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Tdb);
+    }
+    TCG {
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Tcg);
+    }
+    TCB {
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Tcb);
+    }
+    UT1 {
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Ut1);
+    }
+    ET {}
+    GPST {
+        const GNSS_WEEK_EPOCH_J2000_SECONDS: Option<f64> = Some(GPST_EPOCH_J2000_SECONDS);
+        const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Gps);
+        const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = Some(GnssTimeSystem::Gps);
+    }
+    GST {
+        const GNSS_WEEK_EPOCH_J2000_SECONDS: Option<f64> = Some(GST_EPOCH_J2000_SECONDS);
+        const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = Some(GnssTimeSystem::Galileo);
+    }
+    BDT {
+        const GNSS_WEEK_EPOCH_J2000_SECONDS: Option<f64> = Some(BDT_EPOCH_J2000_SECONDS);
+        const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = Some(GnssTimeSystem::BeiDou);
+    }
+    QZSST {
+        const GNSS_WEEK_EPOCH_J2000_SECONDS: Option<f64> = Some(QZSST_EPOCH_J2000_SECONDS);
+        const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = Some(GnssTimeSystem::Qzss);
+    }
+}
+
+impl CalendarScale for UTC {
+    const IS_UTC: bool = true;
+    const FITS_TIMESYS: Option<FitsTimeSys> = Some(FitsTimeSys::Utc);
+    const GNSS_TIME_SYSTEM: Option<GnssTimeSystem> = Some(GnssTimeSystem::Utc);
+
+    fn time_to_mjd_sod(time: Time<UTC>, ctx: &TimeContext) -> Result<MjdSod, ConversionError> {
+        let tai_nanos = time.to_exact_j2000()?.as_nanos_i128() + J2000_NANOS_SINCE_MJD_ZERO;
+        let (mjd, nanos_of_day) = time_data_utc_day_nanos_from_tai_nanos(
+            ctx.time_data(),
+            tai_nanos,
+            ctx.allows_pre_definition_utc(),
+        )?;
+        MjdSod::from_day_nanos(mjd as i128, nanos_of_day)
+    }
+
+    fn mjd_sod_to_time(value: MjdSod, ctx: &TimeContext) -> Result<Time<UTC>, ConversionError> {
+        let tai_nanos = time_data_tai_nanos_from_utc_day_nanos(
+            ctx.time_data(),
+            value.mjd.value(),
+            value.second_of_day.as_nanos_i128(),
+            ctx.allows_pre_definition_utc(),
+        )?;
+        Time::try_from_exact_j2000(ExactDuration::from_nanos(
+            tai_nanos - J2000_NANOS_SINCE_MJD_ZERO,
+        ))
+    }
+}
+
+impl<S: CalendarScale, F: TimeFormat> Time<S, F> {
+    /// Integer MJD and exact seconds of day, using the default [`TimeContext`].
+    pub fn to_mjd_sod(self) -> Result<MjdSod, ConversionError> {
+        self.to_mjd_sod_with(&TimeContext::new())
+    }
+
+    /// Integer MJD and exact seconds of day under an explicit context.
+    ///
+    /// Seconds of day are rounded to the nearest nanosecond.
+    pub fn to_mjd_sod_with(self, ctx: &TimeContext) -> Result<MjdSod, ConversionError> {
+        S::time_to_mjd_sod(self.to_j2000s(), ctx)
+    }
+}
+
+impl<S: CalendarScale> Time<S> {
+    /// Build from an integer MJD and seconds of day, using the default
+    /// [`TimeContext`].
+    pub fn from_mjd_sod(value: MjdSod) -> Result<Self, ConversionError> {
+        Self::from_mjd_sod_with(value, &TimeContext::new())
+    }
+
+    /// Build from an integer MJD and seconds of day under an explicit context.
+    ///
+    /// Returns [`ConversionError::InvalidLeapSecond`] for a UTC second of day in
+    /// `[86 400 s, 86 401 s)` on a day without a leap second, and
+    /// [`ConversionError::OutOfRange`] for seconds of day beyond the length of
+    /// the day.
+    pub fn from_mjd_sod_with(value: MjdSod, ctx: &TimeContext) -> Result<Self, ConversionError> {
+        S::mjd_sod_to_time(value, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GnssWeek, GPST, MJD, TAI, TT};
+
+    fn mjd_sod(mjd: i64, nanos: i128) -> MjdSod {
+        MjdSod::new(qtty::i64::Day::new(mjd), ExactDuration::from_nanos(nanos)).unwrap()
+    }
+
+    fn utc(s: &str) -> Time<UTC> {
+        Time::<UTC>::parse_rfc3339(s).unwrap()
+    }
+
+    #[test]
+    fn new_validates_second_of_day() {
+        let day = qtty::i64::Day::new(60_000);
+        assert!(MjdSod::new(day, ExactDuration::from_nanos(-1)).is_err());
+        assert!(MjdSod::new(day, ExactDuration::from_nanos(86_401 * NANOS_PER_SECOND)).is_err());
+        assert!(MjdSod::new(
+            day,
+            ExactDuration::from_nanos(86_401 * NANOS_PER_SECOND - 1)
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn continuous_scale_keeps_nanoseconds() {
+        let value = mjd_sod(60_475, 45_296_123_456_789);
+        let t = Time::<TT>::from_mjd_sod(value).unwrap();
+        assert_eq!(t.to_mjd_sod().unwrap(), value);
+        let mjd = t.to::<MJD>().raw().value();
+        assert!((mjd - (60_475.0 + 45_296.123_456_789 / 86_400.0)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn continuous_scale_rejects_leap_second_label() {
+        assert_eq!(
+            Time::<TAI>::from_mjd_sod(mjd_sod(57_753, 86_400 * NANOS_PER_SECOND)),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn gnss_scale_agrees_with_gnss_week() {
+        // GPS week 0 starts at MJD 44 244 (1980-01-06).
+        let t = Time::<GPST>::from_mjd_sod(mjd_sod(44_244 + 7 * 2_300 + 3, 12 * NANOS_PER_SECOND))
+            .unwrap();
+        let week = t.to_gnss_week().unwrap();
+        assert_eq!(
+            week,
+            GnssWeek::new(
+                qtty::u32::Week::new(2_300),
+                qtty::u32::Second::new(3 * 86_400 + 12),
+                qtty::u32::Nanosecond::new(0),
+            )
+            .unwrap()
+        );
+    }
+
+    /// The chrono bridge goes through a single `f64` Julian Date (~40 µs
+    /// resolution); allow its rounding.
+    fn assert_close(a: Time<UTC>, b: Time<UTC>) {
+        let delta = a.diff_exact(b).unwrap().as_nanos_i128();
+        assert!(delta.abs() < 50_000, "{a:?} vs {b:?}: {delta} ns");
+    }
+
+    #[test]
+    fn only_utc_is_utc() {
+        assert_eq!(
+            [
+                UTC::IS_UTC,
+                TAI::IS_UTC,
+                TT::IS_UTC,
+                GPST::IS_UTC,
+                UT1::IS_UTC
+            ],
+            [true, false, false, false, false]
+        );
+    }
+
+    #[test]
+    fn gnss_week_epochs_follow_gnss_week_scale() {
+        use crate::{GnssWeekScale, BDT, GST, QZSST};
+        assert_eq!(
+            GPST::GNSS_WEEK_EPOCH_J2000_SECONDS,
+            Some(GPST::epoch_j2000_seconds())
+        );
+        assert_eq!(
+            GST::GNSS_WEEK_EPOCH_J2000_SECONDS,
+            Some(GST::epoch_j2000_seconds())
+        );
+        assert_eq!(
+            BDT::GNSS_WEEK_EPOCH_J2000_SECONDS,
+            Some(BDT::epoch_j2000_seconds())
+        );
+        assert_eq!(
+            QZSST::GNSS_WEEK_EPOCH_J2000_SECONDS,
+            Some(QZSST::epoch_j2000_seconds())
+        );
+        assert_eq!(TT::GNSS_WEEK_EPOCH_J2000_SECONDS, None);
+        assert_eq!(UTC::GNSS_WEEK_EPOCH_J2000_SECONDS, None);
+    }
+
+    #[test]
+    fn utc_matches_civil_labels() {
+        let value = mjd_sod(60_475, 45_296_123_456_789);
+        let t = Time::<UTC>::from_mjd_sod(value).unwrap();
+        assert_close(t, utc("2024-06-14T12:34:56.123456789Z"));
+        assert_eq!(t.to_mjd_sod().unwrap(), value);
+    }
+
+    #[test]
+    fn utc_leap_day_has_86401_seconds() {
+        let last_ordinary = mjd_sod(57_753, 86_400 * NANOS_PER_SECOND - 1);
+        let before = Time::<UTC>::from_mjd_sod(last_ordinary).unwrap();
+        let after = Time::<UTC>::from_mjd_sod(mjd_sod(57_754, 0)).unwrap();
+        let mid_leap = mjd_sod(57_753, 86_400 * NANOS_PER_SECOND + 500_000_000);
+        assert!(Time::<UTC>::from_mjd_sod(mid_leap)
+            .unwrap()
+            .is_leap_second());
+        assert_close(before, utc("2016-12-31T23:59:59.999999999Z"));
+        assert_close(after, utc("2017-01-01T00:00:00Z"));
+        assert_eq!(before.to_mjd_sod().unwrap(), last_ordinary);
+        assert_eq!(after.to_mjd_sod().unwrap(), mjd_sod(57_754, 0));
+        assert_eq!(
+            after.to_exact_j2000().unwrap() - before.to_exact_j2000().unwrap(),
+            ExactDuration::from_nanos(NANOS_PER_SECOND + 1)
+        );
+
+        for nanos in [0, 1, 500_000_000, 999_999_999] {
+            let label = mjd_sod(57_753, 86_400 * NANOS_PER_SECOND + nanos);
+            let t = Time::<UTC>::from_mjd_sod(label).unwrap();
+            assert_eq!(t.to_mjd_sod().unwrap(), label);
+            assert_eq!(
+                t.to_exact_j2000().unwrap() - before.to_exact_j2000().unwrap(),
+                ExactDuration::from_nanos(nanos + 1)
+            );
+        }
+    }
+
+    #[test]
+    fn utc_rejects_leap_second_on_ordinary_day() {
+        assert_eq!(
+            Time::<UTC>::from_mjd_sod(mjd_sod(60_475, 86_400 * NANOS_PER_SECOND)),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+    }
+
+    #[test]
+    fn utc_before_definition_follows_context_policy() {
+        let early = mjd_sod(30_000, 0);
+        assert_eq!(
+            Time::<UTC>::from_mjd_sod(early),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+        let ctx = TimeContext::new().allow_pre_definition_utc();
+        let t = Time::<UTC>::from_mjd_sod_with(early, &ctx).unwrap();
+        assert_eq!(t.to_mjd_sod_with(&ctx).unwrap(), early);
+    }
+
+    #[test]
+    fn utc_rubber_second_era_round_trips() {
+        let value = mjd_sod(38_820, 21_600_250_000_000);
+        let t = Time::<UTC>::from_mjd_sod(value).unwrap();
+        assert_close(t, utc("1965-03-01T06:00:00.25Z"));
+        let back = t.to_mjd_sod().unwrap();
+        assert_eq!(back.mjd, value.mjd);
+        let drift = back.second_of_day.as_nanos_i128() - value.second_of_day.as_nanos_i128();
+        assert!(drift.abs() <= 1, "{drift} ns");
+    }
+}
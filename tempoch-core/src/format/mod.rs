@@ -6,7 +6,8 @@
 //! A *format* marker specifies how a time instant is externally expressed.
 //! The built-in markers live in [`markers`]: Julian Day (`JD`),
 //! Modified Julian Day (`MJD`), J2000 seconds (`J2000s`), POSIX seconds
-//! (`Unix`), and GPS seconds (`GPS`). Format is orthogonal to *scale*:
+//! (`Unix`), leap-second-counting `right/` POSIX seconds (`UnixRight`), and
+//! GPS seconds (`GPS`). Format is orthogonal to *scale*:
 //! `JulianDate<TT>` and `JulianDate<UTC>` share the same format but live on
 //! different physical time axes, and the compiler treats them as distinct,
 //! incompatible types.
@@ -32,18 +33,69 @@ mod time_format;
 pub use time_format::TimeFormat;
 
 pub mod markers;
-pub use markers::{J2000s, Unix, GPS, JD, MJD};
+pub use markers::{J2000s, Unix, UnixRight, GPS, JD, MJD};
 
 mod traits;
 pub use traits::{FormatForScale, InfallibleFormatForScale};
 
 mod impls;
 
+#[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "hifitime")]
+mod hifitime;
+#[cfg(feature = "hifitime")]
+pub use self::hifitime::HifitimeScale;
 pub mod iso;
-pub use iso::{FormatOptions, FormatPrecision};
+#[cfg(feature = "jiff")]
+mod jiff;
+#[cfg(feature = "time")]
+mod time;
+pub use iso::{CcsdsAsciiCode, FormatOptions, FormatPrecision, UtcOffset};
+pub mod iso8601;
+pub use iso8601::{
+    DecimalSign, Iso8601DateForm, Iso8601Notation, Iso8601Profile, Iso8601Resolution,
+};
+pub mod iso_duration;
+pub use iso_duration::{Iso8601Duration, Iso8601DurationError};
+pub mod iso_interval;
+pub use iso_interval::{Iso8601IntervalError, Iso8601IntervalForm};
 pub mod gnss_week;
 pub use gnss_week::{GnssWeek, GnssWeekScale};
+pub mod epoch;
+pub use epoch::{EpochFormat, EpochUnit};
+pub mod jd_pair;
+pub use jd_pair::JdSplit;
+pub mod mjd_sod;
+pub use mjd_sod::{CalendarScale, MjdSod};
+pub mod tai64;
+pub use tai64::{Tai64Kind, Tai64Label};
+pub mod ntp;
+pub use ntp::{LeapIndicator, NtpDate, NtpTimestamp};
+pub mod ptp;
+pub use ptp::PtpTimestamp;
+pub mod day_of_year;
+pub use day_of_year::{DayOfYearProfile, DayOfYearStyle};
+pub mod ccsds;
+pub use ccsds::{CcsdsError, CcsdsLevel, CcsdsPField, CdsCodec, CdsSubMillis, CucCodec};
+pub mod spice;
+pub use spice::{SpiceScale, SpiceTime, TimeParseError, TimeParseErrorKind};
+pub mod fits;
+pub use fits::{FitsTimeError, FitsTimeHeader, FitsTimeSys};
+pub mod rinex;
+pub use rinex::{GnssEpochLayout, GnssTimeSystem};
+pub mod timecode;
+pub use timecode::{
+    Dcf77Frame, IrigBControl, IrigBFrame, MsfFrame, PulseSymbol, TimeCodeError, WwvbDst, WwvbFrame,
+};
+pub mod pattern;
+pub use pattern::TimePattern;
+pub mod zone;
+pub use zone::{LocalDateTime, LocalTimePolicy, LocalTimeType, TimeZone, ZoneError, ZonedDateTime};
+pub mod civil;
+pub use civil::CivilDateTime;
+pub mod calendar;
+pub use calendar::{Calendar, CalendarDate};
 
 /// Julian day instant on scale `S` (`JD` tag).
 pub type JulianDate<S> = crate::model::time::Time<S, JD>;
@@ -53,6 +105,8 @@ pub type ModifiedJulianDate<S> = crate::model::time::Time<S, MJD>;
 pub type J2000Seconds<S> = crate::model::time::Time<S, J2000s>;
 /// POSIX / Unix seconds on the UTC axis.
 pub type UnixTime = crate::model::time::Time<crate::model::scale::UTC, Unix>;
+/// `right/` (leap-second-counting) POSIX seconds on the UTC axis.
+pub type UnixRightTime = crate::model::time::Time<crate::model::scale::UTC, UnixRight>;
 /// GPS seconds on the TAI axis.
 pub type GpsTime = crate::model::time::Time<crate::model::scale::TAI, GPS>;
 
@@ -77,6 +131,13 @@ impl From<UnixTime> for crate::Time<crate::model::scale::UTC> {
     }
 }
 
+impl From<UnixRightTime> for crate::Time<crate::model::scale::UTC> {
+    #[inline]
+    fn from(value: UnixRightTime) -> Self {
+        value.to_j2000s()
+    }
+}
+
 impl From<GpsTime> for crate::Time<crate::model::scale::TAI> {
     #[inline]
     fn from(value: GpsTime) -> Self {
@@ -195,6 +256,34 @@ mod tests {
         assert!((back.raw() - gps.raw()).abs() < Second::new(1e-6));
     }
 
+    #[test]
+    fn unix_right_round_trips_through_leap_second() {
+        let leap = crate::model::time::Time::<UTC>::from_mjd_sod(
+            MjdSod::new(
+                qtty::i64::Day::new(57_753),
+                crate::ExactDuration::from_nanos(86_400_500_000_000),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        // 2016-12-31T23:59:60.5 UTC: POSIX cannot represent it, right/ can.
+        assert_eq!(
+            leap.try_to::<Unix>(),
+            Err(crate::ConversionError::InvalidLeapSecond)
+        );
+        let right: UnixRightTime = leap.to::<UnixRight>();
+        assert_eq!(right.raw(), Second::new(1_483_228_826.5));
+        let back = UnixRightTime::new(right.raw().value());
+        assert_eq!(
+            crate::model::time::Time::<UTC>::from(back).to_exact_j2000(),
+            leap.to_exact_j2000()
+        );
+        assert_eq!(
+            back.to::<TAI>().raw_seconds_pair(),
+            leap.to::<TAI>().raw_seconds_pair()
+        );
+    }
+
     #[test]
     fn from_encoded_time_into_time() {
         let jd = JulianDate::<TT>::new(2_451_545.0);
@@ -318,9 +407,11 @@ mod tests {
         assert_eq!(MJD::NAME, "MJD");
         assert_eq!(J2000s::NAME, "J2000s");
         assert_eq!(Unix::NAME, "Unix");
+        assert_eq!(UnixRight::NAME, "UnixRight");
         assert_eq!(GPS::NAME, "GPS");
     }
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn chrono_helpers_with_explicit_context_cover_tt_encoded_formats() {
         let ctx = TimeContext::new().allow_pre_definition_utc();
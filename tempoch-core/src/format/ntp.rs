@@ -0,0 +1,460 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! NTP timestamp and date formats (RFC 5905 §6).
+//!
+//! NTP counts UTC seconds since the prime epoch 1900-01-01T00:00:00 UTC
+//! without counting leap seconds, like POSIX. Two wire layouts exist:
+//!
+//! - [`NtpTimestamp`] — 64 bits: 32-bit seconds of the current era and a
+//!   32-bit binary fraction (2⁻³² s ≈ 233 ps). The era number is implicit and
+//!   must be recovered from context with [`NtpTimestamp::resolve_near`] or
+//!   [`NtpTimestamp::in_era`].
+//! - [`NtpDate`] — 128 bits: signed 32-bit era, 32-bit era offset, and a
+//!   64-bit fraction. It covers the whole UTC table without ambiguity.
+//!
+//! Leap seconds are signalled with the [`LeapIndicator`] header field. When
+//! encoding, the indicator is [`LeapIndicator::InsertSecond`] throughout a
+//! UTC day that ends in a positive leap second (per the active UTC-TAI
+//! table). The second `23:59:60` has no count of its own: it encodes as the
+//! following `00:00:00` with the indicator still set, and decoding that
+//! count with [`LeapIndicator::InsertSecond`] maps it back into the leap
+//! second. Counts decoded with any other indicator are ordinary labels.
+//!
+//! Fractions round-trip instants built from nanosecond data exactly; decoded
+//! instants are rounded to the nearest nanosecond.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{LeapIndicator, NtpDate, Time, UTC};
+//!
+//! let date = NtpDate::new(0, 3_912_710_400, 0); // 2023-12-28T00:00:00 UTC
+//! let t = Time::<UTC>::from_ntp_date(date, LeapIndicator::NoWarning).unwrap();
+//! assert_eq!(t.to_ntp_date().unwrap(), (date, LeapIndicator::NoWarning));
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{TAI, UTC};
+use crate::model::time::Time;
+
+use super::mjd_sod::MjdSod;
+
+/// MJD of the NTP prime epoch, 1900-01-01.
+const NTP_EPOCH_MJD: i64 = 15_020;
+const SECONDS_PER_DAY: i64 = 86_400;
+const NANOS_PER_DAY: i128 = SECONDS_PER_DAY as i128 * NANOS_PER_SECOND;
+const ERA_SECONDS: i64 = 1 << 32;
+
+/// NTP leap-indicator header field (2 bits).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LeapIndicator {
+    /// No leap second pending.
+    NoWarning,
+    /// The last minute of the day has 61 seconds.
+    InsertSecond,
+    /// The last minute of the day has 59 seconds.
+    DeleteSecond,
+    /// Clock not synchronised (alarm condition).
+    Unsynchronized,
+}
+
+impl LeapIndicator {
+    /// Decode the 2-bit field; higher bits are ignored.
+    pub const fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0 => Self::NoWarning,
+            1 => Self::InsertSecond,
+            2 => Self::DeleteSecond,
+            _ => Self::Unsynchronized,
+        }
+    }
+
+    /// 2-bit wire value.
+    pub const fn bits(self) -> u8 {
+        match self {
+            Self::NoWarning => 0,
+            Self::InsertSecond => 1,
+            Self::DeleteSecond => 2,
+            Self::Unsynchronized => 3,
+        }
+    }
+}
+
+/// 64-bit NTP timestamp: seconds within an era and a 2⁻³² s fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpTimestamp {
+    /// Seconds since the start of the (implicit) era.
+    pub seconds: u32,
+    /// Fraction of a second in units of 2⁻³² s.
+    pub fraction: u32,
+}
+
+impl NtpTimestamp {
+    /// Construct from the two wire fields.
+    pub const fn new(seconds: u32, fraction: u32) -> Self {
+        Self { seconds, fraction }
+    }
+
+    /// Decode the 64-bit wire value (seconds in the high word).
+    pub const fn from_bits(bits: u64) -> Self {
+        Self::new((bits >> 32) as u32, bits as u32)
+    }
+
+    /// 64-bit wire value (seconds in the high word).
+    pub const fn to_bits(self) -> u64 {
+        ((self.seconds as u64) << 32) | self.fraction as u64
+    }
+
+    /// Decode the big-endian wire bytes.
+    pub const fn from_be_bytes(bytes: [u8; 8]) -> Self {
+        Self::from_bits(u64::from_be_bytes(bytes))
+    }
+
+    /// Big-endian wire bytes.
+    pub const fn to_be_bytes(self) -> [u8; 8] {
+        self.to_bits().to_be_bytes()
+    }
+
+    /// Place this timestamp in an explicit era.
+    pub const fn in_era(self, era: i32) -> NtpDate {
+        NtpDate::new(era, self.seconds, (self.fraction as u64) << 32)
+    }
+
+    /// Place this timestamp in the era that puts it within ±2³¹ s
+    /// (≈ 68 years) of `pivot`, the RFC 5905 era-resolution rule.
+    ///
+    /// Fails with [`ConversionError::OutOfRange`] when that era does not fit
+    /// in an `i32` (only next to the first or last representable era).
+    pub fn resolve_near(self, pivot: NtpDate) -> Result<NtpDate, ConversionError> {
+        let delta = self.seconds.wrapping_sub(pivot.era_offset) as i32;
+        let total = pivot
+            .total_seconds()
+            .checked_add(delta as i64)
+            .ok_or(ConversionError::OutOfRange)?;
+        NtpDate::from_total_seconds(total, (self.fraction as u64) << 32)
+    }
+}
+
+/// 128-bit NTP date: era number, era offset, and a 2⁻⁶⁴ s fraction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NtpDate {
+    /// Era number (era 0 starts at 1900-01-01, era 1 in 2036).
+    pub era: i32,
+    /// Seconds since the start of the era.
+    pub era_offset: u32,
+    /// Fraction of a second in units of 2⁻⁶⁴ s.
+    pub fraction: u64,
+}
+
+impl NtpDate {
+    /// Construct from the three wire fields.
+    pub const fn new(era: i32, era_offset: u32, fraction: u64) -> Self {
+        Self {
+            era,
+            era_offset,
+            fraction,
+        }
+    }
+
+    /// Decode the big-endian 128-bit wire layout.
+    pub fn from_be_bytes(bytes: [u8; 16]) -> Self {
+        let word = |at: usize| {
+            u32::from_be_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+        };
+        Self::new(
+            word(0) as i32,
+            word(4),
+            ((word(8) as u64) << 32) | word(12) as u64,
+        )
+    }
+
+    /// Big-endian 128-bit wire layout.
+    pub fn to_be_bytes(self) -> [u8; 16] {
+        let mut out = [0; 16];
+        out[..4].copy_from_slice(&self.era.to_be_bytes());
+        out[4..8].copy_from_slice(&self.era_offset.to_be_bytes());
+        out[8..].copy_from_slice(&self.fraction.to_be_bytes());
+        out
+    }
+
+    /// Whole seconds since the prime epoch (negative before 1900).
+    pub const fn total_seconds(self) -> i64 {
+        self.era as i64 * ERA_SECONDS + self.era_offset as i64
+    }
+
+    /// 64-bit timestamp of this date (era dropped, fraction truncated to
+    /// 32 bits).
+    pub const fn timestamp(self) -> NtpTimestamp {
+        NtpTimestamp::new(self.era_offset, (self.fraction >> 32) as u32)
+    }
+
+    fn from_total_seconds(total: i64, fraction: u64) -> Result<Self, ConversionError> {
+        let era = i32::try_from(total.div_euclid(ERA_SECONDS))
+            .map_err(|_| ConversionError::OutOfRange)?;
+        Ok(Self::new(
+            era,
+            total.rem_euclid(ERA_SECONDS) as u32,
+            fraction,
+        ))
+    }
+}
+
+/// Nearest 2⁻⁶⁴ s fraction to `nanos` (`< 10⁹`).
+fn fraction_from_nanos(nanos: i128) -> u64 {
+    let scaled = ((nanos as u128) << 64) + NANOS_PER_SECOND as u128 / 2;
+    (scaled / NANOS_PER_SECOND as u128) as u64
+}
+
+/// Nearest nanosecond to a 2⁻⁶⁴ s fraction (may equal 10⁹).
+fn nanos_from_fraction(fraction: u64) -> i128 {
+    ((fraction as u128 * NANOS_PER_SECOND as u128 + (1 << 63)) >> 64) as i128
+}
+
+/// Leap indicator for UTC day `mjd` from the active UTC-TAI table.
+pub(crate) fn day_leap_indicator(
+    mjd: i64,
+    ctx: &TimeContext,
+) -> Result<LeapIndicator, ConversionError> {
+    let probe = |nanos: i128| {
+        let label = MjdSod::new(qtty::i64::Day::new(mjd), ExactDuration::from_nanos(nanos))?;
+        Time::<UTC>::from_mjd_sod_with(label, ctx)
+    };
+    match probe(NANOS_PER_DAY + NANOS_PER_SECOND - 1) {
+        Ok(_) => return Ok(LeapIndicator::InsertSecond),
+        Err(ConversionError::InvalidLeapSecond) => {}
+        Err(err) => return Err(err),
+    }
+    match probe(NANOS_PER_DAY - NANOS_PER_SECOND) {
+        Ok(_) => Ok(LeapIndicator::NoWarning),
+        Err(ConversionError::OutOfRange) => Ok(LeapIndicator::DeleteSecond),
+        Err(err) => Err(err),
+    }
+}
+
+impl Time<UTC> {
+    /// Encode as a 128-bit NTP date plus the leap indicator for the current
+    /// UTC day.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    pub fn to_ntp_date(self) -> Result<(NtpDate, LeapIndicator), ConversionError> {
+        self.to_ntp_date_with(&TimeContext::new())
+    }
+
+    /// Like [`Self::to_ntp_date`], using `ctx` for the UTC-TAI table.
+    pub fn to_ntp_date_with(
+        self,
+        ctx: &TimeContext,
+    ) -> Result<(NtpDate, LeapIndicator), ConversionError> {
+        let label = self.to_mjd_sod_with(ctx)?;
+        let leap = day_leap_indicator(label.mjd.value(), ctx)?;
+        // The leap second shares its count with the following midnight.
+        let nominal =
+            label.to_nominal_duration().as_nanos_i128() - NTP_EPOCH_MJD as i128 * NANOS_PER_DAY;
+        let seconds = nominal.div_euclid(NANOS_PER_SECOND);
+        let fraction = fraction_from_nanos(nominal.rem_euclid(NANOS_PER_SECOND));
+        let total = i64::try_from(seconds).map_err(|_| ConversionError::OutOfRange)?;
+        Ok((NtpDate::from_total_seconds(total, fraction)?, leap))
+    }
+
+    /// Decode a 128-bit NTP date.
+    ///
+    /// With [`LeapIndicator::InsertSecond`], a count in the first second of a
+    /// day that follows a positive leap second decodes into `23:59:60` of the
+    /// previous day.
+    pub fn from_ntp_date(date: NtpDate, leap: LeapIndicator) -> Result<Self, ConversionError> {
+        Self::from_ntp_date_with(date, leap, &TimeContext::new())
+    }
+
+    /// Like [`Self::from_ntp_date`], using `ctx` for the UTC-TAI table.
+    pub fn from_ntp_date_with(
+        date: NtpDate,
+        leap: LeapIndicator,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        let nominal = (date.total_seconds() as i128
+            + NTP_EPOCH_MJD as i128 * SECONDS_PER_DAY as i128)
+            * NANOS_PER_SECOND
+            + nanos_from_fraction(date.fraction);
+        let mut mjd = nominal.div_euclid(NANOS_PER_DAY) as i64;
+        let mut nanos_of_day = nominal.rem_euclid(NANOS_PER_DAY);
+        if leap == LeapIndicator::InsertSecond
+            && nanos_of_day < NANOS_PER_SECOND
+            && day_leap_indicator(mjd - 1, ctx)? == LeapIndicator::InsertSecond
+        {
+            mjd -= 1;
+            nanos_of_day += NANOS_PER_DAY;
+        }
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(nanos_of_day),
+        )?;
+        Self::from_mjd_sod_with(label, ctx)
+    }
+}
+
+impl Time<TAI> {
+    /// Encode as a 128-bit NTP date. See [`Time::<UTC>::to_ntp_date`].
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    pub fn to_ntp_date(self) -> Result<(NtpDate, LeapIndicator), ConversionError> {
+        self.to_ntp_date_with(&TimeContext::new())
+    }
+
+    /// Like [`Self::to_ntp_date`], using `ctx` for the UTC-TAI table.
+    pub fn to_ntp_date_with(
+        self,
+        ctx: &TimeContext,
+    ) -> Result<(NtpDate, LeapIndicator), ConversionError> {
+        self.to::<UTC>().to_ntp_date_with(ctx)
+    }
+
+    /// Decode a 128-bit NTP date onto the TAI axis.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    pub fn from_ntp_date(date: NtpDate, leap: LeapIndicator) -> Result<Self, ConversionError> {
+        Self::from_ntp_date_with(date, leap, &TimeContext::new())
+    }
+
+    /// Like [`Self::from_ntp_date`], using `ctx` for the UTC-TAI table.
+    pub fn from_ntp_date_with(
+        date: NtpDate,
+        leap: LeapIndicator,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        Ok(Time::<UTC>::from_ntp_date_with(date, leap, ctx)?.to::<TAI>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(mjd: i64, second_of_day: i128, nanos: i128) -> Time<UTC> {
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(second_of_day * NANOS_PER_SECOND + nanos),
+        )
+        .unwrap();
+        Time::<UTC>::from_mjd_sod(label).unwrap()
+    }
+
+    #[test]
+    fn known_epochs_encode_to_reference_counts() {
+        // 1970-01-01: 2 208 988 800 s after the prime epoch.
+        let (unix_epoch, _) = utc(40_587, 0, 0).to_ntp_date().unwrap();
+        assert_eq!(unix_epoch, NtpDate::new(0, 2_208_988_800, 0));
+        // 2036-02-07T06:28:16 UTC starts era 1.
+        let (era1, _) = utc(64_730, 6 * 3_600 + 28 * 60 + 16, 0)
+            .to_ntp_date()
+            .unwrap();
+        assert_eq!(era1, NtpDate::new(1, 0, 0));
+    }
+
+    #[test]
+    fn fractions_round_trip_nanoseconds() {
+        for nanos in [0, 1, 500_000_000, 999_999_999] {
+            let t = utc(60_000, 12_345, nanos);
+            let (date, leap) = t.to_ntp_date().unwrap();
+            let back = Time::<UTC>::from_ntp_date(date, leap).unwrap();
+            assert_eq!(back.to_exact_j2000(), t.to_exact_j2000());
+            let short = date.timestamp().in_era(date.era);
+            let back = Time::<UTC>::from_ntp_date(short, leap).unwrap();
+            assert_eq!(back.to_exact_j2000(), t.to_exact_j2000());
+        }
+        assert_eq!(
+            utc(60_000, 0, 500_000_000)
+                .to_ntp_date()
+                .unwrap()
+                .0
+                .timestamp()
+                .fraction,
+            1 << 31
+        );
+    }
+
+    #[test]
+    fn leap_second_uses_the_leap_indicator() {
+        // 2016-12-31 ends in a leap second.
+        let before = utc(57_753, 43_200, 0).to_ntp_date().unwrap();
+        let leap = utc(57_753, 86_400, 250_000_000).to_ntp_date().unwrap();
+        let after = utc(57_754, 0, 250_000_000).to_ntp_date().unwrap();
+        assert_eq!(before.1, LeapIndicator::InsertSecond);
+        assert_eq!(leap.1, LeapIndicator::InsertSecond);
+        assert_eq!(after.1, LeapIndicator::NoWarning);
+        // Same count, distinguished only by the indicator.
+        assert_eq!(leap.0, after.0);
+
+        let leap_back = Time::<UTC>::from_ntp_date(leap.0, leap.1).unwrap();
+        assert!(leap_back.is_leap_second());
+        assert_eq!(
+            leap_back.to_exact_j2000(),
+            utc(57_753, 86_400, 250_000_000).to_exact_j2000()
+        );
+        let after_back = Time::<UTC>::from_ntp_date(after.0, after.1).unwrap();
+        assert_eq!(
+            after_back.to_exact_j2000(),
+            utc(57_754, 0, 250_000_000).to_exact_j2000()
+        );
+        assert_eq!(
+            utc(57_754, 0, 0).to_ntp_date().unwrap().1,
+            LeapIndicator::NoWarning
+        );
+    }
+
+    #[test]
+    fn timestamps_resolve_to_the_nearest_era() {
+        let pivot = NtpDate::new(0, u32::MAX - 10, 0);
+        let wrapped = NtpTimestamp::new(5, 0).resolve_near(pivot);
+        assert_eq!(wrapped, Ok(NtpDate::new(1, 5, 0)));
+        let back = NtpTimestamp::new(u32::MAX - 20, 0).resolve_near(NtpDate::new(1, 5, 0));
+        assert_eq!(back, Ok(NtpDate::new(0, u32::MAX - 20, 0)));
+        assert_eq!(
+            NtpTimestamp::new(0, 0).resolve_near(NtpDate::new(i32::MAX, u32::MAX, 0)),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            NtpTimestamp::new(u32::MAX, 0).resolve_near(NtpDate::new(i32::MIN, 0, 0)),
+            Err(ConversionError::OutOfRange)
+        );
+        assert_eq!(
+            NtpTimestamp::new(7, 9).in_era(-1),
+            NtpDate::new(-1, 7, 9 << 32)
+        );
+    }
+
+    #[test]
+    fn wire_layouts_round_trip() {
+        let ts = NtpTimestamp::new(0xe8f1_2345, 0x8000_0001);
+        assert_eq!(NtpTimestamp::from_be_bytes(ts.to_be_bytes()), ts);
+        assert_eq!(ts.to_bits(), 0xe8f1_2345_8000_0001);
+        let date = NtpDate::new(-2, 17, 0x0123_4567_89ab_cdef);
+        let bytes = date.to_be_bytes();
+        assert_eq!(&bytes[..4], &[0xff, 0xff, 0xff, 0xfe]);
+        assert_eq!(NtpDate::from_be_bytes(bytes), date);
+        for bits in 0..4 {
+            assert_eq!(LeapIndicator::from_bits(bits).bits(), bits);
+        }
+    }
+
+    #[test]
+    fn tai_wrappers_share_the_utc_encoding() {
+        let t = utc(59_000, 1, 2);
+        assert_eq!(t.to::<TAI>().to_ntp_date(), t.to_ntp_date());
+        let ctx = TimeContext::new();
+        assert_eq!(t.to::<TAI>().to_ntp_date_with(&ctx), t.to_ntp_date());
+        let (date, leap) = t.to_ntp_date().unwrap();
+        assert_eq!(
+            Time::<TAI>::from_ntp_date(date, leap)
+                .unwrap()
+                .to_exact_j2000(),
+            t.to_exact_j2000()
+        );
+        assert_eq!(
+            Time::<TAI>::from_ntp_date_with(date, leap, &ctx),
+            Time::<TAI>::from_ntp_date(date, leap)
+        );
+    }
+}
@@ -0,0 +1,956 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! strftime-style patterns for calendar labels on any [`CalendarScale`].
+//!
+//! A [`TimePattern`] is compiled once and then formats and parses `Time<S>`
+//! labels. Labels come from [`MjdSod`], not `chrono::format`, so UTC leap
+//! seconds render as second `60` and nanoseconds round-trip exactly.
+//!
+//! | Directive            | Field                                        | Example          |
+//! |----------------------|----------------------------------------------|------------------|
+//! | `%Y` / `%y`          | year, 4 digits / 2 digits (parse: 69–99 → 19xx) | `2024` / `24` |
+//! | `%m` `%d` `%j`       | month, day of month, day of year             | `01` `15` `015`  |
+//! | `%b` / `%B`          | month name, abbreviated / full               | `Jan` / `January` |
+//! | `%a` / `%A` / `%u`   | weekday name / ISO weekday (Monday = 1)      | `Mon` / `Monday` / `1` |
+//! | `%G` `%V`            | ISO 8601 week-numbering year and week        | `2024` `03`      |
+//! | `%H` `%M` `%S`       | hour, minute, second (`60` in a leap second) | `23` `59` `60`   |
+//! | `%f` / `%3f`         | fraction of second, 9 / the given 1–9 digits | `123456789` / `123` |
+//! | `%Z`                 | scale name                                   | `TT`             |
+//! | `%{jd}` / `%9{jd}`   | Julian Date, 6 / the given 0–15 decimals     | `2460325.000000` |
+//! | `%{mjd}` / `%9{mjd}` | Modified Julian Date                         | `60324.500000`   |
+//! | `%{week}`            | GNSS week                                    | `2297`           |
+//! | `%{tow}` / `%3{tow}` | GNSS time of week, 0 / the given 0–9 decimals | `129600`        |
+//! | `%%`                 | literal `%`                                  | `%`              |
+//!
+//! Clock fields are rounded together at the widest fraction directive,
+//! using [`FormatPrecision::Truncate`] by default (as `strftime` does);
+//! JD, MJD, and time of week round their own decimals with the same policy.
+//! JD and MJD divide by the labelled day's length, so UTC leap-second days
+//! span 86 401 s as in SOFA's quasi-JD convention. GNSS weeks count from
+//! the constellation epoch of `GPST`, `GST`, `BDT`, or `QZSST`; other scales
+//! have no GNSS weeks, so `%{week}` and `%{tow}` fail on them.
+//!
+//! Parsing resolves the instant from `%{jd}`/`%{mjd}`, else `%{week}` (with
+//! `%{tow}`), else the calendar date (`%Y`/`%y` with `%m %d` or `%j`, or
+//! `%G %V %u`) plus the clock. Every other field present must agree with
+//! the resolved instant.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{Time, TimePattern, UTC};
+//!
+//! let pattern = TimePattern::new("%a %d %b %Y %H:%M:%S.%3f %Z (day %j)").unwrap();
+//! let leap = Time::<UTC>::parse_ccsds_ascii("2016-12-31T23:59:60.5Z").unwrap();
+//! let s = pattern.format(leap);
+//! assert_eq!(s, "Sat 31 Dec 2016 23:59:60.500 UTC (day 366)");
+//! assert_eq!(pattern.parse::<UTC>(&s).unwrap(), leap);
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+
+use super::iso::{rounded_label, time_from_clock};
+use super::mjd_sod::J2000_NANOS_SINCE_MJD_ZERO;
+use super::spice::{TimeParseError, TimeParseErrorKind};
+use super::{CalendarScale, FormatOptions, FormatPrecision, MjdSod};
+
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+const NANOS_PER_WEEK: i128 = 7 * NANOS_PER_DAY;
+/// Integer part of `JD − MJD` (the remaining half day is added separately).
+const JD_MINUS_MJD_DAYS: i128 = 2_400_000;
+const MAX_DAY_DECIMALS: usize = 15;
+/// Longest integer field accepted on parse (keeps arithmetic in `i128`).
+const MAX_INTEGER_DIGITS: usize = 18;
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Item {
+    Literal(String),
+    Year,
+    ShortYear,
+    Month,
+    Day,
+    DayOfYear,
+    MonthName {
+        full: bool,
+    },
+    WeekdayName {
+        full: bool,
+    },
+    IsoWeekday,
+    IsoYear,
+    IsoWeek,
+    Hour,
+    Minute,
+    Second,
+    /// Fraction digits; `None` writes 9 and reads 1–9.
+    Fraction(Option<usize>),
+    Scale,
+    Jd(usize),
+    Mjd(usize),
+    GnssWeek,
+    TimeOfWeek(usize),
+}
+
+/// Compiled strftime-style pattern for labels on any [`CalendarScale`].
+///
+/// See the [module documentation](self) for the directives.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimePattern {
+    items: Vec<Item>,
+    precision: FormatPrecision,
+}
+
+impl TimePattern {
+    /// Compile `pattern`.
+    ///
+    /// Errors point at the offending `%` in `pattern`: an unknown directive
+    /// is [`TimeParseErrorKind::UnknownWord`], a width on a directive that
+    /// takes none is [`TimeParseErrorKind::UnexpectedToken`], an
+    /// out-of-range width is [`TimeParseErrorKind::InvalidField`], and a
+    /// trailing `%` or unclosed `%{` is [`TimeParseErrorKind::MissingField`].
+    pub fn new(pattern: &str) -> Result<Self, TimeParseError> {
+        use TimeParseErrorKind::{InvalidField, MissingField, UnexpectedToken, UnknownWord};
+
+        let mut items = Vec::new();
+        let mut literal = String::new();
+        let mut rest = pattern;
+        while let Some(at) = rest.find('%') {
+            literal.push_str(&rest[..at]);
+            let start = pattern.len() - rest.len() + at;
+            let spec = &rest[at + 1..];
+            let width_len = spec.bytes().take_while(u8::is_ascii_digit).count();
+            let width = match width_len {
+                0 => None,
+                _ => Some(
+                    spec[..width_len]
+                        .parse::<usize>()
+                        .map_err(|_| TimeParseError::new(start, InvalidField))?,
+                ),
+            };
+            let spec = &spec[width_len..];
+            let directive = spec
+                .chars()
+                .next()
+                .ok_or(TimeParseError::new(pattern.len(), MissingField))?;
+            let (item, len) = match directive {
+                '{' => {
+                    let close = spec
+                        .find('}')
+                        .ok_or(TimeParseError::new(pattern.len(), MissingField))?;
+                    let item = match &spec[1..close] {
+                        "jd" => Item::Jd(width.unwrap_or(6)),
+                        "mjd" => Item::Mjd(width.unwrap_or(6)),
+                        "week" => Item::GnssWeek,
+                        "tow" => Item::TimeOfWeek(width.unwrap_or(0)),
+                        _ => return Err(TimeParseError::new(start, UnknownWord)),
+                    };
+                    (Some(item), close + 1)
+                }
+                '%' => (None, 1),
+                _ => {
+                    let item = match directive {
+                        'Y' => Item::Year,
+                        'y' => Item::ShortYear,
+                        'm' => Item::Month,
+                        'd' => Item::Day,
+                        'j' => Item::DayOfYear,
+                        'b' => Item::MonthName { full: false },
+                        'B' => Item::MonthName { full: true },
+                        'a' => Item::WeekdayName { full: false },
+                        'A' => Item::WeekdayName { full: true },
+                        'u' => Item::IsoWeekday,
+                        'G' => Item::IsoYear,
+                        'V' => Item::IsoWeek,
+                        'H' => Item::Hour,
+                        'M' => Item::Minute,
+                        'S' => Item::Second,
+                        'f' => Item::Fraction(width),
+                        'Z' => Item::Scale,
+                        _ => return Err(TimeParseError::new(start, UnknownWord)),
+                    };
+                    (Some(item), directive.len_utf8())
+                }
+            };
+            let widths = match item {
+                Some(Item::Fraction(_)) => 1..=9,
+                Some(Item::TimeOfWeek(_)) => 0..=9,
+                Some(Item::Jd(_) | Item::Mjd(_)) => 0..=MAX_DAY_DECIMALS,
+                _ if width.is_some() => return Err(TimeParseError::new(start, UnexpectedToken)),
+                _ => 0..=0,
+            };
+            if width.is_some_and(|width| !widths.contains(&width)) {
+                return Err(TimeParseError::new(start, InvalidField));
+            }
+            match item {
+                Some(item) => {
+                    if !literal.is_empty() {
+                        items.push(Item::Literal(core::mem::take(&mut literal)));
+                    }
+                    items.push(item);
+                }
+                None => literal.push('%'),
+            }
+            rest = &spec[len..];
+        }
+        literal.push_str(rest);
+        if !literal.is_empty() {
+            items.push(Item::Literal(literal));
+        }
+        Ok(Self {
+            items,
+            precision: FormatPrecision::Truncate,
+        })
+    }
+
+    /// Use `precision` when rounding seconds, JD/MJD, and time of week.
+    #[inline]
+    pub fn with_precision(mut self, precision: FormatPrecision) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Rounding policy used when formatting.
+    #[inline]
+    pub fn precision(&self) -> FormatPrecision {
+        self.precision
+    }
+
+    /// Format `time` with this pattern.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn format<S: CalendarScale>(&self, time: Time<S>) -> String {
+        self.format_with(time, &TimeContext::new())
+    }
+
+    /// Like [`format`](Self::format), with an explicit [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the instant cannot be labelled; use
+    /// [`try_format_with`](Self::try_format_with) to handle that case
+    /// explicitly.
+    pub fn format_with<S: CalendarScale>(&self, time: Time<S>, ctx: &TimeContext) -> String {
+        match self.try_format_with(time, ctx) {
+            Ok(s) => s,
+            Err(_) => "<invalid>".to_string(),
+        }
+    }
+
+    /// Fallible variant of [`format_with`](Self::format_with).
+    ///
+    /// Returns [`ConversionError::OutOfRange`] if `%Y` or `%G` falls outside
+    /// `0000..=9999`, or for `%{week}`/`%{tow}` on a scale without GNSS weeks.
+    pub fn try_format_with<S: CalendarScale>(
+        &self,
+        time: Time<S>,
+        ctx: &TimeContext,
+    ) -> Result<String, ConversionError> {
+        let digits = self
+            .items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Fraction(width) => Some(width.unwrap_or(9)),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let opts = FormatOptions {
+            subsecond_digits: digits as u8,
+            precision: self.precision,
+            include_zulu: false,
+            utc_offset: None,
+        };
+        let label = rounded_label(time, opts, ctx)?;
+        let (year, month, day) = encoding::gregorian_from_mjd(label.mjd);
+        let (_, day_of_year) = encoding::year_day_from_mjd(label.mjd);
+        let weekday = encoding::iso_weekday(label.mjd);
+        let (iso_year, iso_week) = encoding::iso_week(label.mjd);
+        let exact = time.to_mjd_sod_with(ctx)?;
+        let (mjd, sod) = (exact.mjd.value(), exact.second_of_day.as_nanos_i128());
+        let tow_digits = self
+            .items
+            .iter()
+            .find_map(|item| match item {
+                Item::TimeOfWeek(width) => Some(*width),
+                _ => None,
+            })
+            .unwrap_or(9);
+        let epoch = gnss_epoch::<S>();
+        let week = epoch.map(|epoch| week_and_tow(epoch, mjd, sod, tow_digits, self.precision).0);
+        let [hour, minute, second] = label.clock;
+
+        let mut out = String::new();
+        for item in &self.items {
+            match *item {
+                Item::Literal(ref text) => out.push_str(text),
+                Item::Year => out.push_str(&four_digit_year(year)?),
+                Item::ShortYear => out.push_str(&format!("{:02}", year.rem_euclid(100))),
+                Item::Month => out.push_str(&format!("{month:02}")),
+                Item::Day => out.push_str(&format!("{day:02}")),
+                Item::DayOfYear => out.push_str(&format!("{day_of_year:03}")),
+                Item::MonthName { full } => {
+                    out.push_str(name(MONTH_NAMES[month as usize - 1], full))
+                }
+                Item::WeekdayName { full } => {
+                    out.push_str(name(WEEKDAY_NAMES[weekday as usize - 1], full))
+                }
+                Item::IsoWeekday => out.push_str(&weekday.to_string()),
+                Item::IsoYear => out.push_str(&four_digit_year(iso_year)?),
+                Item::IsoWeek => out.push_str(&format!("{iso_week:02}")),
+                Item::Hour => out.push_str(&format!("{hour:02}")),
+                Item::Minute => out.push_str(&format!("{minute:02}")),
+                Item::Second => out.push_str(&format!("{second:02}")),
+                Item::Fraction(width) => {
+                    let width = width.unwrap_or(9);
+                    let frac = label.frac / 10_u32.pow((digits - width) as u32);
+                    out.push_str(&format!("{frac:0width$}"));
+                }
+                Item::Scale => out.push_str(S::NAME),
+                Item::Jd(decimals) => {
+                    let length = day_nanos::<S>(mjd, ctx)?;
+                    let whole = (mjd as i128 + JD_MINUS_MJD_DAYS) * 10_i128.pow(decimals as u32);
+                    let frac = scaled(2 * sod + length, 2 * length, decimals, self.precision);
+                    out.push_str(&fixed(whole + frac, decimals));
+                }
+                Item::Mjd(decimals) => {
+                    let length = day_nanos::<S>(mjd, ctx)?;
+                    let whole = mjd as i128 * 10_i128.pow(decimals as u32);
+                    let frac = scaled(sod, length, decimals, self.precision);
+                    out.push_str(&fixed(whole + frac, decimals));
+                }
+                Item::GnssWeek => {
+                    out.push_str(&week.ok_or(ConversionError::OutOfRange)?.to_string())
+                }
+                Item::TimeOfWeek(decimals) => {
+                    let epoch = epoch.ok_or(ConversionError::OutOfRange)?;
+                    let (_, tow) = week_and_tow(epoch, mjd, sod, decimals, self.precision);
+                    out.push_str(&fixed(tow, decimals));
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Parse `s` as a label on `S` written with this pattern.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn parse<S: CalendarScale>(&self, s: &str) -> Result<Time<S>, TimeParseError> {
+        self.parse_with(s, &TimeContext::new())
+    }
+
+    /// Like [`parse`](Self::parse), with an explicit [`TimeContext`].
+    ///
+    /// Errors carry the byte offset in `s`. Fields that contradict the
+    /// resolved instant (a wrong weekday, `%j` against `%m %d`, …) are
+    /// [`TimeParseErrorKind::InvalidField`]; a pattern without enough fields
+    /// to fix the day is [`TimeParseErrorKind::MissingField`]. `%{week}` and
+    /// `%{tow}` on a scale without GNSS weeks are
+    /// [`TimeParseErrorKind::InvalidField`].
+    pub fn parse_with<S: CalendarScale>(
+        &self,
+        s: &str,
+        ctx: &TimeContext,
+    ) -> Result<Time<S>, TimeParseError> {
+        let mut cursor = Cursor { input: s, pos: 0 };
+        let mut fields = Fields::default();
+        for item in &self.items {
+            let start = cursor.pos;
+            let at = |value| Some((value, start));
+            match *item {
+                Item::Literal(ref text) => cursor.literal(text)?,
+                Item::Year => fields.year = at(cursor.digits(4, 4)?),
+                Item::ShortYear => fields.short_year = at(cursor.digits(2, 2)?),
+                Item::Month => fields.month = at(cursor.bounded(2, 1..=12)?),
+                Item::Day => fields.day = at(cursor.bounded(2, 1..=31)?),
+                Item::DayOfYear => fields.day_of_year = at(cursor.bounded(3, 1..=366)?),
+                Item::MonthName { .. } => fields.month = at(cursor.name(&MONTH_NAMES)?),
+                Item::WeekdayName { .. } => fields.weekday = at(cursor.name(&WEEKDAY_NAMES)?),
+                Item::IsoWeekday => fields.weekday = at(cursor.bounded(1, 1..=7)?),
+                Item::IsoYear => fields.iso_year = at(cursor.digits(4, 4)?),
+                Item::IsoWeek => fields.iso_week = at(cursor.bounded(2, 1..=53)?),
+                Item::Hour => fields.hour = at(cursor.bounded(2, 0..=23)?),
+                Item::Minute => fields.minute = at(cursor.bounded(2, 0..=59)?),
+                Item::Second => fields.second = at(cursor.bounded(2, 0..=60)?),
+                Item::Fraction(width) => {
+                    let (min, max) = width.map_or((1, 9), |width| (width, width));
+                    let digits = cursor.pos;
+                    let value = cursor.digits(min, max)?;
+                    let len = cursor.pos - digits;
+                    fields.frac_nanos = (value * 10_i128.pow(9 - len as u32)) as u32;
+                }
+                Item::Scale => cursor.literal(S::NAME).map_err(|_| cursor.invalid())?,
+                Item::Jd(_) => {
+                    let value = cursor.decimal(MAX_DAY_DECIMALS)?;
+                    let (day, numer, denom) = value.split();
+                    // MJD = JD − 2 400 000.5.
+                    let (day, numer, denom) = match 2 * numer + denom {
+                        n if n >= 2 * denom => (day - JD_MINUS_MJD_DAYS, n - 2 * denom, 2 * denom),
+                        n => (day - JD_MINUS_MJD_DAYS - 1, n, 2 * denom),
+                    };
+                    fields.day_value = Some((day, numer, denom, start));
+                }
+                Item::Mjd(_) => {
+                    let (day, numer, denom) = cursor.decimal(MAX_DAY_DECIMALS)?.split();
+                    fields.day_value = Some((day, numer, denom, start));
+                }
+                Item::GnssWeek | Item::TimeOfWeek(_) if gnss_epoch::<S>().is_none() => {
+                    return Err(TimeParseError::new(start, TimeParseErrorKind::InvalidField));
+                }
+                Item::GnssWeek => fields.week = at(cursor.digits(1, MAX_INTEGER_DIGITS)?),
+                Item::TimeOfWeek(_) => {
+                    let value = cursor.decimal(9)?;
+                    let nanos = value.whole * NANOS_PER_SECOND
+                        + value.frac * 10_i128.pow(9 - value.frac_digits as u32);
+                    if value.negative || nanos >= NANOS_PER_WEEK {
+                        return Err(TimeParseError::new(start, TimeParseErrorKind::InvalidField));
+                    }
+                    fields.tow = at(nanos);
+                }
+            }
+        }
+        if cursor.pos != s.len() {
+            return Err(cursor.error(TimeParseErrorKind::UnexpectedCharacter));
+        }
+        fields.resolve(s.len(), ctx)
+    }
+}
+
+// ── Formatting helpers ───────────────────────────────────────────────────
+
+fn four_digit_year(year: i64) -> Result<String, ConversionError> {
+    if (0..=9_999).contains(&year) {
+        Ok(format!("{year:04}"))
+    } else {
+        Err(ConversionError::OutOfRange)
+    }
+}
+
+fn name(full: &'static str, full_form: bool) -> &'static str {
+    if full_form {
+        full
+    } else {
+        &full[..3]
+    }
+}
+
+/// `numer / denom` in units of `10^-digits`, rounded per `precision`.
+fn scaled(numer: i128, denom: i128, digits: usize, precision: FormatPrecision) -> i128 {
+    let scaled = numer * 10_i128.pow(digits as u32);
+    let (quotient, remainder) = (scaled.div_euclid(denom), scaled.rem_euclid(denom));
+    let round_up = precision == FormatPrecision::RoundHalfToEven
+        && (2 * remainder > denom || (2 * remainder == denom && quotient % 2 == 1));
+    quotient + round_up as i128
+}
+
+/// `value · 10^-digits` as a decimal string.
+fn fixed(value: i128, digits: usize) -> String {
+    let sign = if value < 0 { "-" } else { "" };
+    let scale = 10_u128.pow(digits as u32);
+    let abs = value.unsigned_abs();
+    if digits == 0 {
+        format!("{sign}{abs}")
+    } else {
+        format!("{sign}{}.{:0digits$}", abs / scale, abs % scale)
+    }
+}
+
+/// Length of the labelled day `mjd` on `S` in nanoseconds: 86 400 s except
+/// on UTC leap-second and pre-1972 days.
+fn day_nanos<S: CalendarScale>(mjd: i64, ctx: &TimeContext) -> Result<i128, ConversionError> {
+    let start = time_from_clock::<S>(mjd, [0, 0, 0], 0, ctx)?.to_exact_j2000()?;
+    let end = time_from_clock::<S>(mjd + 1, [0, 0, 0], 0, ctx)?.to_exact_j2000()?;
+    Ok(end.as_nanos_i128() - start.as_nanos_i128())
+}
+
+/// Start of GNSS week 0 on `S`, as label nanoseconds since MJD 0, or `None`
+/// if `S` has no GNSS weeks.
+fn gnss_epoch<S: CalendarScale>() -> Option<i128> {
+    S::GNSS_WEEK_EPOCH_J2000_SECONDS
+        .map(|seconds| seconds as i128 * NANOS_PER_SECOND + J2000_NANOS_SINCE_MJD_ZERO)
+}
+
+/// GNSS week and time of week (in `10^-digits` s) of a label; a rounding
+/// carry into second 604 800 moves to the next week.
+fn week_and_tow(
+    epoch: i128,
+    mjd: i64,
+    sod: i128,
+    digits: usize,
+    precision: FormatPrecision,
+) -> (i128, i128) {
+    let since_epoch = mjd as i128 * NANOS_PER_DAY + sod - epoch;
+    let week = since_epoch.div_euclid(NANOS_PER_WEEK);
+    let tow = scaled(
+        since_epoch.rem_euclid(NANOS_PER_WEEK),
+        NANOS_PER_SECOND,
+        digits,
+        precision,
+    );
+    if tow == NANOS_PER_WEEK / NANOS_PER_SECOND * 10_i128.pow(digits as u32) {
+        (week + 1, 0)
+    } else {
+        (week, tow)
+    }
+}
+
+// ── Parsing helpers ──────────────────────────────────────────────────────
+
+struct Cursor<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+/// Signed decimal `±whole.frac` read from the input.
+struct Decimal {
+    negative: bool,
+    whole: i128,
+    frac: i128,
+    frac_digits: usize,
+}
+
+impl Decimal {
+    /// `(day, numer, denom)` with `value = day + numer / denom` and
+    /// `0 <= numer < denom`.
+    fn split(&self) -> (i128, i128, i128) {
+        let denom = 10_i128.pow(self.frac_digits as u32);
+        match (self.negative, self.frac) {
+            (false, frac) => (self.whole, frac, denom),
+            (true, 0) => (-self.whole, 0, denom),
+            (true, frac) => (-self.whole - 1, denom - frac, denom),
+        }
+    }
+}
+
+impl Cursor<'_> {
+    fn rest(&self) -> &str {
+        &self.input[self.pos..]
+    }
+
+    fn error(&self, kind: TimeParseErrorKind) -> TimeParseError {
+        TimeParseError::new(self.pos, kind)
+    }
+
+    /// [`TimeParseErrorKind::MissingField`] at the end of the input, else
+    /// [`TimeParseErrorKind::InvalidField`].
+    fn invalid(&self) -> TimeParseError {
+        match self.rest().is_empty() {
+            true => self.error(TimeParseErrorKind::MissingField),
+            false => self.error(TimeParseErrorKind::InvalidField),
+        }
+    }
+
+    fn literal(&mut self, text: &str) -> Result<(), TimeParseError> {
+        if self.rest().starts_with(text) {
+            self.pos += text.len();
+            Ok(())
+        } else if self.rest().is_empty() {
+            Err(self.error(TimeParseErrorKind::MissingField))
+        } else {
+            Err(self.error(TimeParseErrorKind::UnexpectedCharacter))
+        }
+    }
+
+    /// `min..=max` ASCII digits.
+    fn digits(&mut self, min: usize, max: usize) -> Result<i128, TimeParseError> {
+        let len = self
+            .rest()
+            .bytes()
+            .take(max)
+            .take_while(u8::is_ascii_digit)
+            .count();
+        if len < min {
+            return Err(self.invalid());
+        }
+        let value = self.rest()[..len]
+            .parse()
+            .map_err(|_| self.error(TimeParseErrorKind::InvalidField))?;
+        self.pos += len;
+        Ok(value)
+    }
+
+    /// Exactly `width` digits whose value lies in `range`.
+    fn bounded(
+        &mut self,
+        width: usize,
+        range: core::ops::RangeInclusive<i128>,
+    ) -> Result<i128, TimeParseError> {
+        let start = self.pos;
+        let value = self.digits(width, width)?;
+        if !range.contains(&value) {
+            return Err(TimeParseError::new(start, TimeParseErrorKind::InvalidField));
+        }
+        Ok(value)
+    }
+
+    /// Full or three-letter English name (ASCII case-insensitive); returns
+    /// its 1-based index.
+    fn name(&mut self, names: &[&str]) -> Result<i128, TimeParseError> {
+        let rest = self.rest();
+        let matches = |candidate: &str| {
+            rest.get(..candidate.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(candidate))
+        };
+        for (index, full) in names.iter().enumerate() {
+            for candidate in [*full, &full[..3]] {
+                if matches(candidate) {
+                    self.pos += candidate.len();
+                    return Ok(index as i128 + 1);
+                }
+            }
+        }
+        Err(self.invalid())
+    }
+
+    /// `[-]digits[.digits]` with at most `max_frac` fractional digits.
+    fn decimal(&mut self, max_frac: usize) -> Result<Decimal, TimeParseError> {
+        let start = self.pos;
+        let negative = self.rest().starts_with('-');
+        self.pos += negative as usize;
+        let whole = self.digits(1, MAX_INTEGER_DIGITS)?;
+        let (frac, frac_digits) = match self.rest().strip_prefix('.') {
+            Some(digits) => {
+                let len = digits.bytes().take_while(u8::is_ascii_digit).count();
+                if len == 0 || len > max_frac {
+                    return Err(TimeParseError::new(start, TimeParseErrorKind::InvalidField));
+                }
+                self.pos += 1;
+                (self.digits(len, len)?, len)
+            }
+            None => (0, 0),
+        };
+        Ok(Decimal {
+            negative,
+            whole,
+            frac,
+            frac_digits,
+        })
+    }
+}
+
+/// Parsed field values with the input offset they came from.
+#[derive(Default)]
+struct Fields {
+    year: Option<(i128, usize)>,
+    short_year: Option<(i128, usize)>,
+    month: Option<(i128, usize)>,
+    day: Option<(i128, usize)>,
+    day_of_year: Option<(i128, usize)>,
+    weekday: Option<(i128, usize)>,
+    iso_year: Option<(i128, usize)>,
+    iso_week: Option<(i128, usize)>,
+    hour: Option<(i128, usize)>,
+    minute: Option<(i128, usize)>,
+    second: Option<(i128, usize)>,
+    frac_nanos: u32,
+    /// `(mjd, numer, denom, offset)` from `%{jd}` or `%{mjd}`.
+    day_value: Option<(i128, i128, i128, usize)>,
+    week: Option<(i128, usize)>,
+    tow: Option<(i128, usize)>,
+}
+
+impl Fields {
+    fn resolve<S: CalendarScale>(
+        &self,
+        end: usize,
+        ctx: &TimeContext,
+    ) -> Result<Time<S>, TimeParseError> {
+        let invalid = |pos| TimeParseError::new(pos, TimeParseErrorKind::InvalidField);
+        let missing = TimeParseError::new(end, TimeParseErrorKind::MissingField);
+        let conversion = |err| TimeParseError::new(0, TimeParseErrorKind::Conversion(err));
+        let to_i64 = |value: i128, pos| i64::try_from(value).map_err(|_| invalid(pos));
+
+        let time = if let Some((day, numer, denom, pos)) = self.day_value {
+            let mjd = to_i64(day, pos)?;
+            let length = day_nanos::<S>(mjd, ctx).map_err(conversion)?;
+            let sod = (2 * numer * length + denom) / (2 * denom);
+            let (mjd, sod) = match sod >= length {
+                true => (mjd + 1, sod - length),
+                false => (mjd, sod),
+            };
+            from_label(mjd, sod, ctx).map_err(conversion)?
+        } else if let Some((week, pos)) = self.week {
+            let since_epoch = week.checked_mul(NANOS_PER_WEEK).ok_or(invalid(pos))?
+                + self.tow.map_or(0, |(tow, _)| tow)
+                + gnss_epoch::<S>().ok_or(invalid(pos))?;
+            let mjd = to_i64(since_epoch.div_euclid(NANOS_PER_DAY), pos)?;
+            from_label(mjd, since_epoch.rem_euclid(NANOS_PER_DAY), ctx).map_err(conversion)?
+        } else if self.tow.is_some() {
+            return Err(missing);
+        } else {
+            let mjd = self.calendar_day().ok_or(missing)??;
+            let clock = [self.hour, self.minute, self.second].map(|f| f.map_or(0, |f| f.0 as u32));
+            time_from_clock(mjd, clock, self.frac_nanos, ctx).map_err(conversion)?
+        };
+
+        // Every field present must describe the resolved label.
+        let label = time.to_mjd_sod_with(ctx).map_err(conversion)?;
+        let mjd = label.mjd.value();
+        let sod = (label.second_of_day.as_nanos_i128() / NANOS_PER_SECOND) as u32;
+        let clock = match sod {
+            86_400.. => [23, 59, 60],
+            _ => [sod / 3_600, sod / 60 % 60, sod % 60],
+        };
+        let (year, month, day) = encoding::gregorian_from_mjd(mjd);
+        let (_, day_of_year) = encoding::year_day_from_mjd(mjd);
+        let (iso_year, iso_week) = encoding::iso_week(mjd);
+        let checks = [
+            (self.year, year as i128),
+            (self.short_year, year.rem_euclid(100) as i128),
+            (self.month, month as i128),
+            (self.day, day as i128),
+            (self.day_of_year, day_of_year as i128),
+            (self.weekday, encoding::iso_weekday(mjd) as i128),
+            (self.iso_year, iso_year as i128),
+            (self.iso_week, iso_week as i128),
+            (self.hour, clock[0] as i128),
+            (self.minute, clock[1] as i128),
+            (self.second, clock[2] as i128),
+        ];
+        for (field, expected) in checks {
+            if let Some((value, pos)) = field {
+                if value != expected {
+                    return Err(invalid(pos));
+                }
+            }
+        }
+        Ok(time)
+    }
+
+    /// Day from `%Y`/`%y` with `%m %d` or `%j`, or from `%G %V [%u]`.
+    fn calendar_day(&self) -> Option<Result<i64, TimeParseError>> {
+        let invalid = |pos| TimeParseError::new(pos, TimeParseErrorKind::InvalidField);
+        let year = self
+            .year
+            .map(|(year, _)| year)
+            .or(self.short_year.map(|(year, _)| {
+                if year >= 69 {
+                    1_900 + year
+                } else {
+                    2_000 + year
+                }
+            }));
+        let day = match (year, self.month, self.day, self.day_of_year) {
+            (Some(year), Some((month, _)), Some((day, pos)), _) => {
+                encoding::mjd_from_gregorian(year as i64, month as u32, day as u32)
+                    .ok_or(invalid(pos))
+            }
+            (Some(year), _, _, Some((ordinal, pos))) => {
+                encoding::mjd_from_year_day(year as i64, ordinal as u32).ok_or(invalid(pos))
+            }
+            _ => {
+                let ((iso_year, _), (week, pos)) = (self.iso_year?, self.iso_week?);
+                let weekday = self.weekday.map_or(1, |(weekday, _)| weekday as u32);
+                encoding::mjd_from_iso_week(iso_year as i64, week as u32, weekday)
+                    .ok_or(invalid(pos))
+            }
+        };
+        Some(day)
+    }
+}
+
+fn from_label<S: CalendarScale>(
+    mjd: i64,
+    nanos_of_day: i128,
+    ctx: &TimeContext,
+) -> Result<Time<S>, ConversionError> {
+    let label = MjdSod::new(
+        qtty::i64::Day::new(mjd),
+        ExactDuration::from_nanos(nanos_of_day),
+    )?;
+    Time::<S>::from_mjd_sod_with(label, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{GnssWeek, BDT, GPST, GST, TT, UTC};
+
+    fn pattern(s: &str) -> TimePattern {
+        TimePattern::new(s).unwrap()
+    }
+
+    #[test]
+    fn calendar_and_iso_week_fields_round_trip() {
+        let t = Time::<TT>::parse_iso("2021-01-03T04:05:06.789 TT").unwrap();
+        let p = pattern("%A %B %d %Y (%y, %j) %G-W%V-%u %H:%M:%S.%f %Z");
+        let s = p.format(t);
+        assert_eq!(
+            s,
+            "Sunday January 03 2021 (21, 003) 2020-W53-7 04:05:06.789000000 TT"
+        );
+        assert_eq!(p.parse::<TT>(&s).unwrap(), t);
+
+        let iso = pattern("%G-W%V-%u");
+        assert_eq!(
+            iso.parse::<TT>("2020-W53-7").unwrap(),
+            Time::<TT>::parse_iso("2021-01-03 TT").unwrap()
+        );
+        assert_eq!(
+            pattern("%y%j")
+                .parse::<TT>("99365")
+                .unwrap()
+                .format_iso(FormatOptions::SECONDS),
+            "1999-12-31T00:00:00 TT"
+        );
+    }
+
+    #[test]
+    fn day_values_and_leap_seconds() {
+        let j2000 = Time::<TT>::parse_iso("2000-01-01T12:00:00 TT").unwrap();
+        assert_eq!(pattern("%{jd}").format(j2000), "2451545.000000");
+        assert_eq!(pattern("%0{mjd} %Z").format(j2000), "51544 TT");
+        assert_eq!(
+            pattern("%9{jd}").parse::<TT>("2451545.250000000").unwrap(),
+            Time::<TT>::parse_iso("2000-01-01T18:00:00 TT").unwrap()
+        );
+        assert_eq!(
+            pattern("%{mjd}")
+                .parse::<TT>("-0.25")
+                .unwrap()
+                .format_iso(FormatOptions::SECONDS),
+            "1858-11-16T18:00:00 TT"
+        );
+
+        let leap = Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60Z").unwrap();
+        assert_eq!(pattern("%H:%M:%S").format(leap), "23:59:60");
+        // Leap-second days are 86 401 s long.
+        assert_eq!(pattern("%9{mjd}").format(leap), "57753.999988426");
+
+        let tt = pattern("%Y-%m-%d %H:%M:%S");
+        assert_eq!(
+            tt.parse::<TT>("2016-12-31 23:59:60"),
+            Err(TimeParseError::new(
+                0,
+                TimeParseErrorKind::Conversion(ConversionError::OutOfRange)
+            ))
+        );
+    }
+
+    #[test]
+    fn gnss_week_tokens_match_gnss_week() {
+        let p = pattern("%{week} %3{tow}");
+        let gpst = Time::<GPST>::parse_iso("2024-01-15T12:00:00.25 GPST").unwrap();
+        assert_eq!(p.format(gpst), "2297 129600.250");
+        assert_eq!(p.parse::<GPST>("2297 129600.250").unwrap(), gpst);
+
+        let gst = Time::<GST>::parse_iso("2024-01-15T12:00:00 GST").unwrap();
+        let GnssWeek {
+            week,
+            seconds_of_week,
+            ..
+        } = gst.to_gnss_week().unwrap();
+        assert_eq!(
+            pattern("%{week} %{tow}").format(gst),
+            format!("{} {}", week.value(), seconds_of_week.value())
+        );
+
+        let bdt = Time::<BDT>::parse_iso("2024-01-15T12:00:00 BDT").unwrap();
+        let GnssWeek {
+            week,
+            seconds_of_week,
+            ..
+        } = bdt.to_gnss_week().unwrap();
+        assert_eq!(
+            pattern("%{week} %{tow}").format(bdt),
+            format!("{} {}", week.value(), seconds_of_week.value())
+        );
+
+        // Scales without GNSS weeks reject the week directives.
+        let tt = Time::<TT>::parse_iso("2024-01-15T12:00:00 TT").unwrap();
+        let ctx = TimeContext::new();
+        for p in [pattern("%{week}"), pattern("%Y-%m-%d %{tow}")] {
+            assert_eq!(
+                p.try_format_with(tt, &ctx),
+                Err(ConversionError::OutOfRange)
+            );
+        }
+        let invalid = |pos| TimeParseError::new(pos, TimeParseErrorKind::InvalidField);
+        assert_eq!(p.parse::<TT>("2297 129600.250"), Err(invalid(0)));
+        assert_eq!(
+            pattern("%Y-%m-%d %{tow}").parse::<UTC>("2024-01-15 129600"),
+            Err(invalid(11))
+        );
+    }
+
+    #[test]
+    fn rounding_follows_precision() {
+        let t = Time::<TT>::parse_iso("2024-01-01T23:59:59.9996 TT").unwrap();
+        let p = pattern("%d %H:%M:%S.%3f");
+        assert_eq!(p.format(t), "01 23:59:59.999");
+        let rounded = p.clone().with_precision(FormatPrecision::RoundHalfToEven);
+        assert_eq!(rounded.precision(), FormatPrecision::RoundHalfToEven);
+        assert_eq!(rounded.format(t), "02 00:00:00.000");
+        assert_eq!(pattern("%S.%f|%2f").format(t), "59.999600000|99");
+    }
+
+    #[test]
+    fn errors_carry_positions() {
+        use TimeParseErrorKind::*;
+
+        let compile = |s| TimePattern::new(s).unwrap_err();
+        assert_eq!(compile("%Y-%q"), TimeParseError::new(3, UnknownWord));
+        assert_eq!(compile("%3Y"), TimeParseError::new(0, UnexpectedToken));
+        assert_eq!(compile("%10f"), TimeParseError::new(0, InvalidField));
+        assert_eq!(compile("%{foo}"), TimeParseError::new(0, UnknownWord));
+        assert_eq!(compile("abc %"), TimeParseError::new(5, MissingField));
+        assert_eq!(compile("%{jd"), TimeParseError::new(4, MissingField));
+
+        let p = pattern("%a %d %b %Y");
+        assert_eq!(
+            p.parse::<TT>("Tue 15 Jan 2024").unwrap_err(),
+            TimeParseError::new(0, InvalidField)
+        );
+        assert_eq!(
+            p.parse::<TT>("Mon 15 Jan 2024!").unwrap_err(),
+            TimeParseError::new(15, UnexpectedCharacter)
+        );
+        assert_eq!(
+            p.parse::<TT>("Mon 15 Jan").unwrap_err(),
+            TimeParseError::new(10, MissingField)
+        );
+        assert_eq!(
+            p.parse::<TT>("Mon 15 Foo 2024").unwrap_err(),
+            TimeParseError::new(7, InvalidField)
+        );
+        assert_eq!(
+            pattern("%Y-%m-%d").parse::<TT>("2024-13-01").unwrap_err(),
+            TimeParseError::new(5, InvalidField)
+        );
+        assert_eq!(
+            pattern("%H:%M").parse::<TT>("12:00").unwrap_err(),
+            TimeParseError::new(5, MissingField)
+        );
+        assert_eq!(
+            pattern("%Y %Z").parse::<TT>("2024 TAI").unwrap_err(),
+            TimeParseError::new(5, InvalidField)
+        );
+    }
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! PTP (IEEE 1588) timestamps.
+//!
+//! PTP uses the TAI timescale with epoch 1970-01-01T00:00:00 TAI. A wire
+//! `Timestamp` is a 48-bit unsigned seconds field followed by a 32-bit
+//! nanoseconds field, both big-endian (10 bytes). Because the count is TAI,
+//! encoding and decoding [`Time<TAI>`] is pure integer arithmetic; UTC
+//! consumers use the `currentUtcOffset` announced alongside it, available
+//! here as [`Time::<UTC>::ptp_utc_offset`].
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{PtpTimestamp, Time, TAI};
+//!
+//! let ts = PtpTimestamp::new(1_700_000_037, 250_000_000).unwrap();
+//! let t = Time::<TAI>::from_ptp_timestamp(ts).unwrap();
+//! assert_eq!(t.to_ptp_timestamp().unwrap(), ts);
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{TAI, UTC};
+use crate::model::time::Time;
+
+/// J2000 coordinate of the PTP epoch (1970-01-01T00:00:00 TAI) on the TAI axis.
+const PTP_EPOCH_J2000_NANOS: i128 = -946_728_000 * NANOS_PER_SECOND;
+/// MJD of 1970-01-01.
+const UNIX_EPOCH_MJD: i128 = 40_587;
+const NANOS_PER_DAY: i128 = 86_400 * NANOS_PER_SECOND;
+
+/// PTP wire timestamp: 48-bit TAI seconds since 1970 and nanoseconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PtpTimestamp {
+    /// TAI seconds since 1970-01-01T00:00:00 TAI (48 bits on the wire).
+    pub seconds: u64,
+    /// Nanoseconds within the second, in `[0, 10⁹)`.
+    pub nanoseconds: u32,
+}
+
+impl PtpTimestamp {
+    /// Largest representable seconds value (`2⁴⁸ − 1`).
+    pub const MAX_SECONDS: u64 = (1 << 48) - 1;
+
+    /// Construct, validating the field ranges.
+    pub const fn new(seconds: u64, nanoseconds: u32) -> Result<Self, ConversionError> {
+        if seconds > Self::MAX_SECONDS || nanoseconds >= NANOS_PER_SECOND as u32 {
+            return Err(ConversionError::OutOfRange);
+        }
+        Ok(Self {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Decode the 10-byte big-endian wire layout.
+    pub fn from_be_bytes(bytes: [u8; 10]) -> Result<Self, ConversionError> {
+        let mut seconds = [0; 8];
+        seconds[2..].copy_from_slice(&bytes[..6]);
+        let nanoseconds = u32::from_be_bytes([bytes[6], bytes[7], bytes[8], bytes[9]]);
+        Self::new(u64::from_be_bytes(seconds), nanoseconds)
+    }
+
+    /// 10-byte big-endian wire layout.
+    pub fn to_be_bytes(self) -> [u8; 10] {
+        let mut out = [0; 10];
+        out[..6].copy_from_slice(&self.seconds.to_be_bytes()[2..]);
+        out[6..].copy_from_slice(&self.nanoseconds.to_be_bytes());
+        out
+    }
+
+    fn nanos_since_epoch(self) -> i128 {
+        self.seconds as i128 * NANOS_PER_SECOND + self.nanoseconds as i128
+    }
+}
+
+impl Time<TAI> {
+    /// Encode as a PTP timestamp, rounded to the nearest nanosecond.
+    ///
+    /// Instants before 1970 or beyond the 48-bit range return
+    /// [`ConversionError::OutOfRange`].
+    pub fn to_ptp_timestamp(self) -> Result<PtpTimestamp, ConversionError> {
+        let nanos = self.to_exact_j2000()?.as_nanos_i128() - PTP_EPOCH_J2000_NANOS;
+        if nanos < 0 {
+            return Err(ConversionError::OutOfRange);
+        }
+        let seconds =
+            u64::try_from(nanos / NANOS_PER_SECOND).map_err(|_| ConversionError::OutOfRange)?;
+        PtpTimestamp::new(seconds, (nanos % NANOS_PER_SECOND) as u32)
+    }
+
+    /// Decode a PTP timestamp.
+    pub fn from_ptp_timestamp(timestamp: PtpTimestamp) -> Result<Self, ConversionError> {
+        let timestamp = PtpTimestamp::new(timestamp.seconds, timestamp.nanoseconds)?;
+        Self::try_from_exact_j2000(ExactDuration::from_nanos(
+            timestamp.nanos_since_epoch() + PTP_EPOCH_J2000_NANOS,
+        ))
+    }
+}
+
+impl Time<UTC> {
+    /// Encode as a PTP timestamp. See [`Time::<TAI>::to_ptp_timestamp`].
+    pub fn to_ptp_timestamp(self) -> Result<PtpTimestamp, ConversionError> {
+        self.to::<TAI>().to_ptp_timestamp()
+    }
+
+    /// Decode a PTP timestamp onto the UTC axis.
+    pub fn from_ptp_timestamp(timestamp: PtpTimestamp) -> Result<Self, ConversionError> {
+        Ok(Time::<TAI>::from_ptp_timestamp(timestamp)?.to::<UTC>())
+    }
+
+    /// PTP `currentUtcOffset` (`TAI − UTC` in whole seconds) at this instant.
+    ///
+    /// During `23:59:60` the previous offset still applies. Before 1972 the
+    /// offset is not an integer and [`ConversionError::OutOfRange`] is
+    /// returned. Snapshots the active time-data bundle via
+    /// [`TimeContext::new`].
+    pub fn ptp_utc_offset(self) -> Result<i16, ConversionError> {
+        self.ptp_utc_offset_with(&TimeContext::new())
+    }
+
+    /// Like [`Self::ptp_utc_offset`], using `ctx` for the UTC-TAI table.
+    pub fn ptp_utc_offset_with(self, ctx: &TimeContext) -> Result<i16, ConversionError> {
+        let label = self.to_mjd_sod_with(ctx)?;
+        let utc_nanos =
+            label.to_nominal_duration().as_nanos_i128() - UNIX_EPOCH_MJD * NANOS_PER_DAY;
+        let tai_nanos = self.to_exact_j2000()?.as_nanos_i128() - PTP_EPOCH_J2000_NANOS;
+        let offset = tai_nanos - utc_nanos;
+        if offset % NANOS_PER_SECOND != 0 {
+            return Err(ConversionError::OutOfRange);
+        }
+        i16::try_from(offset / NANOS_PER_SECOND).map_err(|_| ConversionError::OutOfRange)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MjdSod;
+
+    fn utc(mjd: i64, second_of_day: i128, nanos: i128) -> Time<UTC> {
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(second_of_day * NANOS_PER_SECOND + nanos),
+        )
+        .unwrap();
+        Time::<UTC>::from_mjd_sod(label).unwrap()
+    }
+
+    #[test]
+    fn epoch_and_present_day_timestamps() {
+        let epoch = Time::<TAI>::from_ptp_timestamp(PtpTimestamp::new(0, 0).unwrap()).unwrap();
+        assert_eq!(
+            epoch.to_exact_j2000().unwrap().as_nanos_i128(),
+            PTP_EPOCH_J2000_NANOS
+        );
+        // 2017-01-01T00:00:00 UTC = POSIX 1 483 228 800, TAI − UTC = 37 s.
+        let ts = utc(57_754, 0, 123_456_789).to_ptp_timestamp().unwrap();
+        assert_eq!(ts, PtpTimestamp::new(1_483_228_837, 123_456_789).unwrap());
+    }
+
+    #[test]
+    fn round_trip_is_nanosecond_exact() {
+        let ts = PtpTimestamp::new(1_900_000_000, 999_999_999).unwrap();
+        let t = Time::<UTC>::from_ptp_timestamp(ts).unwrap();
+        assert_eq!(t.to_ptp_timestamp().unwrap(), ts);
+        let bytes = ts.to_be_bytes();
+        assert_eq!(PtpTimestamp::from_be_bytes(bytes).unwrap(), ts);
+    }
+
+    #[test]
+    fn utc_offset_follows_the_table() {
+        assert_eq!(utc(57_753, 43_200, 0).ptp_utc_offset().unwrap(), 36);
+        assert_eq!(
+            utc(57_753, 86_400, 500_000_000).ptp_utc_offset().unwrap(),
+            36
+        );
+        assert_eq!(utc(57_754, 0, 0).ptp_utc_offset().unwrap(), 37);
+        // 1968: rubber-second era, no integer offset.
+        assert_eq!(
+            utc(40_000, 0, 0).ptp_utc_offset(),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        assert!(PtpTimestamp::new(PtpTimestamp::MAX_SECONDS + 1, 0).is_err());
+        assert!(PtpTimestamp::new(0, 1_000_000_000).is_err());
+        let mut bytes = PtpTimestamp::new(1, 0).unwrap().to_be_bytes();
+        bytes[6..].copy_from_slice(&1_000_000_000_u32.to_be_bytes());
+        assert!(PtpTimestamp::from_be_bytes(bytes).is_err());
+        let before_epoch = Time::<TAI>::from_ptp_timestamp(PtpTimestamp::new(0, 0).unwrap())
+            .unwrap()
+            .try_sub_exact(ExactDuration::NANOSECOND)
+            .unwrap();
+        assert_eq!(
+            before_epoch.to_ptp_timestamp(),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+}
@@ -0,0 +1,381 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! RINEX and SP3 epoch records on GNSS time scales.
+//!
+//! GNSS observation (RINEX 2/3/4) and precise-orbit (SP3-c/d) files label
+//! their epochs with fixed-width calendar fields in the file's time system:
+//!
+//! | Layout                      | Record                            | Width | Resolution |
+//! |-----------------------------|-----------------------------------|-------|------------|
+//! | [`GnssEpochLayout::Rinex2`] | ` 24  1 15 12  0 30.0000000`      | 26    | 100 ns     |
+//! | [`GnssEpochLayout::Rinex3`] | `> 2024 01 15 12 00 30.0000000`   | 29    | 100 ns     |
+//! | [`GnssEpochLayout::Sp3`]    | `*  2024  1 15 12  0 30.00000000` | 31    | 10 ns      |
+//!
+//! RINEX 4 keeps the RINEX 3 record. RINEX 2 two-digit years map 80–99 to
+//! 19xx and 00–79 to 20xx. Only the first [`GnssEpochLayout::width`] bytes
+//! are read, so whole epoch lines (with flags and satellite counts) can be
+//! passed directly; formatting emits exactly that many bytes.
+//!
+//! The fields are a calendar label on the file's scale, e.g. `Time<GPST>`
+//! for `GPS` files. [`GnssTimeSystem`] maps the RINEX/SP3 time-system codes
+//! onto tempoch scales; `GLO` files are labelled in UTC(SU), handled here as
+//! [`crate::UTC`]. Parsing is exact to the nanosecond; formatting rounds
+//! half-to-even at the layout's resolution.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{GnssEpochLayout, Time, GPST};
+//!
+//! let line = "> 2024 01 15 12 00 30.0000000  0 31";
+//! let t = Time::<GPST>::parse_gnss_epoch(line, GnssEpochLayout::Rinex3).unwrap();
+//! assert_eq!(t.format_gnss_epoch(GnssEpochLayout::Rinex3), &line[..29]);
+//! assert_eq!(
+//!     t.format_gnss_epoch(GnssEpochLayout::Sp3),
+//!     "*  2024  1 15 12  0 30.00000000"
+//! );
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::error::ConversionError;
+use crate::model::time::Time;
+
+use super::iso::{parse_digits, parse_fraction_nanos, rounded_label, time_from_clock};
+use super::{CalendarScale, FormatOptions, FormatPrecision};
+
+/// Fixed-column epoch record layouts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GnssEpochLayout {
+    /// RINEX 2 observation epoch: `(1X,I2.2,4(1X,I2),F11.7)`.
+    Rinex2,
+    /// RINEX 3/4 observation epoch: `(A1,1X,I4,4(1X,I2.2),F11.7)`.
+    Rinex3,
+    /// SP3-c/d epoch header: `(A2,1X,I4,4(1X,I2),1X,F11.8)`.
+    Sp3,
+}
+
+impl GnssEpochLayout {
+    /// Width of the epoch fields in bytes.
+    pub const fn width(self) -> usize {
+        match self {
+            Self::Rinex2 => 26,
+            Self::Rinex3 => 29,
+            Self::Sp3 => 31,
+        }
+    }
+
+    /// Decimal places in the seconds field.
+    pub const fn subsecond_digits(self) -> u8 {
+        match self {
+            Self::Rinex2 | Self::Rinex3 => 7,
+            Self::Sp3 => 8,
+        }
+    }
+
+    /// `(record prefix, year range, [month, day, hour, minute] starts,
+    /// seconds start)`.
+    const fn columns(self) -> (&'static str, core::ops::Range<usize>, [usize; 4], usize) {
+        match self {
+            Self::Rinex2 => (" ", 1..3, [4, 7, 10, 13], 15),
+            Self::Rinex3 => ("> ", 2..6, [7, 10, 13, 16], 18),
+            Self::Sp3 => ("*  ", 3..7, [8, 11, 14, 17], 20),
+        }
+    }
+}
+
+/// RINEX/SP3 time-system codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GnssTimeSystem {
+    /// `GPS`: [`crate::GPST`].
+    Gps,
+    /// `GAL`: [`crate::GST`].
+    Galileo,
+    /// `BDT`: [`crate::BDT`].
+    BeiDou,
+    /// `QZS`: [`crate::QZSST`].
+    Qzss,
+    /// `GLO`: UTC(SU), labelled as [`crate::UTC`].
+    Glonass,
+    /// `UTC`.
+    Utc,
+    /// `TAI`.
+    Tai,
+}
+
+impl GnssTimeSystem {
+    /// Parse a three-letter time-system code (surrounding blanks ignored).
+    pub fn parse(code: &str) -> Option<Self> {
+        Some(match code.trim() {
+            "GPS" => Self::Gps,
+            "GAL" => Self::Galileo,
+            "BDT" => Self::BeiDou,
+            "QZS" => Self::Qzss,
+            "GLO" => Self::Glonass,
+            "UTC" => Self::Utc,
+            "TAI" => Self::Tai,
+            _ => return None,
+        })
+    }
+
+    /// Time-system code as written in RINEX and SP3 headers.
+    pub const fn code(self) -> &'static str {
+        match self {
+            Self::Gps => "GPS",
+            Self::Galileo => "GAL",
+            Self::BeiDou => "BDT",
+            Self::Qzss => "QZS",
+            Self::Glonass => "GLO",
+            Self::Utc => "UTC",
+            Self::Tai => "TAI",
+        }
+    }
+
+    /// [`Scale::NAME`](crate::Scale::NAME) of the scale that labels this system's epochs.
+    pub const fn scale_name(self) -> &'static str {
+        match self {
+            Self::Gps => "GPST",
+            Self::Galileo => "GST",
+            Self::BeiDou => "BDT",
+            Self::Qzss => "QZSST",
+            Self::Glonass | Self::Utc => "UTC",
+            Self::Tai => "TAI",
+        }
+    }
+
+    /// The time system written for epochs on scale `S` (`UTC` rather than
+    /// `GLO` for [`crate::UTC`]).
+    pub fn of<S: CalendarScale>() -> Option<Self> {
+        S::GNSS_TIME_SYSTEM
+    }
+}
+
+impl<S: CalendarScale> Time<S> {
+    /// Parse the epoch fields at the start of `line` in `layout`.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn parse_gnss_epoch(line: &str, layout: GnssEpochLayout) -> Result<Self, ConversionError> {
+        Self::parse_gnss_epoch_with(line, layout, &TimeContext::new())
+    }
+
+    /// Like [`parse_gnss_epoch`](Self::parse_gnss_epoch), with an explicit
+    /// [`TimeContext`].
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for a malformed or non-ASCII
+    /// record and [`ConversionError::InvalidLeapSecond`] for second 60 on a day without
+    /// a leap second.
+    pub fn parse_gnss_epoch_with(
+        line: &str,
+        layout: GnssEpochLayout,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        let record = line
+            .get(..layout.width())
+            .ok_or(ConversionError::OutOfRange)?;
+        let (prefix, year_range, starts, seconds_start) = layout.columns();
+        // Fields are byte columns; a multi-byte character would split them.
+        if !record.is_ascii() || !record.starts_with(prefix) {
+            return Err(ConversionError::OutOfRange);
+        }
+        let bytes = record.as_bytes();
+        // Month to minute are each preceded by a blank separator column; the
+        // seconds field is blank-padded on its own.
+        if starts.iter().any(|&start| bytes[start - 1] != b' ') {
+            return Err(ConversionError::OutOfRange);
+        }
+        let [month, day, hour, minute] =
+            starts.map(|start| fixed_integer(&record[start..start + 2]));
+        let mut year = fixed_integer(&record[year_range])? as i64;
+        if layout == GnssEpochLayout::Rinex2 {
+            year += if year >= 80 { 1_900 } else { 2_000 };
+        }
+
+        let seconds = record[seconds_start..].trim_start();
+        let (whole, frac) = seconds.split_once('.').ok_or(ConversionError::OutOfRange)?;
+        if frac.len() != layout.subsecond_digits() as usize {
+            return Err(ConversionError::OutOfRange);
+        }
+        let mjd =
+            encoding::mjd_from_gregorian(year, month?, day?).ok_or(ConversionError::OutOfRange)?;
+        time_from_clock(
+            mjd,
+            [hour?, minute?, parse_digits(whole)?],
+            parse_fraction_nanos(frac)?,
+            ctx,
+        )
+    }
+
+    /// Format as a `layout` epoch record.
+    pub fn format_gnss_epoch(&self, layout: GnssEpochLayout) -> String {
+        self.format_gnss_epoch_with(layout, &TimeContext::new())
+    }
+
+    /// Like [`format_gnss_epoch`](Self::format_gnss_epoch), with an explicit
+    /// [`TimeContext`].
+    ///
+    /// Returns `"<invalid>"` if the instant cannot be labelled; use
+    /// [`try_format_gnss_epoch_with`](Self::try_format_gnss_epoch_with) to
+    /// handle that case explicitly.
+    pub fn format_gnss_epoch_with(&self, layout: GnssEpochLayout, ctx: &TimeContext) -> String {
+        self.try_format_gnss_epoch_with(layout, ctx)
+            .unwrap_or_else(|_| "<invalid>".to_string())
+    }
+
+    /// Fallible variant of
+    /// [`format_gnss_epoch_with`](Self::format_gnss_epoch_with).
+    ///
+    /// Returns [`ConversionError::OutOfRange`] for years outside
+    /// `0000..=9999` (1980–2079 for [`GnssEpochLayout::Rinex2`]).
+    pub fn try_format_gnss_epoch_with(
+        &self,
+        layout: GnssEpochLayout,
+        ctx: &TimeContext,
+    ) -> Result<String, ConversionError> {
+        let opts = FormatOptions {
+            subsecond_digits: layout.subsecond_digits(),
+            precision: FormatPrecision::RoundHalfToEven,
+            include_zulu: false,
+            utc_offset: None,
+        };
+        let label = rounded_label(*self, opts, ctx)?;
+        let (year, month, day) = encoding::gregorian_from_mjd(label.mjd);
+        let [hour, minute, second] = label.clock;
+        let frac = label.frac;
+        Ok(match layout {
+            GnssEpochLayout::Rinex2 => {
+                if !(1_980..=2_079).contains(&year) {
+                    return Err(ConversionError::OutOfRange);
+                }
+                format!(
+                    " {:02} {month:>2} {day:>2} {hour:>2} {minute:>2}{second:>3}.{frac:07}",
+                    year % 100
+                )
+            }
+            _ if !(0..=9_999).contains(&year) => return Err(ConversionError::OutOfRange),
+            GnssEpochLayout::Rinex3 => format!(
+                "> {year:04} {month:02} {day:02} {hour:02} {minute:02}{second:>3}.{frac:07}"
+            ),
+            GnssEpochLayout::Sp3 => format!(
+                "*  {year:04} {month:>2} {day:>2} {hour:>2} {minute:>2} {second:>2}.{frac:08}"
+            ),
+        })
+    }
+}
+
+/// Right-justified fixed-width integer (`" 1"`, `"01"`, `"2024"`).
+fn fixed_integer(field: &str) -> Result<u32, ConversionError> {
+    parse_digits(field.trim_start())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::format::MjdSod;
+    use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+    use crate::{Scale, BDT, GPST, GST, UTC};
+
+    fn label<S: CalendarScale>(mjd: i64, nanos_of_day: i128) -> Time<S> {
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(nanos_of_day),
+        )
+        .unwrap();
+        Time::<S>::from_mjd_sod(label).unwrap()
+    }
+
+    #[test]
+    fn records_round_trip_byte_for_byte() {
+        let cases = [
+            (GnssEpochLayout::Rinex2, " 24  1 15 12  0 30.1234567"),
+            (GnssEpochLayout::Rinex2, " 99 12 31 23 59 59.9999999"),
+            (GnssEpochLayout::Rinex3, "> 2024 01 15 12 00 30.1234567"),
+            (GnssEpochLayout::Sp3, "*  2024  1 15 12  0 30.12345678"),
+        ];
+        for (layout, record) in cases {
+            let t = Time::<GPST>::parse_gnss_epoch(record, layout).unwrap();
+            assert_eq!(t.format_gnss_epoch(layout), record);
+            assert_eq!(record.len(), layout.width());
+        }
+        let t =
+            Time::<GST>::parse_gnss_epoch(" 24  1 15 12  0 30.1234567", GnssEpochLayout::Rinex2)
+                .unwrap();
+        assert_eq!(t, label(60_324, 43_230 * NANOS_PER_SECOND + 123_456_700));
+    }
+
+    #[test]
+    fn parses_full_lines_and_rejects_misaligned_fields() {
+        let line = "*  2006  1  1  0  0  0.00000000";
+        let t = Time::<BDT>::parse_gnss_epoch(line, GnssEpochLayout::Sp3).unwrap();
+        assert_eq!(t, label(53_736, 0));
+        let obs = "> 2006 01 01 00 00  0.0000000  0 12      -0.000123456789";
+        assert_eq!(
+            Time::<BDT>::parse_gnss_epoch(obs, GnssEpochLayout::Rinex3),
+            Ok(t)
+        );
+        for (layout, bad) in [
+            (GnssEpochLayout::Rinex3, "> 2006 01 01 00 00  0.000000"),
+            (GnssEpochLayout::Rinex3, ">2006 01 01 00 00   0.0000000"),
+            (GnssEpochLayout::Rinex3, "> 2006 13 01 00 00  0.0000000"),
+            (GnssEpochLayout::Sp3, "*  2006  1  1  0  0  0.0000000 "),
+            (
+                GnssEpochLayout::Rinex3,
+                "> 2024 01 15 12 0é 30.0000000  0 31",
+            ),
+            (GnssEpochLayout::Rinex2, " 2é  1 15 12  0 30.1234567"),
+            (GnssEpochLayout::Sp3, "*  2024  1 15 12  0 3é.00000000"),
+        ] {
+            assert_eq!(
+                Time::<BDT>::parse_gnss_epoch(bad, layout),
+                Err(ConversionError::OutOfRange),
+                "{bad}"
+            );
+        }
+    }
+
+    #[test]
+    fn utc_epochs_carry_leap_seconds_and_rounding() {
+        let leap = "> 2016 12 31 23 59 60.5000000";
+        let t = Time::<UTC>::parse_gnss_epoch(leap, GnssEpochLayout::Rinex3).unwrap();
+        assert_eq!(t, label(57_753, 86_400 * NANOS_PER_SECOND + 500_000_000));
+        assert_eq!(t.format_gnss_epoch(GnssEpochLayout::Rinex3), leap);
+        assert_eq!(
+            Time::<UTC>::parse_gnss_epoch("> 2016 12 30 23 59 60.0000000", GnssEpochLayout::Rinex3),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+        // 59.99999996 s rounds up into the next minute at 100 ns resolution.
+        let t = label::<GPST>(60_324, 60 * NANOS_PER_SECOND - 40);
+        assert_eq!(
+            t.format_gnss_epoch(GnssEpochLayout::Rinex3),
+            "> 2024 01 15 00 01  0.0000000"
+        );
+        assert_eq!(
+            t.format_gnss_epoch(GnssEpochLayout::Sp3),
+            "*  2024  1 15  0  0 59.99999996"
+        );
+        assert_eq!(
+            label::<GPST>(29_000, 0)
+                .try_format_gnss_epoch_with(GnssEpochLayout::Rinex2, &TimeContext::new()),
+            Err(ConversionError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn time_system_codes_map_to_scales() {
+        assert_eq!(GnssTimeSystem::parse(" GAL"), Some(GnssTimeSystem::Galileo));
+        assert_eq!(GnssTimeSystem::parse("IRN"), None);
+        assert_eq!(GnssTimeSystem::Glonass.scale_name(), UTC::NAME);
+        assert_eq!(GnssTimeSystem::of::<UTC>(), Some(GnssTimeSystem::Utc));
+        assert_eq!(
+            GnssTimeSystem::of::<BDT>().map(GnssTimeSystem::code),
+            Some("BDT")
+        );
+        assert_eq!(GnssTimeSystem::of::<crate::TT>(), None);
+        assert_eq!(GnssTimeSystem::of::<GST>(), Some(GnssTimeSystem::Galileo));
+        assert_eq!(
+            GnssTimeSystem::of::<crate::TAI>(),
+            Some(GnssTimeSystem::Tai)
+        );
+    }
+}
@@ -0,0 +1,901 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! SPICE `str2et`-style time strings.
+//!
+//! Accepts the forms NAIF tools exchange, e.g.
+//!
+//! - `2024 JAN 15 12:00:00.5 TDB`, `JAN 15, 2024 12:00`, `15 JAN 2024`;
+//! - `2024-01-15T12:00:00`, `2024-015 // 12:00`, `1985-JAN-01 A.D.`;
+//! - `JD 2451545.0 TT`, `JDTDB 2451545.0`;
+//! - `2024-01-01 05:00 UTC+5`, `1 PM` / `A.M.` meridiem markers.
+//!
+//! Like `str2et`, calendar dates on or after 1582-10-15 are Gregorian and
+//! earlier dates Julian; `B.C.` years count backwards with no year zero.
+//! Month names match on their first three letters, case-insensitively. The
+//! scale defaults to UTC and may be `UTC`, `TDB`, `TDT` (= `TT`), `TT`, or
+//! `TAI`; a zone offset (`UTC±h[:mm]`) is allowed on UTC calendar strings.
+//! Julian Dates are read as exact decimals (UTC JDs use 86 400 s days).
+//! A leading `@` is accepted and ignored; a leading sign is rejected, since
+//! negative years and Julian Dates are not supported (use `B.C.`).
+//!
+//! [`SpiceTime`] keeps the instant on the scale it was written in;
+//! [`SpiceTime::to_time`] and [`Time::parse_spice`] convert to any scale
+//! through the usual [`Time::try_to`] conversions. Errors carry the byte
+//! offset of the offending token.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{SpiceTime, Time, TDB, TT, UTC};
+//!
+//! let t = SpiceTime::parse("2000 JAN 01 12:00:00 TT").unwrap();
+//! assert_eq!(t, SpiceTime::parse("JD 2451545.0 TDT").unwrap());
+//! let tdb: Time<TDB> = t.to_time().unwrap();
+//! assert_eq!(tdb, Time::<TT>::parse_spice("JDTDT 2451545").unwrap().to::<TDB>());
+//!
+//! let err = Time::<UTC>::parse_spice("2024 FOO 15").unwrap_err();
+//! assert_eq!(err.position, 5);
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::duration::{ExactDuration, NANOS_PER_SECOND};
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{Scale, TAI, TDB, TT, UTC};
+use crate::model::target::ConversionTarget;
+use crate::model::time::Time;
+
+use super::iso::parse_fraction_nanos;
+use super::{CalendarScale, MjdSod};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const NANOS_PER_DAY: i128 = SECONDS_PER_DAY as i128 * NANOS_PER_SECOND;
+/// JD 0 expressed as nanoseconds since MJD 0 (`−2 400 000.5 d`).
+const JD_ZERO_NANOS_SINCE_MJD_ZERO: i128 = -207_360_043_200 * NANOS_PER_SECOND;
+/// Fractional JD digits kept (1e-15 d ≈ 0.09 ps).
+const MAX_JD_FRACTION_DIGITS: usize = 15;
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+/// What went wrong in a [`TimeParseError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeParseErrorKind {
+    /// A character outside the grammar.
+    UnexpectedCharacter,
+    /// A word that is not a month, era, meridiem, scale, or JD marker.
+    UnknownWord,
+    /// A valid token in a position the grammar does not allow (duplicate
+    /// month or scale, extra numbers, a zone on a non-UTC scale, …).
+    UnexpectedToken,
+    /// The input ended before a required field.
+    MissingField,
+    /// A field is malformed or out of range (month 13, hour 25, …).
+    InvalidField,
+    /// The fields are well formed but do not label an instant on the scale.
+    Conversion(ConversionError),
+}
+
+impl core::fmt::Display for TimeParseErrorKind {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnexpectedCharacter => f.write_str("unexpected character"),
+            Self::UnknownWord => f.write_str("unknown word"),
+            Self::UnexpectedToken => f.write_str("unexpected token"),
+            Self::MissingField => f.write_str("missing field"),
+            Self::InvalidField => f.write_str("invalid field"),
+            Self::Conversion(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+/// Error returned by the SPICE time-string parser and by [`crate::TimePattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeParseError {
+    /// Byte offset of the offending token (the input length for
+    /// [`TimeParseErrorKind::MissingField`]).
+    pub position: usize,
+    /// What went wrong.
+    pub kind: TimeParseErrorKind,
+}
+
+impl TimeParseError {
+    pub(crate) const fn new(position: usize, kind: TimeParseErrorKind) -> Self {
+        Self { position, kind }
+    }
+}
+
+impl core::fmt::Display for TimeParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.position)
+    }
+}
+
+impl std::error::Error for TimeParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            TimeParseErrorKind::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// Instant parsed from a SPICE time string, on the scale it was written in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpiceTime {
+    /// `UTC` (the default) or a zone-shifted UTC string.
+    Utc(Time<UTC>),
+    /// `TDT` or `TT`.
+    Tt(Time<TT>),
+    /// `TDB`.
+    Tdb(Time<TDB>),
+    /// `TAI`.
+    Tai(Time<TAI>),
+}
+
+/// Scales a [`SpiceTime`] can be converted to: every scale reachable from
+/// UTC, TT, TDB, and TAI through [`Time::try_to`].
+pub trait SpiceScale:
+    Scale
+    + ConversionTarget<UTC, Output = Time<Self>>
+    + ConversionTarget<TT, Output = Time<Self>>
+    + ConversionTarget<TDB, Output = Time<Self>>
+    + ConversionTarget<TAI, Output = Time<Self>>
+{
+}
+
+impl<S> SpiceScale for S where
+    S: Scale
+        + ConversionTarget<UTC, Output = Time<S>>
+        + ConversionTarget<TT, Output = Time<S>>
+        + ConversionTarget<TDB, Output = Time<S>>
+        + ConversionTarget<TAI, Output = Time<S>>
+{
+}
+
+impl SpiceTime {
+    /// Parse a SPICE time string.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn parse(s: &str) -> Result<Self, TimeParseError> {
+        Self::parse_with(s, &TimeContext::new())
+    }
+
+    /// Like [`parse`](Self::parse), using `ctx` for UTC labels.
+    pub fn parse_with(s: &str, ctx: &TimeContext) -> Result<Self, TimeParseError> {
+        Self::parse_anchored(s, ctx).map(|(time, _)| time)
+    }
+
+    /// Parse, also returning the position of the date or JD token.
+    fn parse_anchored(s: &str, ctx: &TimeContext) -> Result<(Self, usize), TimeParseError> {
+        Parsed::from_tokens(s, tokenize(s)?)?.build(ctx)
+    }
+
+    /// Name of the scale the string was written in.
+    pub fn scale_name(&self) -> &'static str {
+        match self {
+            Self::Utc(_) => UTC::NAME,
+            Self::Tt(_) => TT::NAME,
+            Self::Tdb(_) => TDB::NAME,
+            Self::Tai(_) => TAI::NAME,
+        }
+    }
+
+    /// Convert to scale `S`.
+    pub fn to_time<S: SpiceScale>(self) -> Result<Time<S>, ConversionError> {
+        match self {
+            Self::Utc(t) => t.try_to::<S>(),
+            Self::Tt(t) => t.try_to::<S>(),
+            Self::Tdb(t) => t.try_to::<S>(),
+            Self::Tai(t) => t.try_to::<S>(),
+        }
+    }
+}
+
+impl<S: SpiceScale> Time<S> {
+    /// Parse a SPICE time string and convert it to `S`. See
+    /// [`SpiceTime::parse`].
+    ///
+    /// A failed conversion to `S` is reported at the date (or `JD`) token.
+    pub fn parse_spice(s: &str) -> Result<Self, TimeParseError> {
+        let (time, anchor) = SpiceTime::parse_anchored(s, &TimeContext::new())?;
+        time.to_time()
+            .map_err(|err| TimeParseError::new(anchor, TimeParseErrorKind::Conversion(err)))
+    }
+}
+
+// ── Tokens ───────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Tok<'a> {
+    /// Digits with at most one `.`.
+    Num(&'a str),
+    /// Letters and dots, e.g. `JAN`, `A.D.`.
+    Word(&'a str),
+    /// `+` or `-`.
+    Sign(u8),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    tok: Tok<'a>,
+    pos: usize,
+}
+
+fn tokenize(s: &str) -> Result<Vec<Token<'_>>, TimeParseError> {
+    let bytes = s.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    let mut seen_content = false;
+    while i < bytes.len() {
+        let b = bytes[i];
+        let start = i;
+        match b {
+            b' ' | b'\t' | b',' | b'/' | b':' => i += 1,
+            b'@' if !seen_content => i += 1,
+            b'+' | b'-' => {
+                tokens.push(Token {
+                    tok: Tok::Sign(b),
+                    pos: start,
+                });
+                i += 1;
+            }
+            b'0'..=b'9' => {
+                let mut dot = false;
+                while i < bytes.len() && (bytes[i].is_ascii_digit() || (bytes[i] == b'.' && !dot)) {
+                    dot |= bytes[i] == b'.';
+                    i += 1;
+                }
+                tokens.push(Token {
+                    tok: Tok::Num(&s[start..i]),
+                    pos: start,
+                });
+            }
+            _ if b.is_ascii_alphabetic() => {
+                while i < bytes.len() && (bytes[i].is_ascii_alphabetic() || bytes[i] == b'.') {
+                    i += 1;
+                }
+                tokens.push(Token {
+                    tok: Tok::Word(&s[start..i]),
+                    pos: start,
+                });
+            }
+            _ => {
+                return Err(TimeParseError::new(
+                    start,
+                    TimeParseErrorKind::UnexpectedCharacter,
+                ))
+            }
+        }
+        seen_content |= !matches!(b, b' ' | b'\t');
+    }
+    Ok(tokens)
+}
+
+// ── Grammar ──────────────────────────────────────────────────────────────
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ScaleLabel {
+    Utc,
+    Tt,
+    Tdb,
+    Tai,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Era {
+    Ad,
+    Bc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+/// A numeric token with its position.
+#[derive(Debug, Clone, Copy)]
+struct Num<'a> {
+    text: &'a str,
+    pos: usize,
+}
+
+impl Num<'_> {
+    fn integer(&self) -> Result<i64, TimeParseError> {
+        if self.text.contains('.') || self.text.len() > 12 {
+            return Err(self.invalid());
+        }
+        self.text.parse().map_err(|_| self.invalid())
+    }
+
+    fn invalid(&self) -> TimeParseError {
+        TimeParseError::new(self.pos, TimeParseErrorKind::InvalidField)
+    }
+}
+
+/// Tokens sorted into fields, before any calendar arithmetic.
+struct Parsed<'a> {
+    input_len: usize,
+    numbers: Vec<Num<'a>>,
+    /// Month (1–12), its position, and how many numbers preceded it.
+    month: Option<(u32, usize, usize)>,
+    jd: Option<usize>,
+    era: Option<(Era, usize)>,
+    meridiem: Option<(Meridiem, usize)>,
+    scale: Option<(ScaleLabel, usize)>,
+    /// Zone offset in seconds east of UTC and the sign's position.
+    zone: Option<(i64, usize)>,
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, pos: usize) -> Result<(), TimeParseError> {
+    if slot.is_some() {
+        return Err(TimeParseError::new(
+            pos,
+            TimeParseErrorKind::UnexpectedToken,
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+impl<'a> Parsed<'a> {
+    fn from_tokens(s: &'a str, tokens: Vec<Token<'a>>) -> Result<Self, TimeParseError> {
+        let mut parsed = Parsed {
+            input_len: s.len(),
+            numbers: Vec::new(),
+            month: None,
+            jd: None,
+            era: None,
+            meridiem: None,
+            scale: None,
+            zone: None,
+        };
+        let mut iter = tokens.into_iter().peekable();
+        // Whether the previous token was an era, meridiem, scale, or JD word.
+        let mut after_label = false;
+        let mut first = true;
+        while let Some(Token { tok, pos }) = iter.next() {
+            let follows_label = core::mem::take(&mut after_label);
+            let leading = core::mem::take(&mut first);
+            let word = match tok {
+                Tok::Num(text) => {
+                    parsed.numbers.push(Num { text, pos });
+                    continue;
+                }
+                // Signs only matter in a zone offset; elsewhere they separate
+                // date fields. Negative years and Julian dates are not
+                // accepted, so a leading sign is an error rather than ignored.
+                Tok::Sign(_) if follows_label || leading => {
+                    return Err(TimeParseError::new(
+                        pos,
+                        TimeParseErrorKind::UnexpectedToken,
+                    ))
+                }
+                Tok::Sign(_) => continue,
+                Tok::Word(word) => word.replace('.', "").to_ascii_uppercase(),
+            };
+            let scale = match word.as_str() {
+                "T" => continue,
+                "AD" => {
+                    set_once(&mut parsed.era, (Era::Ad, pos), pos)?;
+                    after_label = true;
+                    continue;
+                }
+                "BC" => {
+                    set_once(&mut parsed.era, (Era::Bc, pos), pos)?;
+                    after_label = true;
+                    continue;
+                }
+                "AM" => {
+                    set_once(&mut parsed.meridiem, (Meridiem::Am, pos), pos)?;
+                    after_label = true;
+                    continue;
+                }
+                "PM" => {
+                    set_once(&mut parsed.meridiem, (Meridiem::Pm, pos), pos)?;
+                    after_label = true;
+                    continue;
+                }
+                "JD" => None,
+                "JDUTC" | "UTC" => Some(ScaleLabel::Utc),
+                "JDTDT" | "TDT" | "TT" => Some(ScaleLabel::Tt),
+                "JDTDB" | "TDB" => Some(ScaleLabel::Tdb),
+                "TAI" => Some(ScaleLabel::Tai),
+                month if month.len() >= 3 => {
+                    let index = MONTHS
+                        .iter()
+                        .position(|m| month.starts_with(m))
+                        .ok_or(TimeParseError::new(pos, TimeParseErrorKind::UnknownWord))?;
+                    let preceding = parsed.numbers.len();
+                    set_once(&mut parsed.month, (index as u32 + 1, pos, preceding), pos)?;
+                    continue;
+                }
+                _ => return Err(TimeParseError::new(pos, TimeParseErrorKind::UnknownWord)),
+            };
+            after_label = true;
+            if word.starts_with("JD") {
+                set_once(&mut parsed.jd, pos, pos)?;
+            }
+            if let Some(scale) = scale {
+                set_once(&mut parsed.scale, (scale, pos), pos)?;
+            }
+            if word == "UTC" {
+                if let Some(&Token {
+                    tok: Tok::Sign(sign),
+                    pos: sign_pos,
+                }) = iter.peek()
+                {
+                    iter.next();
+                    let offset = parse_zone(s, &mut iter, sign_pos)?;
+                    let offset = if sign == b'-' { -offset } else { offset };
+                    parsed.zone = Some((offset, sign_pos));
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn missing(&self) -> TimeParseError {
+        TimeParseError::new(self.input_len, TimeParseErrorKind::MissingField)
+    }
+
+    /// The instant and the position of its date or `JD` token.
+    fn build(self, ctx: &TimeContext) -> Result<(SpiceTime, usize), TimeParseError> {
+        let (mjd, nanos_of_day, anchor) = match self.jd {
+            Some(jd_pos) => self.julian_date(jd_pos)?,
+            None => self.calendar()?,
+        };
+        let scale = self.scale.map_or(ScaleLabel::Utc, |(scale, _)| scale);
+        let conversion = |err| TimeParseError::new(anchor, TimeParseErrorKind::Conversion(err));
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(nanos_of_day),
+        )
+        .map_err(conversion)?;
+        match scale {
+            ScaleLabel::Utc => label_time(label, ctx).map(SpiceTime::Utc),
+            ScaleLabel::Tt => label_time(label, ctx).map(SpiceTime::Tt),
+            ScaleLabel::Tdb => label_time(label, ctx).map(SpiceTime::Tdb),
+            ScaleLabel::Tai => label_time(label, ctx).map(SpiceTime::Tai),
+        }
+        .map(|time| (time, anchor))
+        .map_err(conversion)
+    }
+
+    /// `JD <number>`: returns `(mjd, nanos_of_day, anchor position)`.
+    fn julian_date(&self, jd_pos: usize) -> Result<(i64, i128, usize), TimeParseError> {
+        let unexpected = |pos| TimeParseError::new(pos, TimeParseErrorKind::UnexpectedToken);
+        if let Some((_, pos, _)) = self.month {
+            return Err(unexpected(pos));
+        }
+        if let Some((_, pos)) = self.era.or(self.meridiem.map(|(_, p)| (Era::Ad, p))) {
+            return Err(unexpected(pos));
+        }
+        if let Some((_, pos)) = self.zone {
+            return Err(unexpected(pos));
+        }
+        let number = match self.numbers.as_slice() {
+            [number] => number,
+            [] => return Err(self.missing()),
+            [_, extra, ..] => return Err(unexpected(extra.pos)),
+        };
+        let (whole, frac) = number.text.split_once('.').unwrap_or((number.text, ""));
+        if whole.len() > 12 {
+            return Err(number.invalid());
+        }
+        let frac = &frac[..frac.len().min(MAX_JD_FRACTION_DIGITS)];
+        let scale = 10_i128.pow(frac.len() as u32);
+        let days: i128 = whole.parse().map_err(|_| number.invalid())?;
+        let frac_value: i128 = if frac.is_empty() {
+            0
+        } else {
+            frac.parse().map_err(|_| number.invalid())?
+        };
+        // Whole days and the fraction apart, so 12 + 15 digits stay well
+        // inside `i128`; the fraction rounds half up to the nearest nanosecond.
+        let nanos = days * NANOS_PER_DAY
+            + (2 * frac_value * NANOS_PER_DAY + scale) / (2 * scale)
+            + JD_ZERO_NANOS_SINCE_MJD_ZERO;
+        let mjd = nanos.div_euclid(NANOS_PER_DAY) as i64;
+        Ok((mjd, nanos.rem_euclid(NANOS_PER_DAY), jd_pos))
+    }
+
+    /// Calendar date and optional clock: returns `(mjd, nanos_of_day, anchor)`.
+    fn calendar(&self) -> Result<(i64, i128, usize), TimeParseError> {
+        let numbers = &self.numbers;
+        let get = |i: usize| numbers.get(i).ok_or_else(|| self.missing());
+        // Resolve (year, month-or-ordinal, day) and the index of the clock.
+        let (year, date, clock_start) = match self.month {
+            Some((month, _, 0)) => (get(1)?, DateField::Month(month, get(0)?), 2),
+            Some((month, _, 1)) if get(0)?.text.len() >= 3 => {
+                (get(0)?, DateField::Month(month, get(1)?), 2)
+            }
+            Some((month, _, 1)) => (get(1)?, DateField::Month(month, get(0)?), 2),
+            Some((_, pos, _)) => {
+                return Err(TimeParseError::new(
+                    pos,
+                    TimeParseErrorKind::UnexpectedToken,
+                ))
+            }
+            None if get(1)?.text.len() == 3 => (get(0)?, DateField::Ordinal(get(1)?), 2),
+            None => (get(0)?, DateField::Numeric(get(1)?, get(2)?), 3),
+        };
+        let anchor = numbers[0].pos;
+
+        let mut year_value = year.integer()?;
+        match self.era {
+            Some((_, _)) if year_value < 1 => return Err(year.invalid()),
+            Some((Era::Bc, _)) => year_value = 1 - year_value,
+            _ => {}
+        }
+        let mjd = date.mjd(year_value)?;
+
+        let clock = &numbers[clock_start.min(numbers.len())..];
+        if clock.len() > 3 {
+            return Err(TimeParseError::new(
+                clock[3].pos,
+                TimeParseErrorKind::UnexpectedToken,
+            ));
+        }
+        let mut fields = [0_i64; 3];
+        let mut frac_nanos = 0;
+        for (i, number) in clock.iter().enumerate() {
+            let last = i == clock.len() - 1;
+            let (whole, frac) = number.text.split_once('.').unwrap_or((number.text, ""));
+            if number.text.contains('.') && (!last || i != 2 || frac.is_empty()) {
+                return Err(number.invalid());
+            }
+            fields[i] = Num {
+                text: whole,
+                ..*number
+            }
+            .integer()?;
+            frac_nanos = parse_fraction_nanos(frac).map_err(|_| number.invalid())?;
+        }
+        let [mut hour, minute, second] = fields;
+        let field_error = |i: usize| {
+            clock
+                .get(i)
+                .map_or(self.missing(), |number| number.invalid())
+        };
+        if let Some((meridiem, pos)) = self.meridiem {
+            if clock.is_empty() {
+                return Err(TimeParseError::new(
+                    pos,
+                    TimeParseErrorKind::UnexpectedToken,
+                ));
+            }
+            if !(1..=12).contains(&hour) {
+                return Err(field_error(0));
+            }
+            hour = match meridiem {
+                Meridiem::Am => hour % 12,
+                Meridiem::Pm => hour % 12 + 12,
+            };
+        }
+        if hour >= 24 {
+            return Err(field_error(0));
+        }
+        if minute >= 60 {
+            return Err(field_error(1));
+        }
+        if second > 60 {
+            return Err(field_error(2));
+        }
+
+        let zone = match (self.zone, self.scale) {
+            (Some((_, pos)), Some((scale, _))) if scale != ScaleLabel::Utc => {
+                return Err(TimeParseError::new(
+                    pos,
+                    TimeParseErrorKind::UnexpectedToken,
+                ));
+            }
+            (Some((offset, _)), _) => offset,
+            (None, _) => 0,
+        };
+        // Shift the local clock to UTC; a `:60` must land on 23:59:60.
+        let local = hour * 3_600 + minute * 60 + second.min(59);
+        let shifted = local - zone;
+        let mjd = mjd + shifted.div_euclid(SECONDS_PER_DAY);
+        let mut second_of_day = shifted.rem_euclid(SECONDS_PER_DAY);
+        if second == 60 {
+            if second_of_day != SECONDS_PER_DAY - 1 {
+                return Err(TimeParseError::new(
+                    clock[2].pos,
+                    TimeParseErrorKind::Conversion(ConversionError::InvalidLeapSecond),
+                ));
+            }
+            second_of_day += 1;
+        }
+        let nanos = second_of_day as i128 * NANOS_PER_SECOND + frac_nanos as i128;
+        let anchor = if second == 60 { clock[2].pos } else { anchor };
+        Ok((mjd, nanos, anchor))
+    }
+}
+
+/// Month-and-day, day-of-year, or numeric month/day date fields.
+enum DateField<'n, 'a> {
+    Month(u32, &'n Num<'a>),
+    Numeric(&'n Num<'a>, &'n Num<'a>),
+    Ordinal(&'n Num<'a>),
+}
+
+impl DateField<'_, '_> {
+    /// MJD on the mixed Julian/Gregorian calendar.
+    fn mjd(&self, year: i64) -> Result<i64, TimeParseError> {
+        let (month, day, pos) = match *self {
+            DateField::Ordinal(ordinal) => {
+                let value = ordinal.integer()?;
+                let jan1 = mixed_calendar_mjd(year, 1, 1).ok_or(ordinal.invalid())?;
+                let next = mixed_calendar_mjd(year + 1, 1, 1).ok_or(ordinal.invalid())?;
+                if value < 1 || jan1 + value > next {
+                    return Err(ordinal.invalid());
+                }
+                return Ok(jan1 + value - 1);
+            }
+            DateField::Month(month, day) => (month, day.integer()?, day),
+            DateField::Numeric(month, day) => {
+                let value = month.integer()?;
+                if !(1..=12).contains(&value) {
+                    return Err(month.invalid());
+                }
+                (value as u32, day.integer()?, day)
+            }
+        };
+        u32::try_from(day)
+            .ok()
+            .and_then(|day| mixed_calendar_mjd(year, month, day))
+            .ok_or(pos.invalid())
+    }
+}
+
+/// Gregorian on or after 1582-10-15, Julian up to 1582-10-04.
+fn mixed_calendar_mjd(year: i64, month: u32, day: u32) -> Option<i64> {
+    if (year, month, day) >= (1582, 10, 15) {
+        encoding::mjd_from_gregorian(year, month, day)
+    } else if (year, month, day) <= (1582, 10, 4) {
+        encoding::mjd_from_julian(year, month, day)
+    } else {
+        None
+    }
+}
+
+/// Zone offset after `UTC±`: `h`, `hh`, or `h:mm`, in seconds.
+fn parse_zone<'a>(
+    s: &str,
+    iter: &mut core::iter::Peekable<std::vec::IntoIter<Token<'a>>>,
+    sign_pos: usize,
+) -> Result<i64, TimeParseError> {
+    let invalid = |pos| TimeParseError::new(pos, TimeParseErrorKind::InvalidField);
+    let Some(Token {
+        tok: Tok::Num(hours),
+        pos,
+    }) = iter.next()
+    else {
+        return Err(invalid(sign_pos));
+    };
+    let hours = Num { text: hours, pos };
+    let hour_value = hours.integer()?;
+    if hours.text.len() > 2 || hour_value > 12 {
+        return Err(hours.invalid());
+    }
+    let mut minute_value = 0;
+    if s.as_bytes().get(pos + hours.text.len()) == Some(&b':') {
+        match iter.next() {
+            Some(Token {
+                tok: Tok::Num(minutes),
+                pos,
+            }) => {
+                let minutes = Num { text: minutes, pos };
+                minute_value = minutes.integer()?;
+                if minutes.text.len() != 2 || minute_value >= 60 {
+                    return Err(minutes.invalid());
+                }
+            }
+            _ => return Err(invalid(pos)),
+        }
+    }
+    Ok(hour_value * 3_600 + minute_value * 60)
+}
+
+fn label_time<S: CalendarScale>(
+    label: MjdSod,
+    ctx: &TimeContext,
+) -> Result<Time<S>, ConversionError> {
+    Time::<S>::from_mjd_sod_with(label, ctx)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label<S: CalendarScale>(mjd: i64, second_of_day: i128, nanos: i128) -> Time<S> {
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(second_of_day * NANOS_PER_SECOND + nanos),
+        )
+        .unwrap();
+        Time::<S>::from_mjd_sod(label).unwrap()
+    }
+
+    fn error(s: &str) -> TimeParseError {
+        SpiceTime::parse(s).unwrap_err()
+    }
+
+    #[test]
+    fn calendar_forms_agree() {
+        let expected = SpiceTime::Tdb(label(60_324, 43_200, 500_000_000));
+        for s in [
+            "2024 JAN 15 12:00:00.5 TDB",
+            "JANUARY 15, 2024 12:00:00.5 TDB",
+            "15 jan 2024 12:00:00.5 tdb",
+            "2024-01-15T12:00:00.5 TDB",
+            "2024-015 // 12:00:00.5 TDB",
+            "2024-Jan-15 12:00:00.5 P.M. TDB",
+        ] {
+            assert_eq!(SpiceTime::parse(s).unwrap(), expected, "{s}");
+        }
+        assert_eq!(
+            SpiceTime::parse("1985-JAN-01 A.D.").unwrap(),
+            SpiceTime::Utc(label(46_066, 0, 0))
+        );
+    }
+
+    #[test]
+    fn julian_dates_are_exact_decimals() {
+        assert_eq!(
+            SpiceTime::parse("JD 2451545.0 TT").unwrap(),
+            SpiceTime::Tt(label(51_544, 43_200, 0))
+        );
+        assert_eq!(
+            SpiceTime::parse("JDTDB 2451545.0000000115740740740").unwrap(),
+            SpiceTime::Tdb(label(51_544, 43_200, 1_000_000))
+        );
+        assert_eq!(
+            SpiceTime::parse("JD2400000.5 TDT").unwrap(),
+            SpiceTime::Tt(label(0, 0, 0))
+        );
+        assert_eq!(
+            error("JD 2451545 JAN").kind,
+            TimeParseErrorKind::UnexpectedToken
+        );
+        assert_eq!(
+            error("JD 999999999999.999999999999999 TDB"),
+            TimeParseError::new(
+                0,
+                TimeParseErrorKind::Conversion(ConversionError::OutOfRange)
+            )
+        );
+        assert_eq!(
+            error("-2451545.0 JD TT"),
+            TimeParseError::new(0, TimeParseErrorKind::UnexpectedToken)
+        );
+        assert_eq!(
+            error("JD -2451545.0 TT"),
+            TimeParseError::new(3, TimeParseErrorKind::UnexpectedToken)
+        );
+        assert_eq!(
+            error("-2024 JAN 15"),
+            TimeParseError::new(0, TimeParseErrorKind::UnexpectedToken)
+        );
+    }
+
+    #[test]
+    fn zones_eras_and_the_julian_calendar() {
+        assert_eq!(
+            SpiceTime::parse("@2024-01-01 UTC+5").unwrap(),
+            SpiceTime::Utc(label(60_309, 68_400, 0))
+        );
+        assert_eq!(
+            SpiceTime::parse("2024-01-01 05:30 UTC+05:30").unwrap(),
+            SpiceTime::Utc(label(60_310, 0, 0))
+        );
+        // 44 B.C. is astronomical year −43; March 15 on the Julian calendar.
+        let ides = SpiceTime::parse("MAR 15, 44 B.C. TT").unwrap();
+        let jd = SpiceTime::parse("JD 1705425.5 TT").unwrap();
+        assert_eq!(ides.to_time::<TT>(), jd.to_time::<TT>());
+        assert_eq!(
+            SpiceTime::parse("1582 OCT 4 TT")
+                .unwrap()
+                .to_time::<TT>()
+                .unwrap(),
+            SpiceTime::parse("1582 OCT 15 TT")
+                .unwrap()
+                .to_time::<TT>()
+                .unwrap()
+                .try_sub_exact(ExactDuration::from_nanos(NANOS_PER_DAY))
+                .unwrap()
+        );
+        assert_eq!(error("1582 OCT 10 TT").position, 9);
+        assert_eq!(
+            error("2024-01-01 TDB+5"),
+            TimeParseError::new(14, TimeParseErrorKind::UnexpectedToken)
+        );
+    }
+
+    #[test]
+    fn leap_seconds_and_zone_shifted_leap_seconds() {
+        let leap = SpiceTime::Utc(label(57_753, 86_400, 250_000_000));
+        assert_eq!(SpiceTime::parse("2016 DEC 31 23:59:60.25").unwrap(), leap);
+        assert_eq!(
+            SpiceTime::parse("2017 JAN 1 04:59:60.25 UTC+5").unwrap(),
+            leap
+        );
+        let err = error("2016 DEC 31 12:59:60");
+        assert_eq!(
+            (err.position, err.kind),
+            (
+                18,
+                TimeParseErrorKind::Conversion(ConversionError::InvalidLeapSecond)
+            )
+        );
+        let err = error("2015 DEC 31 23:59:60");
+        assert_eq!(
+            (err.position, err.kind),
+            (
+                18,
+                TimeParseErrorKind::Conversion(ConversionError::InvalidLeapSecond)
+            )
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_token() {
+        assert_eq!(
+            error("2024 FOO 15"),
+            TimeParseError::new(5, TimeParseErrorKind::UnknownWord)
+        );
+        assert_eq!(
+            error("2024 JAN 32"),
+            TimeParseError::new(9, TimeParseErrorKind::InvalidField)
+        );
+        assert_eq!(
+            error("2024 JAN 15 25:00"),
+            TimeParseError::new(12, TimeParseErrorKind::InvalidField)
+        );
+        assert_eq!(
+            error("2024 JAN"),
+            TimeParseError::new(8, TimeParseErrorKind::MissingField)
+        );
+        assert_eq!(
+            error("2024 JAN 15 TDB UTC"),
+            TimeParseError::new(16, TimeParseErrorKind::UnexpectedToken)
+        );
+        assert_eq!(
+            error("2024 JAN 15 #"),
+            TimeParseError::new(12, TimeParseErrorKind::UnexpectedCharacter)
+        );
+        assert_eq!(
+            error("2024 JAN 15 12:00:00 5"),
+            TimeParseError::new(21, TimeParseErrorKind::UnexpectedToken)
+        );
+    }
+
+    #[test]
+    fn typed_parse_converts_scales() {
+        let tdb = Time::<TDB>::parse_spice("2000 JAN 01 12:00:00 TT").unwrap();
+        assert_eq!(tdb, label::<TT>(51_544, 43_200, 0).to::<TDB>());
+        let utc = Time::<UTC>::parse_spice("2017 JAN 1 00:00:37 TAI").unwrap();
+        assert_eq!(utc, label::<UTC>(57_754, 0, 0));
+        assert_eq!(
+            SpiceTime::parse("2000-01-01 TAI").unwrap().scale_name(),
+            "TAI"
+        );
+        // A conversion beyond the UT1 horizon points at the date.
+        let horizon = TimeParseError::new(
+            2,
+            TimeParseErrorKind::Conversion(ConversionError::Ut1HorizonExceeded),
+        );
+        assert_eq!(
+            Time::<crate::UT1>::parse_spice("  9000-01-01 TT"),
+            Err(horizon)
+        );
+        assert_eq!(
+            Time::<crate::UT1>::parse_spice("@ JDTDB 9999999.5"),
+            Err(horizon)
+        );
+    }
+}
@@ -0,0 +1,304 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! TAI64, TAI64N, and TAI64NA labels (D. J. Bernstein, `libtai`).
+//!
+//! A TAI64 label is the 64-bit integer `2⁶² + s`, where `s` is the number of
+//! TAI seconds since 1970-01-01T00:00:00 TAI, stored big-endian in 8 bytes.
+//! TAI64N appends a 4-byte big-endian nanosecond field (12 bytes) and TAI64NA
+//! a further 4-byte attosecond field (16 bytes). daemontools and s6 print
+//! TAI64N labels as `@` followed by 24 lowercase hex digits.
+//!
+//! Labels are defined on TAI, so they map onto [`Time<TAI>`] with integer
+//! arithmetic. [`Time<UTC>`] shares that storage axis; civil UTC labels reach
+//! a TAI64 label through the active UTC-TAI table when the `Time<UTC>` is
+//! built (for example via [`crate::MjdSod`] or `Time::<UTC>::from_chrono`).
+//!
+//! Encoding is exact to the nanosecond: the seconds and nanosecond fields
+//! come from [`Time::to_exact_j2000`]. Label precision stops there. `Time`
+//! storage resolves only about 10⁻¹⁶ s, so decoded attoseconds are kept
+//! approximately and encoding always writes a zero attosecond field.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{Tai64Kind, Tai64Label, Time, TAI};
+//!
+//! let label = Tai64Label::from_hex("@4000000065a7c3b20f4240a8").unwrap();
+//! let t = Time::<TAI>::from_tai64_label(label).unwrap();
+//! assert_eq!(t.to_tai64_label().unwrap().to_hex(Tai64Kind::Tai64N), "@4000000065a7c3b20f4240a8");
+//! ```
+
+use crate::foundation::duration::NANOS_PER_SECOND;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::{TAI, UTC};
+use crate::model::time::Time;
+
+/// Label value of 1970-01-01T00:00:00 TAI (`2⁶²`).
+const TAI64_EPOCH_LABEL: i128 = 1 << 62;
+/// J2000 coordinate of 1970-01-01T00:00:00 TAI on the TAI axis.
+const TAI64_EPOCH_J2000_SECONDS: i128 = -946_728_000;
+/// Labels with the top bit set are reserved.
+const TAI64_LABEL_LIMIT: u64 = 1 << 63;
+const SUBSECOND_LIMIT: u32 = 1_000_000_000;
+
+/// Encoded width of a TAI64 label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tai64Kind {
+    /// 8 bytes: whole seconds only.
+    Tai64,
+    /// 12 bytes: seconds plus nanoseconds.
+    Tai64N,
+    /// 16 bytes: seconds, nanoseconds, and attoseconds.
+    Tai64NA,
+}
+
+impl Tai64Kind {
+    /// Encoded length in bytes.
+    pub const fn byte_len(self) -> usize {
+        match self {
+            Self::Tai64 => 8,
+            Self::Tai64N => 12,
+            Self::Tai64NA => 16,
+        }
+    }
+}
+
+/// Decoded TAI64NA label (narrower kinds leave the finer fields at zero).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Tai64Label {
+    /// Raw 64-bit label, `2⁶² + TAI seconds since 1970-01-01T00:00:00 TAI`.
+    pub seconds: u64,
+    /// Nanoseconds within the second, in `[0, 10⁹)`.
+    pub nanoseconds: u32,
+    /// Attoseconds within the nanosecond, in `[0, 10⁹)`.
+    pub attoseconds: u32,
+}
+
+impl Tai64Label {
+    /// Construct, validating the field ranges.
+    ///
+    /// Labels at or above `2⁶³` are reserved by the specification and return
+    /// [`ConversionError::OutOfRange`].
+    pub const fn new(
+        seconds: u64,
+        nanoseconds: u32,
+        attoseconds: u32,
+    ) -> Result<Self, ConversionError> {
+        if seconds >= TAI64_LABEL_LIMIT
+            || nanoseconds >= SUBSECOND_LIMIT
+            || attoseconds >= SUBSECOND_LIMIT
+        {
+            return Err(ConversionError::OutOfRange);
+        }
+        Ok(Self {
+            seconds,
+            nanoseconds,
+            attoseconds,
+        })
+    }
+
+    /// Decode an 8-, 12-, or 16-byte big-endian label.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ConversionError> {
+        let field = |range: core::ops::Range<usize>| -> u64 {
+            bytes[range]
+                .iter()
+                .fold(0_u64, |acc, &b| (acc << 8) | u64::from(b))
+        };
+        let (nanoseconds, attoseconds) = match bytes.len() {
+            8 => (0, 0),
+            12 => (field(8..12) as u32, 0),
+            16 => (field(8..12) as u32, field(12..16) as u32),
+            _ => return Err(ConversionError::OutOfRange),
+        };
+        Self::new(field(0..8), nanoseconds, attoseconds)
+    }
+
+    /// Big-endian encoding of the requested width.
+    ///
+    /// Narrower kinds drop the finer fields (they are not rounded).
+    pub fn to_bytes(&self, kind: Tai64Kind) -> Vec<u8> {
+        let mut out = Vec::with_capacity(kind.byte_len());
+        out.extend_from_slice(&self.seconds.to_be_bytes());
+        if kind != Tai64Kind::Tai64 {
+            out.extend_from_slice(&self.nanoseconds.to_be_bytes());
+        }
+        if kind == Tai64Kind::Tai64NA {
+            out.extend_from_slice(&self.attoseconds.to_be_bytes());
+        }
+        out
+    }
+
+    /// Parse the hex form (16, 24, or 32 hex digits), with or without the
+    /// leading `@` written by daemontools/s6.
+    pub fn from_hex(text: &str) -> Result<Self, ConversionError> {
+        let digits = text.strip_prefix('@').unwrap_or(text).as_bytes();
+        if !digits.len().is_multiple_of(2) {
+            return Err(ConversionError::OutOfRange);
+        }
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| {
+                let hi = (pair[0] as char).to_digit(16)?;
+                let lo = (pair[1] as char).to_digit(16)?;
+                Some((hi * 16 + lo) as u8)
+            })
+            .collect::<Option<Vec<u8>>>()
+            .ok_or(ConversionError::OutOfRange)?;
+        Self::from_bytes(&bytes)
+    }
+
+    /// `@`-prefixed lowercase hex form of the requested width.
+    pub fn to_hex(&self, kind: Tai64Kind) -> String {
+        let mut out = String::with_capacity(1 + 2 * kind.byte_len());
+        out.push('@');
+        for byte in self.to_bytes(kind) {
+            out.push_str(&format!("{byte:02x}"));
+        }
+        out
+    }
+}
+
+impl Time<TAI> {
+    /// Encode as a TAI64NA label (use [`Tai64Label::to_bytes`] /
+    /// [`Tai64Label::to_hex`] to pick the width).
+    ///
+    /// The instant is rounded to the nearest nanosecond and the attosecond
+    /// field is zero. Returns [`ConversionError::OutOfRange`] outside the label range and
+    /// [`ConversionError::NonFinite`] for non-finite instants.
+    pub fn to_tai64_label(self) -> Result<Tai64Label, ConversionError> {
+        let nanos = self.to_exact_j2000()?.as_nanos_i128();
+        let whole = nanos.div_euclid(NANOS_PER_SECOND);
+        let label = whole - TAI64_EPOCH_J2000_SECONDS + TAI64_EPOCH_LABEL;
+        let seconds = u64::try_from(label).map_err(|_| ConversionError::OutOfRange)?;
+        Tai64Label::new(seconds, nanos.rem_euclid(NANOS_PER_SECOND) as u32, 0)
+    }
+
+    /// Decode a TAI64 / TAI64N / TAI64NA label.
+    ///
+    /// Attoseconds are added to the stored fraction of a second, which
+    /// resolves about 10⁻¹⁶ s.
+    pub fn from_tai64_label(label: Tai64Label) -> Result<Self, ConversionError> {
+        let label = Tai64Label::new(label.seconds, label.nanoseconds, label.attoseconds)?;
+        let whole = label.seconds as i128 - TAI64_EPOCH_LABEL + TAI64_EPOCH_J2000_SECONDS;
+        let fraction = label.nanoseconds as f64 * 1e-9 + label.attoseconds as f64 * 1e-18;
+        Self::try_from_j2000_whole_and_fraction(whole as i64, fraction)
+    }
+}
+
+impl Time<UTC> {
+    /// Encode as a TAI64NA label. See [`Time::<TAI>::to_tai64_label`].
+    pub fn to_tai64_label(self) -> Result<Tai64Label, ConversionError> {
+        self.to::<TAI>().to_tai64_label()
+    }
+
+    /// Decode a TAI64 / TAI64N / TAI64NA label into the UTC axis.
+    pub fn from_tai64_label(label: Tai64Label) -> Result<Self, ConversionError> {
+        Ok(Time::<TAI>::from_tai64_label(label)?.to::<UTC>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ExactDuration, MjdSod};
+
+    fn utc_label(mjd: i64, second_of_day: i128, nanos: i128) -> Time<UTC> {
+        let value = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(second_of_day * 1_000_000_000 + nanos),
+        )
+        .unwrap();
+        Time::<UTC>::from_mjd_sod(value).unwrap()
+    }
+
+    #[test]
+    fn epoch_and_reference_labels() {
+        let epoch = Time::<TAI>::from_tai64_label(Tai64Label::new(1 << 62, 0, 0).unwrap()).unwrap();
+        assert_eq!(epoch.raw_seconds_pair().0.value(), -946_728_000.0);
+        // djb's example: 400000002a2b2c2d is 1992-06-02 08:07:09 TAI,
+        // i.e. 08:06:43 UTC (TAI − UTC = 26 s).
+        let utc = utc_label(48_775, 8 * 3_600 + 6 * 60 + 43, 0);
+        let label = utc.to_tai64_label().unwrap();
+        assert_eq!(label.seconds, 0x4000_0000_2a2b_2c2d);
+        assert_eq!(label.to_hex(Tai64Kind::Tai64), "@400000002a2b2c2d");
+    }
+
+    #[test]
+    fn nanosecond_field_is_exact() {
+        let t = utc_label(60_475, 45_296, 123_456_789);
+        let label = t.to_tai64_label().unwrap();
+        assert_eq!(label.nanoseconds, 123_456_789);
+        assert_eq!(label.attoseconds, 0);
+        let bytes = label.to_bytes(Tai64Kind::Tai64N);
+        assert_eq!(bytes.len(), 12);
+        let back = Time::<UTC>::from_tai64_label(Tai64Label::from_bytes(&bytes).unwrap()).unwrap();
+        assert_eq!(back.to_exact_j2000(), t.to_exact_j2000());
+    }
+
+    #[test]
+    fn leap_second_has_its_own_label() {
+        let before = utc_label(57_753, 86_399, 500_000_000)
+            .to_tai64_label()
+            .unwrap();
+        let leap = utc_label(57_753, 86_400, 500_000_000)
+            .to_tai64_label()
+            .unwrap();
+        let after = utc_label(57_754, 0, 500_000_000).to_tai64_label().unwrap();
+        assert_eq!(leap.seconds, before.seconds + 1);
+        assert_eq!(after.seconds, leap.seconds + 1);
+        assert_eq!(leap.nanoseconds, 500_000_000);
+    }
+
+    #[test]
+    fn attosecond_round_trip_through_sixteen_bytes() {
+        let label = Tai64Label::new(0x4000_0000_6000_0000, 1, 250_000_000).unwrap();
+        let bytes = label.to_bytes(Tai64Kind::Tai64NA);
+        assert_eq!(Tai64Label::from_bytes(&bytes).unwrap(), label);
+        // Encoding rounds to the nanosecond and drops the attoseconds.
+        let t = Time::<TAI>::from_tai64_label(label).unwrap();
+        assert_eq!(t.to_tai64_label(), Tai64Label::new(label.seconds, 1, 0));
+        let t =
+            Time::<TAI>::from_tai64_label(Tai64Label::new(label.seconds, 1, 600_000_000).unwrap())
+                .unwrap();
+        assert_eq!(t.to_tai64_label(), Tai64Label::new(label.seconds, 2, 0));
+    }
+
+    #[test]
+    fn nanosecond_labels_round_trip_exactly() {
+        for (seconds, nanoseconds) in [
+            (0x4000_0000_0000_0000, 0),
+            (0x4000_0000_6000_0000, 1),
+            (0x4000_0000_6000_0000, 999_999_999),
+            (0x3fff_ffff_0000_0000, 123_456_789),
+            (0x4000_0001_0000_0000, 500_000_000),
+        ] {
+            let label = Tai64Label::new(seconds, nanoseconds, 0).unwrap();
+            let t = Time::<TAI>::from_tai64_label(label).unwrap();
+            assert_eq!(t.to_tai64_label(), Ok(label));
+        }
+    }
+
+    #[test]
+    fn hex_parsing_accepts_daemontools_form() {
+        let plain = Tai64Label::from_hex("4000000065A7C3B20F4240A8").unwrap();
+        let at = Tai64Label::from_hex("@4000000065a7c3b20f4240a8").unwrap();
+        assert_eq!(plain, at);
+        assert_eq!(at.nanoseconds, 0x0f42_40a8);
+        assert_eq!(
+            at.to_hex(Tai64Kind::Tai64NA),
+            "@4000000065a7c3b20f4240a800000000"
+        );
+    }
+
+    #[test]
+    fn malformed_labels_are_rejected() {
+        for text in ["", "@4000", "@400000002a2b2c2g", "@400000002a2b2c2d0"] {
+            assert_eq!(Tai64Label::from_hex(text), Err(ConversionError::OutOfRange));
+        }
+        // Reserved top bit and out-of-range subsecond fields.
+        assert!(Tai64Label::from_hex("@8000000000000000").is_err());
+        assert!(Tai64Label::new(1 << 62, 1_000_000_000, 0).is_err());
+        assert!(Tai64Label::from_bytes(&[0; 9]).is_err());
+    }
+}
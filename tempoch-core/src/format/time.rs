@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! `time` crate interop for `Time<UTC>`.
+//!
+//! [`time::OffsetDateTime`] and [`time::UtcDateTime`] convert through the
+//! civil label ([`CivilDateTime`]), so nanoseconds are kept exactly. UTC
+//! before 1961 follows the `chrono` bridge: it is
+//! [`ConversionError::UtcBeforeDefinition`] unless the [`TimeContext`] allows
+//! it. `time` cannot label `23:59:60`, so converting a leap-second instant
+//! out is [`ConversionError::InvalidLeapSecond`].
+
+use time::{Date, Month, OffsetDateTime, UtcDateTime};
+
+use crate::earth::context::TimeContext;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::UTC;
+use crate::model::time::Time;
+
+use super::CivilDateTime;
+
+fn civil_from_parts(date: Date, clock: time::Time) -> Result<CivilDateTime, ConversionError> {
+    CivilDateTime::new(
+        date.year() as i64,
+        u8::from(date.month()) as u32,
+        date.day() as u32,
+        clock.hour() as u32,
+        clock.minute() as u32,
+        clock.second() as u32,
+        clock.nanosecond(),
+    )
+}
+
+fn utc_datetime_from_civil(civil: CivilDateTime) -> Result<UtcDateTime, ConversionError> {
+    let year = i32::try_from(civil.year).map_err(|_| ConversionError::OutOfRange)?;
+    let month = Month::try_from(civil.month as u8).map_err(|_| ConversionError::OutOfRange)?;
+    let date = Date::from_calendar_date(year, month, civil.day as u8)
+        .map_err(|_| ConversionError::OutOfRange)?;
+    let clock = time::Time::from_hms_nano(
+        civil.hour as u8,
+        civil.minute as u8,
+        civil.second as u8,
+        civil.nanosecond,
+    )
+    .map_err(|_| ConversionError::OutOfRange)?;
+    Ok(UtcDateTime::new(date, clock))
+}
+
+impl Time<UTC> {
+    /// Build a UTC instant from a `time::OffsetDateTime` using the context's
+    /// captured time-data bundle.
+    pub fn try_from_offset_datetime_with(
+        dt: OffsetDateTime,
+        ctx: &TimeContext,
+    ) -> Result<Self, ConversionError> {
+        let utc = dt
+            .checked_to_offset(time::UtcOffset::UTC)
+            .ok_or(ConversionError::OutOfRange)?;
+        Self::from_civil_with(civil_from_parts(utc.date(), utc.time())?, ctx)
+    }
+
+    /// Build a UTC instant from a `time::OffsetDateTime`.
+    ///
+    /// Snapshots the active time-data bundle at call time via
+    /// [`TimeContext::new`].
+    #[inline]
+    pub fn try_from_offset_datetime(dt: OffsetDateTime) -> Result<Self, ConversionError> {
+        Self::try_from_offset_datetime_with(dt, &TimeContext::new())
+    }
+
+    /// Convert to a `time::OffsetDateTime` at `offset`, using the context's
+    /// captured time-data bundle.
+    pub fn try_to_offset_datetime_with(
+        self,
+        offset: time::UtcOffset,
+        ctx: &TimeContext,
+    ) -> Result<OffsetDateTime, ConversionError> {
+        utc_datetime_from_civil(self.civil_without_leap_with(ctx)?)?
+            .checked_to_offset(offset)
+            .ok_or(ConversionError::OutOfRange)
+    }
+
+    /// Convert to a `time::OffsetDateTime` at `offset`.
+    ///
+    /// Snapshots the active time-data bundle at call time via
+    /// [`TimeContext::new`].
+    #[inline]
+    pub fn try_to_offset_datetime(
+        self,
+        offset: time::UtcOffset,
+    ) -> Result<OffsetDateTime, ConversionError> {
+        self.try_to_offset_datetime_with(offset, &TimeContext::new())
+    }
+}
+
+impl TryFrom<OffsetDateTime> for Time<UTC> {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: OffsetDateTime) -> Result<Self, Self::Error> {
+        Self::try_from_offset_datetime(value)
+    }
+}
+
+impl TryFrom<UtcDateTime> for Time<UTC> {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: UtcDateTime) -> Result<Self, Self::Error> {
+        Self::from_civil(civil_from_parts(value.date(), value.time())?)
+    }
+}
+
+impl TryFrom<Time<UTC>> for OffsetDateTime {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Time<UTC>) -> Result<Self, Self::Error> {
+        value.try_to_offset_datetime(time::UtcOffset::UTC)
+    }
+}
+
+impl TryFrom<Time<UTC>> for UtcDateTime {
+    type Error = ConversionError;
+
+    #[inline]
+    fn try_from(value: Time<UTC>) -> Result<Self, Self::Error> {
+        utc_datetime_from_civil(value.civil_without_leap_with(&TimeContext::new())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundation::duration::ExactDuration;
+
+    #[test]
+    fn offset_datetimes_round_trip_with_nanoseconds() {
+        let expected = Time::<UTC>::parse_rfc3339("2024-06-14T12:34:56.123456789Z").unwrap();
+        let offset = time::UtcOffset::from_hms(5, 30, 0).unwrap();
+        let dt = expected.try_to_offset_datetime(offset).unwrap();
+        assert_eq!(
+            (dt.hour(), dt.minute(), dt.nanosecond()),
+            (18, 4, 123_456_789)
+        );
+        assert_eq!(Time::<UTC>::try_from(dt).unwrap(), expected);
+
+        let utc = UtcDateTime::try_from(expected).unwrap();
+        assert_eq!(utc.unix_timestamp(), 1_718_368_496);
+        assert_eq!(Time::<UTC>::try_from(utc).unwrap(), expected);
+    }
+
+    #[test]
+    fn leap_seconds_and_pre_1961_follow_the_chrono_policy() {
+        let leap = Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60.5Z").unwrap();
+        assert_eq!(
+            OffsetDateTime::try_from(leap),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+        let after = leap.try_add_exact(ExactDuration::SECOND).unwrap();
+        assert_eq!(
+            OffsetDateTime::try_from(after).unwrap().unix_timestamp(),
+            1_483_228_800
+        );
+
+        let dt = OffsetDateTime::from_unix_timestamp(-631_152_000).unwrap();
+        assert_eq!(
+            Time::<UTC>::try_from(dt),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+        let ctx = TimeContext::new().allow_pre_definition_utc();
+        let utc = Time::<UTC>::try_from_offset_datetime_with(dt, &ctx).unwrap();
+        assert_eq!(
+            utc.try_to_offset_datetime_with(time::UtcOffset::UTC, &ctx),
+            Ok(dt)
+        );
+    }
+
+    #[test]
+    fn offset_datetimes_move_the_local_date_not_the_instant() {
+        // 20:00 at -09:30 on the 14th is 05:30 UTC on the 15th.
+        let offset = time::UtcOffset::from_hms(-9, -30, 0).unwrap();
+        let date = Date::from_calendar_date(2024, Month::June, 14).unwrap();
+        let clock = time::Time::from_hms_nano(20, 0, 0, 1).unwrap();
+        let dt = time::PrimitiveDateTime::new(date, clock).assume_offset(offset);
+        let expected = Time::<UTC>::parse_rfc3339("2024-06-15T05:30:00.000000001Z").unwrap();
+        assert_eq!(Time::<UTC>::try_from(dt), Ok(expected));
+        let back = expected.try_to_offset_datetime(offset).unwrap();
+        assert_eq!(
+            (back.date(), back.time(), back.offset()),
+            (date, clock, offset)
+        );
+
+        // East of Greenwich the local date runs a day ahead of UTC.
+        let nepal = time::UtcOffset::from_hms(5, 45, 0).unwrap();
+        let late = Time::<UTC>::parse_rfc3339("2024-12-31T18:20:00Z").unwrap();
+        let dt = late.try_to_offset_datetime(nepal).unwrap();
+        assert_eq!(
+            dt.date(),
+            Date::from_calendar_date(2025, Month::January, 1).unwrap()
+        );
+        assert_eq!((dt.hour(), dt.minute()), (0, 5));
+        assert_eq!(Time::<UTC>::try_from(dt), Ok(late));
+
+        let leap = Time::<UTC>::parse_rfc3339("2016-12-31T23:59:60Z").unwrap();
+        assert_eq!(
+            leap.try_to_offset_datetime(time::UtcOffset::from_hms(9, 0, 0).unwrap()),
+            Err(ConversionError::InvalidLeapSecond)
+        );
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn pre_1961_offset_labels_match_the_chrono_bridge() {
+        // 1950-12-31T22:00-05:00 is 1951-01-01T03:00Z.
+        let offset = time::UtcOffset::from_hms(-5, 0, 0).unwrap();
+        let dt = time::PrimitiveDateTime::new(
+            Date::from_calendar_date(1950, Month::December, 31).unwrap(),
+            time::Time::from_hms(22, 0, 0).unwrap(),
+        )
+        .assume_offset(offset);
+        let chrono_dt =
+            ::chrono::DateTime::<::chrono::Utc>::from_timestamp(-599_605_200, 0).unwrap();
+        assert_eq!(
+            Time::<UTC>::try_from(dt),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+        assert_eq!(
+            Time::<UTC>::try_from_chrono(chrono_dt).map(|_| ()),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+
+        let ctx = TimeContext::new().allow_pre_definition_utc();
+        let utc = Time::<UTC>::try_from_offset_datetime_with(dt, &ctx).unwrap();
+        let via_chrono = Time::<UTC>::try_from_chrono_with(chrono_dt, &ctx).unwrap();
+        // The chrono bridge works in `f64` seconds: it agrees on the way in
+        // to well under 1 µs, and on the way out to the 50 µs it is tested to.
+        let nanos = |t: Time<UTC>| t.to_exact_j2000().unwrap().as_nanos_i128();
+        assert!((nanos(utc) - nanos(via_chrono)).abs() < 1_000);
+        let out = utc.try_to_chrono_with(&ctx).unwrap() - chrono_dt;
+        assert!(out.num_microseconds().unwrap().abs() < 50);
+        let back = utc.try_to_offset_datetime_with(offset, &ctx).unwrap();
+        assert_eq!((back, back.offset()), (dt, offset));
+        assert_eq!(
+            utc.try_to_offset_datetime(offset),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+        assert_eq!(
+            UtcDateTime::try_from(utc),
+            Err(ConversionError::UtcBeforeDefinition)
+        );
+    }
+}
@@ -18,4 +18,4 @@ pub trait TimeFormat: Sealed + Copy + Clone + fmt::Debug + 'static {
 }
 
 #[allow(unused_imports)]
-pub use crate::format::markers::{J2000s, Unix, GPS, JD, MJD};
+pub use crate::format::markers::{J2000s, Unix, UnixRight, GPS, JD, MJD};
@@ -0,0 +1,1789 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Broadcast time codes: IRIG-B and the DCF77, WWVB, and MSF minute frames.
+//!
+//! Each frame type holds the decoded fields and converts to and from the
+//! symbols on the wire:
+//!
+//! | Frame          | Labels             | Period | Wire form                       |
+//! |----------------|--------------------|--------|---------------------------------|
+//! | [`IrigBFrame`] | UTC, this second   | 1 s    | 100 [`PulseSymbol`]s            |
+//! | [`Dcf77Frame`] | CET/CEST, next min | 1 min  | 59 bits (`u64`, bit *n* = second *n*) |
+//! | [`WwvbFrame`]  | UTC, this minute   | 1 min  | 60 [`PulseSymbol`]s             |
+//! | [`MsfFrame`]   | GMT/BST, next min  | 1 min  | A and B bit pairs (`u64`s)      |
+//!
+//! DCF77 and MSF transmit the label of the minute that starts at the *next*
+//! minute marker; the `Time<UTC>` conversions here always refer to the
+//! labelled minute itself. Two-digit years are read as 2000–2099.
+//!
+//! Encoders fill the status bits from the active tables: leap-second
+//! warnings from the UTC-TAI table (IEEE 1344 `LSP`/`LS` in the last minute
+//! before the leap, DCF77 `A2` in the last hour, WWVB bit 56 for the whole
+//! month), DUT1 from [`TimeContext::ut1_minus_utc`] rounded to 0.1 s, and
+//! the EU (DCF77, MSF) and US (WWVB) daylight-saving rules. DCF77 weather
+//! bits and the IRIG-B local-offset fields are not modelled.
+//!
+//! # Examples
+//!
+//! ```
+//! use tempoch_core::{Dcf77Frame, IrigBFrame, Time, UTC};
+//! use tempoch_core::format::MjdSod;
+//! use tempoch_core::ExactDuration;
+//!
+//! // 2016-12-31T23:59:60 UTC, the last leap second.
+//! let label = MjdSod::new(
+//!     qtty::i64::Day::new(57_753),
+//!     ExactDuration::from_nanos(86_400_000_000_000),
+//! )
+//! .unwrap();
+//! let leap = Time::<UTC>::from_mjd_sod(label).unwrap();
+//!
+//! let irig = leap.to_irig_b().unwrap();
+//! assert_eq!((irig.hour, irig.minute, irig.second), (23, 59, 60));
+//! assert!(irig.control.leap_second_pending);
+//! let symbols = irig.to_symbols().unwrap();
+//! assert_eq!(IrigBFrame::from_symbols(&symbols).unwrap(), irig);
+//!
+//! let dcf = leap.to_dcf77().unwrap();
+//! assert!(dcf.leap_second_announcement);
+//! assert_eq!((dcf.hour, dcf.minute), (0, 59)); // CET
+//! assert_eq!(Dcf77Frame::from_bits(dcf.to_bits().unwrap()).unwrap(), dcf);
+//! ```
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::UTC;
+use crate::model::time::Time;
+
+use super::iso::time_from_clock;
+use super::ntp::{day_leap_indicator, LeapIndicator};
+
+const MINUTES_PER_DAY: i64 = 1_440;
+
+/// Error returned by the time-code frame decoders and encoders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeCodeError {
+    /// Position markers, fixed bits, or BCD digits do not match the format,
+    /// or a frame field does not fit its wire encoding.
+    InvalidFrame,
+    /// A parity bit does not match its data bits.
+    ParityMismatch,
+    /// The frame fields do not label a valid instant, or the instant cannot
+    /// be expressed in the frame (year outside 2000–2099, DUT1 unavailable).
+    Conversion(ConversionError),
+}
+
+impl core::fmt::Display for TimeCodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidFrame => f.write_str("malformed time-code frame"),
+            Self::ParityMismatch => f.write_str("time-code parity mismatch"),
+            Self::Conversion(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for TimeCodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<ConversionError> for TimeCodeError {
+    fn from(err: ConversionError) -> Self {
+        Self::Conversion(err)
+    }
+}
+
+/// Pulse-width symbol of the IRIG-B and WWVB codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PulseSymbol {
+    /// Binary 0.
+    Zero,
+    /// Binary 1.
+    One,
+    /// Position or frame marker.
+    Marker,
+}
+
+// ── Bit-field helpers ────────────────────────────────────────────────────
+
+/// `(bit position, weight)` pairs of a BCD or binary field.
+type Field = &'static [(usize, u32)];
+
+/// Set the bits of `field` for `value`, taking the largest weights first.
+/// Returns `None`, with the bits only partly set, when no subset of the
+/// weights sums to `value` (it is too large for the field).
+fn put(bits: &mut u128, field: Field, value: u32) -> Option<()> {
+    let mut rest = value;
+    let mut weights: Vec<_> = field.to_vec();
+    weights.sort_by_key(|&(_, weight)| core::cmp::Reverse(weight));
+    for (position, weight) in weights {
+        if rest >= weight {
+            rest -= weight;
+            *bits |= 1 << position;
+        }
+    }
+    (rest == 0).then_some(())
+}
+
+/// Read `field`, rejecting non-canonical encodings (BCD digits above 9).
+fn get(bits: u128, field: Field) -> Result<u32, TimeCodeError> {
+    let value = field
+        .iter()
+        .filter(|(position, _)| bits >> position & 1 == 1)
+        .map(|(_, weight)| weight)
+        .sum();
+    let mask = field
+        .iter()
+        .fold(0_u128, |mask, (position, _)| mask | 1 << position);
+    let mut canonical = 0;
+    match put(&mut canonical, field, value) {
+        Some(()) if canonical == bits & mask => Ok(value),
+        _ => Err(TimeCodeError::InvalidFrame),
+    }
+}
+
+fn bit(bits: u128, position: usize) -> bool {
+    bits >> position & 1 == 1
+}
+
+/// [`put`] for an encoder: a value that does not fit is
+/// [`TimeCodeError::InvalidFrame`].
+fn encode(bits: &mut u128, field: Field, value: u32) -> Result<(), TimeCodeError> {
+    put(bits, field, value).ok_or(TimeCodeError::InvalidFrame)
+}
+
+/// Number of set bits in `range`.
+fn ones(bits: u128, range: core::ops::RangeInclusive<usize>) -> u32 {
+    range.filter(|&position| bit(bits, position)).count() as u32
+}
+
+fn to_symbols<const N: usize>(bits: u128, markers: &[usize]) -> [PulseSymbol; N] {
+    core::array::from_fn(|position| {
+        if markers.contains(&position) {
+            PulseSymbol::Marker
+        } else if bit(bits, position) {
+            PulseSymbol::One
+        } else {
+            PulseSymbol::Zero
+        }
+    })
+}
+
+fn from_symbols(symbols: &[PulseSymbol], markers: &[usize]) -> Result<u128, TimeCodeError> {
+    let mut bits = 0;
+    for (position, &symbol) in symbols.iter().enumerate() {
+        match (symbol, markers.contains(&position)) {
+            (PulseSymbol::Marker, true) | (PulseSymbol::Zero, false) => {}
+            (PulseSymbol::One, false) => bits |= 1 << position,
+            _ => return Err(TimeCodeError::InvalidFrame),
+        }
+    }
+    Ok(bits)
+}
+
+// ── Calendar helpers ─────────────────────────────────────────────────────
+
+/// ISO weekday (Monday = 1 … Sunday = 7) of `mjd`; MJD 0 was a Wednesday.
+fn iso_weekday(mjd: i64) -> u32 {
+    (mjd + 2).rem_euclid(7) as u32 + 1
+}
+
+/// Two-digit year of `year`, which must lie in 2000–2099.
+fn two_digit_year(year: i64) -> Result<u8, TimeCodeError> {
+    if (2_000..=2_099).contains(&year) {
+        Ok((year - 2_000) as u8)
+    } else {
+        Err(ConversionError::OutOfRange.into())
+    }
+}
+
+fn first_of_month(year: i64, month: u32) -> i64 {
+    let (year, month) = if month > 12 {
+        (year + 1, 1)
+    } else {
+        (year, month)
+    };
+    encoding::mjd_from_gregorian(year, month, 1).expect("the first of a month is a valid date")
+}
+
+fn last_sunday(year: i64, month: u32) -> i64 {
+    let last = first_of_month(year, month + 1) - 1;
+    last - (iso_weekday(last) % 7) as i64
+}
+
+fn nth_sunday(year: i64, month: u32, n: i64) -> i64 {
+    let first = first_of_month(year, month);
+    first + (7 - iso_weekday(first) as i64) % 7 + 7 * (n - 1)
+}
+
+/// EU summer time (last Sunday of March to last Sunday of October, changing
+/// at 01:00 UTC) at UTC minute `minutes` since MJD 0.
+fn eu_summer_time(minutes: i64) -> bool {
+    let (year, _, _) = encoding::gregorian_from_mjd(minutes.div_euclid(MINUTES_PER_DAY));
+    let start = last_sunday(year, 3) * MINUTES_PER_DAY + 60;
+    let end = last_sunday(year, 10) * MINUTES_PER_DAY + 60;
+    (start..end).contains(&minutes)
+}
+
+/// Whether the EU summer-time state changes within `window` minutes after
+/// UTC minute `minutes`.
+fn eu_change_within(minutes: i64, window: i64) -> bool {
+    eu_summer_time(minutes) != eu_summer_time(minutes + window)
+}
+
+/// UTC minute since MJD 0 containing `time`, and whether `time` falls in a
+/// leap second (which belongs to minute 23:59).
+fn utc_minute(time: Time<UTC>, ctx: &TimeContext) -> Result<(i64, i64), TimeCodeError> {
+    let label = time.to_mjd_sod_with(ctx)?;
+    let second_of_day = (label.second_of_day.as_nanos_i128() / 1_000_000_000) as i64;
+    Ok((
+        label.mjd.value(),
+        (second_of_day / 60).min(MINUTES_PER_DAY - 1),
+    ))
+}
+
+/// Start of UTC minute `minutes` since MJD 0.
+fn minute_start(minutes: i64, ctx: &TimeContext) -> Result<Time<UTC>, TimeCodeError> {
+    let mjd = minutes.div_euclid(MINUTES_PER_DAY);
+    let minute = minutes.rem_euclid(MINUTES_PER_DAY) as u32;
+    Ok(time_from_clock(mjd, [minute / 60, minute % 60, 0], 0, ctx)?)
+}
+
+/// DUT1 in tenths of a second for UTC day `mjd`.
+fn dut1_tenths(mjd: i64, ctx: &TimeContext) -> Result<i8, TimeCodeError> {
+    let dut1 = ctx
+        .ut1_minus_utc(qtty::Day::new(mjd as f64))
+        .ok_or(ConversionError::Ut1HorizonExceeded)?;
+    Ok((dut1.value() * 10.0).round().clamp(-9.0, 9.0) as i8)
+}
+
+// ── IRIG-B ───────────────────────────────────────────────────────────────
+
+const IRIG_MARKERS: [usize; 11] = [0, 9, 19, 29, 39, 49, 59, 69, 79, 89, 99];
+const IRIG_SECONDS: Field = &[(1, 1), (2, 2), (3, 4), (4, 8), (6, 10), (7, 20), (8, 40)];
+const IRIG_MINUTES: Field = &[
+    (10, 1),
+    (11, 2),
+    (12, 4),
+    (13, 8),
+    (15, 10),
+    (16, 20),
+    (17, 40),
+];
+const IRIG_HOURS: Field = &[(20, 1), (21, 2), (22, 4), (23, 8), (25, 10), (26, 20)];
+const IRIG_DAYS: Field = &[
+    (30, 1),
+    (31, 2),
+    (32, 4),
+    (33, 8),
+    (35, 10),
+    (36, 20),
+    (37, 40),
+    (38, 80),
+    (40, 100),
+    (41, 200),
+];
+const IRIG_YEAR: Field = &[
+    (50, 1),
+    (51, 2),
+    (52, 4),
+    (53, 8),
+    (55, 10),
+    (56, 20),
+    (57, 40),
+    (58, 80),
+];
+const IRIG_QUALITY: Field = &[(71, 1), (72, 2), (73, 4), (74, 8)];
+const IRIG_SBS: Field = &[
+    (80, 1),
+    (81, 2),
+    (82, 4),
+    (83, 8),
+    (84, 16),
+    (85, 32),
+    (86, 64),
+    (87, 128),
+    (88, 256),
+    (90, 512),
+    (91, 1_024),
+    (92, 2_048),
+    (93, 4_096),
+    (94, 8_192),
+    (95, 16_384),
+    (96, 32_768),
+    (97, 65_536),
+];
+/// IEEE 1344 leap-second pending, leap-second sign, DST pending, DST.
+const IRIG_LSP: usize = 60;
+const IRIG_LS: usize = 61;
+const IRIG_DSP: usize = 62;
+const IRIG_DST: usize = 63;
+/// Local-offset sign, hours, and half-hour bits (must be clear).
+const IRIG_OFFSET: [usize; 6] = [64, 65, 66, 67, 68, 70];
+const IRIG_PARITY: usize = 75;
+
+/// IEEE 1344 / C37.118 control functions carried in IRIG-B bits 60–75.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IrigBControl {
+    /// `LSP`: a leap second occurs at the end of the current minute.
+    pub leap_second_pending: bool,
+    /// `LS`: the pending leap second is negative (deleted).
+    pub leap_second_negative: bool,
+    /// `DSP`: a daylight-saving change is pending.
+    pub dst_pending: bool,
+    /// `DST`: daylight-saving time is in effect.
+    pub dst: bool,
+    /// Time-quality code, 0 (locked) to 15 (failed).
+    pub time_quality: u8,
+}
+
+/// IRIG-B frame labelling one UTC second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IrigBFrame {
+    /// Two-digit year (2000–2099).
+    pub year: u8,
+    /// Day of year, 1–366.
+    pub day_of_year: u16,
+    /// Hour, 0–23.
+    pub hour: u8,
+    /// Minute, 0–59.
+    pub minute: u8,
+    /// Second, 0–60.
+    pub second: u8,
+    /// IEEE 1344 control functions.
+    pub control: IrigBControl,
+    /// Straight binary seconds of day (86 400 during a leap second).
+    pub straight_binary_seconds: u32,
+}
+
+impl IrigBFrame {
+    /// The 100 symbols of the frame, starting with the reference marker.
+    ///
+    /// The parity bit (75) is set for even parity over bits 1–74. Returns
+    /// [`TimeCodeError::InvalidFrame`] if a field does not fit its BCD or
+    /// binary width.
+    pub fn to_symbols(&self) -> Result<[PulseSymbol; 100], TimeCodeError> {
+        let mut bits = 0;
+        let fields = [
+            (IRIG_SECONDS, self.second as u32),
+            (IRIG_MINUTES, self.minute as u32),
+            (IRIG_HOURS, self.hour as u32),
+            (IRIG_DAYS, self.day_of_year as u32),
+            (IRIG_YEAR, self.year as u32),
+            (IRIG_QUALITY, self.control.time_quality as u32),
+            (IRIG_SBS, self.straight_binary_seconds),
+        ];
+        for (field, value) in fields {
+            encode(&mut bits, field, value)?;
+        }
+        for (position, set) in [
+            (IRIG_LSP, self.control.leap_second_pending),
+            (IRIG_LS, self.control.leap_second_negative),
+            (IRIG_DSP, self.control.dst_pending),
+            (IRIG_DST, self.control.dst),
+        ] {
+            bits |= (set as u128) << position;
+        }
+        bits |= ((ones(bits, 1..=74) % 2) as u128) << IRIG_PARITY;
+        Ok(to_symbols(bits, &IRIG_MARKERS))
+    }
+
+    /// Decode 100 symbols starting at the reference marker.
+    ///
+    /// Returns [`TimeCodeError::InvalidFrame`] for misplaced markers, bad
+    /// BCD digits, a non-zero local offset, or straight binary seconds that
+    /// are neither zero nor consistent with the BCD time, and
+    /// [`TimeCodeError::ParityMismatch`] if bit 75 fails even parity.
+    pub fn from_symbols(symbols: &[PulseSymbol; 100]) -> Result<Self, TimeCodeError> {
+        let bits = from_symbols(symbols, &IRIG_MARKERS)?;
+        if IRIG_OFFSET.iter().any(|&position| bit(bits, position)) {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        if !(ones(bits, 1..=74) + bit(bits, IRIG_PARITY) as u32).is_multiple_of(2) {
+            return Err(TimeCodeError::ParityMismatch);
+        }
+        let frame = Self {
+            year: get(bits, IRIG_YEAR)? as u8,
+            day_of_year: get(bits, IRIG_DAYS)? as u16,
+            hour: get(bits, IRIG_HOURS)? as u8,
+            minute: get(bits, IRIG_MINUTES)? as u8,
+            second: get(bits, IRIG_SECONDS)? as u8,
+            control: IrigBControl {
+                leap_second_pending: bit(bits, IRIG_LSP),
+                leap_second_negative: bit(bits, IRIG_LS),
+                dst_pending: bit(bits, IRIG_DSP),
+                dst: bit(bits, IRIG_DST),
+                time_quality: get(bits, IRIG_QUALITY)? as u8,
+            },
+            straight_binary_seconds: get(bits, IRIG_SBS)?,
+        };
+        let clock_seconds =
+            frame.hour as u32 * 3_600 + frame.minute as u32 * 60 + frame.second as u32;
+        if frame.straight_binary_seconds != 0 && frame.straight_binary_seconds != clock_seconds {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        Ok(frame)
+    }
+}
+
+// ── DCF77 ────────────────────────────────────────────────────────────────
+
+const DCF_MINUTE: Field = &[
+    (21, 1),
+    (22, 2),
+    (23, 4),
+    (24, 8),
+    (25, 10),
+    (26, 20),
+    (27, 40),
+];
+const DCF_HOUR: Field = &[(29, 1), (30, 2), (31, 4), (32, 8), (33, 10), (34, 20)];
+const DCF_DAY: Field = &[(36, 1), (37, 2), (38, 4), (39, 8), (40, 10), (41, 20)];
+const DCF_WEEKDAY: Field = &[(42, 1), (43, 2), (44, 4)];
+const DCF_MONTH: Field = &[(45, 1), (46, 2), (47, 4), (48, 8), (49, 10)];
+const DCF_YEAR: Field = &[
+    (50, 1),
+    (51, 2),
+    (52, 4),
+    (53, 8),
+    (54, 10),
+    (55, 20),
+    (56, 40),
+    (57, 80),
+];
+const DCF_CALL: usize = 15;
+const DCF_A1: usize = 16;
+const DCF_CEST: usize = 17;
+const DCF_CET: usize = 18;
+const DCF_A2: usize = 19;
+const DCF_START: usize = 20;
+/// Even-parity bits and the data ranges they cover.
+const DCF_PARITY: [(usize, core::ops::RangeInclusive<usize>); 3] =
+    [(28, 21..=27), (35, 29..=34), (58, 36..=57)];
+
+/// DCF77 minute frame (German legal time, CET/CEST).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dcf77Frame {
+    /// Two-digit year (2000–2099).
+    pub year: u8,
+    /// Month, 1–12.
+    pub month: u8,
+    /// Day of month, 1–31.
+    pub day: u8,
+    /// ISO weekday, Monday = 1 … Sunday = 7.
+    pub weekday: u8,
+    /// Local hour, 0–23.
+    pub hour: u8,
+    /// Local minute, 0–59.
+    pub minute: u8,
+    /// `Z1`: the label is CEST (UTC+2) rather than CET (UTC+1).
+    pub summer_time: bool,
+    /// `A1`: a CET/CEST change occurs within the hour.
+    pub summer_time_announcement: bool,
+    /// `A2`: a leap second occurs within the hour.
+    pub leap_second_announcement: bool,
+    /// `R`: the call bit for abnormal transmitter operation.
+    pub call_bit: bool,
+}
+
+impl Dcf77Frame {
+    /// Bits 0–58 of the frame (bit *n* is second *n*), with parity. Returns
+    /// [`TimeCodeError::InvalidFrame`] if a field does not fit its BCD width.
+    pub fn to_bits(&self) -> Result<u64, TimeCodeError> {
+        let mut bits = 0;
+        for (field, value) in [
+            (DCF_MINUTE, self.minute),
+            (DCF_HOUR, self.hour),
+            (DCF_DAY, self.day),
+            (DCF_WEEKDAY, self.weekday),
+            (DCF_MONTH, self.month),
+            (DCF_YEAR, self.year),
+        ] {
+            encode(&mut bits, field, value as u32)?;
+        }
+        for (position, set) in [
+            (DCF_CALL, self.call_bit),
+            (DCF_A1, self.summer_time_announcement),
+            (DCF_CEST, self.summer_time),
+            (DCF_CET, !self.summer_time),
+            (DCF_A2, self.leap_second_announcement),
+            (DCF_START, true),
+        ] {
+            bits |= (set as u128) << position;
+        }
+        for (parity, range) in DCF_PARITY {
+            bits |= ((ones(bits, range) % 2) as u128) << parity;
+        }
+        Ok(bits as u64)
+    }
+
+    /// Decode bits 0–58 (bit *n* is second *n*; higher bits are ignored).
+    /// Weather bits 1–14 are not interpreted.
+    pub fn from_bits(bits: u64) -> Result<Self, TimeCodeError> {
+        let bits = bits as u128 & ((1 << 59) - 1);
+        if bit(bits, 0) || !bit(bits, DCF_START) || bit(bits, DCF_CEST) == bit(bits, DCF_CET) {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        for (parity, range) in DCF_PARITY {
+            if !(ones(bits, range) + bit(bits, parity) as u32).is_multiple_of(2) {
+                return Err(TimeCodeError::ParityMismatch);
+            }
+        }
+        Ok(Self {
+            year: get(bits, DCF_YEAR)? as u8,
+            month: get(bits, DCF_MONTH)? as u8,
+            day: get(bits, DCF_DAY)? as u8,
+            weekday: get(bits, DCF_WEEKDAY)? as u8,
+            hour: get(bits, DCF_HOUR)? as u8,
+            minute: get(bits, DCF_MINUTE)? as u8,
+            summer_time: bit(bits, DCF_CEST),
+            summer_time_announcement: bit(bits, DCF_A1),
+            leap_second_announcement: bit(bits, DCF_A2),
+            call_bit: bit(bits, DCF_CALL),
+        })
+    }
+}
+
+// ── WWVB ─────────────────────────────────────────────────────────────────
+
+const WWVB_MARKERS: [usize; 7] = [0, 9, 19, 29, 39, 49, 59];
+const WWVB_MINUTE: Field = &[(1, 40), (2, 20), (3, 10), (5, 8), (6, 4), (7, 2), (8, 1)];
+const WWVB_HOUR: Field = &[(12, 20), (13, 10), (15, 8), (16, 4), (17, 2), (18, 1)];
+const WWVB_DAY: Field = &[
+    (22, 200),
+    (23, 100),
+    (25, 80),
+    (26, 40),
+    (27, 20),
+    (28, 10),
+    (30, 8),
+    (31, 4),
+    (32, 2),
+    (33, 1),
+];
+const WWVB_DUT1: Field = &[(40, 8), (41, 4), (42, 2), (43, 1)];
+const WWVB_YEAR: Field = &[
+    (45, 80),
+    (46, 40),
+    (47, 20),
+    (48, 10),
+    (50, 8),
+    (51, 4),
+    (52, 2),
+    (53, 1),
+];
+/// DUT1 sign bits: `101` positive, `010` negative.
+const WWVB_DUT1_SIGN: [usize; 3] = [36, 37, 38];
+const WWVB_LEAP_YEAR: usize = 55;
+const WWVB_LEAP_SECOND: usize = 56;
+const WWVB_DST: [usize; 2] = [57, 58];
+
+/// WWVB daylight-saving status (bits 57–58).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum WwvbDst {
+    /// `00`: standard time all day.
+    #[default]
+    Standard,
+    /// `10`: daylight time begins today.
+    Begins,
+    /// `11`: daylight time all day.
+    InEffect,
+    /// `01`: daylight time ends today.
+    Ends,
+}
+
+/// WWVB minute frame (UTC).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WwvbFrame {
+    /// Two-digit year (2000–2099).
+    pub year: u8,
+    /// Day of year, 1–366.
+    pub day_of_year: u16,
+    /// Hour, 0–23.
+    pub hour: u8,
+    /// Minute, 0–59.
+    pub minute: u8,
+    /// DUT1 in tenths of a second, −9 to 9.
+    pub dut1_tenths: i8,
+    /// The year is a leap year.
+    pub leap_year: bool,
+    /// A leap second occurs at the end of the month.
+    pub leap_second_warning: bool,
+    /// US daylight-saving status for the day.
+    pub dst: WwvbDst,
+}
+
+impl WwvbFrame {
+    /// The 60 symbols of the frame, starting with the frame reference marker.
+    ///
+    /// Returns [`TimeCodeError::InvalidFrame`] if a field does not fit its
+    /// BCD width or |DUT1| exceeds 0.9 s.
+    pub fn to_symbols(&self) -> Result<[PulseSymbol; 60], TimeCodeError> {
+        if self.dut1_tenths.unsigned_abs() > 9 {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        let mut bits = 0;
+        for (field, value) in [
+            (WWVB_MINUTE, self.minute as u32),
+            (WWVB_HOUR, self.hour as u32),
+            (WWVB_DAY, self.day_of_year as u32),
+            (WWVB_DUT1, self.dut1_tenths.unsigned_abs() as u32),
+            (WWVB_YEAR, self.year as u32),
+        ] {
+            encode(&mut bits, field, value)?;
+        }
+        let [plus_a, minus, plus_b] = WWVB_DUT1_SIGN;
+        if self.dut1_tenths < 0 {
+            bits |= 1 << minus;
+        } else {
+            bits |= 1 << plus_a | 1 << plus_b;
+        }
+        let (end_of_day, start_of_day) = match self.dst {
+            WwvbDst::Standard => (false, false),
+            WwvbDst::Begins => (true, false),
+            WwvbDst::InEffect => (true, true),
+            WwvbDst::Ends => (false, true),
+        };
+        for (position, set) in [
+            (WWVB_LEAP_YEAR, self.leap_year),
+            (WWVB_LEAP_SECOND, self.leap_second_warning),
+            (WWVB_DST[0], end_of_day),
+            (WWVB_DST[1], start_of_day),
+        ] {
+            bits |= (set as u128) << position;
+        }
+        Ok(to_symbols(bits, &WWVB_MARKERS))
+    }
+
+    /// Decode 60 symbols starting at the frame reference marker.
+    pub fn from_symbols(symbols: &[PulseSymbol; 60]) -> Result<Self, TimeCodeError> {
+        let bits = from_symbols(symbols, &WWVB_MARKERS)?;
+        let magnitude = get(bits, WWVB_DUT1)? as i8;
+        let dut1_tenths = match WWVB_DUT1_SIGN.map(|position| bit(bits, position)) {
+            [true, false, true] => magnitude,
+            [false, true, false] => -magnitude,
+            _ => return Err(TimeCodeError::InvalidFrame),
+        };
+        if magnitude > 9 {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        let dst = match WWVB_DST.map(|position| bit(bits, position)) {
+            [false, false] => WwvbDst::Standard,
+            [true, false] => WwvbDst::Begins,
+            [true, true] => WwvbDst::InEffect,
+            [false, true] => WwvbDst::Ends,
+        };
+        Ok(Self {
+            year: get(bits, WWVB_YEAR)? as u8,
+            day_of_year: get(bits, WWVB_DAY)? as u16,
+            hour: get(bits, WWVB_HOUR)? as u8,
+            minute: get(bits, WWVB_MINUTE)? as u8,
+            dut1_tenths,
+            leap_year: bit(bits, WWVB_LEAP_YEAR),
+            leap_second_warning: bit(bits, WWVB_LEAP_SECOND),
+            dst,
+        })
+    }
+}
+
+// ── MSF ──────────────────────────────────────────────────────────────────
+
+const MSF_YEAR: Field = &[
+    (17, 80),
+    (18, 40),
+    (19, 20),
+    (20, 10),
+    (21, 8),
+    (22, 4),
+    (23, 2),
+    (24, 1),
+];
+const MSF_MONTH: Field = &[(25, 10), (26, 8), (27, 4), (28, 2), (29, 1)];
+const MSF_DAY: Field = &[(30, 20), (31, 10), (32, 8), (33, 4), (34, 2), (35, 1)];
+const MSF_WEEKDAY: Field = &[(36, 4), (37, 2), (38, 1)];
+const MSF_HOUR: Field = &[(39, 20), (40, 10), (41, 8), (42, 4), (43, 2), (44, 1)];
+const MSF_MINUTE: Field = &[
+    (45, 40),
+    (46, 20),
+    (47, 10),
+    (48, 8),
+    (49, 4),
+    (50, 2),
+    (51, 1),
+];
+/// A-bit minute identifier `01111110` in seconds 52–59.
+const MSF_IDENTIFIER: u128 = 0b0111_1110 << 52;
+const MSF_IDENTIFIER_MASK: u128 = 0xff << 52;
+const MSF_DUT1_POSITIVE: core::ops::RangeInclusive<usize> = 1..=8;
+const MSF_DUT1_NEGATIVE: core::ops::RangeInclusive<usize> = 9..=16;
+const MSF_SUMMER_WARNING: usize = 53;
+/// B-bit odd-parity positions and the A-bit ranges they cover.
+const MSF_PARITY: [(usize, core::ops::RangeInclusive<usize>); 4] =
+    [(54, 17..=24), (55, 25..=35), (56, 36..=38), (57, 39..=51)];
+const MSF_SUMMER_TIME: usize = 58;
+
+/// MSF minute frame (UK civil time, GMT/BST).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsfFrame {
+    /// Two-digit year (2000–2099).
+    pub year: u8,
+    /// Month, 1–12.
+    pub month: u8,
+    /// Day of month, 1–31.
+    pub day: u8,
+    /// Weekday, Sunday = 0 … Saturday = 6.
+    pub weekday: u8,
+    /// Local hour, 0–23.
+    pub hour: u8,
+    /// Local minute, 0–59.
+    pub minute: u8,
+    /// The label is BST (UTC+1) rather than GMT.
+    pub summer_time: bool,
+    /// A GMT/BST change occurs within the next 61 minutes.
+    pub summer_time_warning: bool,
+    /// DUT1 in tenths of a second, −8 to 8.
+    pub dut1_tenths: i8,
+}
+
+impl MsfFrame {
+    /// A and B bits of the frame (bit *n* is second *n*; second 0 is the
+    /// minute marker and left clear), with parity.
+    ///
+    /// Returns [`TimeCodeError::InvalidFrame`] if a field does not fit its
+    /// BCD width or |DUT1| exceeds 0.8 s.
+    pub fn to_bits(&self) -> Result<(u64, u64), TimeCodeError> {
+        if self.dut1_tenths.unsigned_abs() > 8 {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        let mut a = MSF_IDENTIFIER;
+        for (field, value) in [
+            (MSF_YEAR, self.year),
+            (MSF_MONTH, self.month),
+            (MSF_DAY, self.day),
+            (MSF_WEEKDAY, self.weekday),
+            (MSF_HOUR, self.hour),
+            (MSF_MINUTE, self.minute),
+        ] {
+            encode(&mut a, field, value as u32)?;
+        }
+        let mut b = 0_u128;
+        let count = self.dut1_tenths.unsigned_abs() as usize;
+        let dut1 = if self.dut1_tenths < 0 {
+            MSF_DUT1_NEGATIVE
+        } else {
+            MSF_DUT1_POSITIVE
+        };
+        for position in dut1.take(count) {
+            b |= 1 << position;
+        }
+        for (parity, range) in MSF_PARITY {
+            b |= (((ones(a, range) + 1) % 2) as u128) << parity;
+        }
+        b |= (self.summer_time_warning as u128) << MSF_SUMMER_WARNING;
+        b |= (self.summer_time as u128) << MSF_SUMMER_TIME;
+        Ok((a as u64, b as u64))
+    }
+
+    /// Decode A and B bits (bit *n* is second *n*).
+    pub fn from_bits(a: u64, b: u64) -> Result<Self, TimeCodeError> {
+        let (a, b) = (a as u128, b as u128);
+        if a & MSF_IDENTIFIER_MASK != MSF_IDENTIFIER {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        for (parity, range) in MSF_PARITY {
+            if (ones(a, range) + bit(b, parity) as u32).is_multiple_of(2) {
+                return Err(TimeCodeError::ParityMismatch);
+            }
+        }
+        // DUT1 bits are sent as a contiguous run from the first of each group.
+        let run = |range: core::ops::RangeInclusive<usize>| {
+            let count = ones(b, range.clone());
+            let mask = range.clone().fold(0_u128, |mask, p| mask | 1 << p);
+            let expected = range
+                .take(count as usize)
+                .fold(0_u128, |run, p| run | 1 << p);
+            (b & mask == expected).then_some(count as i8)
+        };
+        let positive = run(MSF_DUT1_POSITIVE);
+        let negative = run(MSF_DUT1_NEGATIVE);
+        let dut1_tenths = match (positive, negative) {
+            (Some(p), Some(0)) => p,
+            (Some(0), Some(n)) => -n,
+            _ => return Err(TimeCodeError::InvalidFrame),
+        };
+        Ok(Self {
+            year: get(a, MSF_YEAR)? as u8,
+            month: get(a, MSF_MONTH)? as u8,
+            day: get(a, MSF_DAY)? as u8,
+            weekday: get(a, MSF_WEEKDAY)? as u8,
+            hour: get(a, MSF_HOUR)? as u8,
+            minute: get(a, MSF_MINUTE)? as u8,
+            summer_time: bit(b, MSF_SUMMER_TIME),
+            summer_time_warning: bit(b, MSF_SUMMER_WARNING),
+            dut1_tenths,
+        })
+    }
+}
+
+// ── Time<UTC> conversions ────────────────────────────────────────────────
+
+/// UTC minute for a local civil label `offset_minutes` ahead of UTC.
+fn local_minute(
+    year: u8,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    offset_minutes: i64,
+) -> Result<i64, TimeCodeError> {
+    if hour >= 24 || minute >= 60 {
+        return Err(TimeCodeError::InvalidFrame);
+    }
+    let mjd = encoding::mjd_from_gregorian(2_000 + year as i64, month as u32, day as u32)
+        .ok_or(TimeCodeError::InvalidFrame)?;
+    Ok(mjd * MINUTES_PER_DAY + hour as i64 * 60 + minute as i64 - offset_minutes)
+}
+
+impl Time<UTC> {
+    /// IRIG-B frame for the UTC second containing this instant.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn to_irig_b(self) -> Result<IrigBFrame, TimeCodeError> {
+        self.to_irig_b_with(&TimeContext::new())
+    }
+
+    /// Like [`to_irig_b`](Self::to_irig_b), with an explicit [`TimeContext`].
+    ///
+    /// `LSP`/`LS` are set during the last minute of a day that ends in a leap
+    /// second; the DST bits and time quality are left clear.
+    pub fn to_irig_b_with(self, ctx: &TimeContext) -> Result<IrigBFrame, TimeCodeError> {
+        let label = self.to_mjd_sod_with(ctx)?;
+        let mjd = label.mjd.value();
+        let seconds = (label.second_of_day.as_nanos_i128() / 1_000_000_000) as u32;
+        let (year, day_of_year) = encoding::year_day_from_mjd(mjd);
+        let leap = match seconds >= 86_340 {
+            true => day_leap_indicator(mjd, ctx)?,
+            false => LeapIndicator::NoWarning,
+        };
+        let (hour, minute, second) = match seconds {
+            86_400.. => (23, 59, 60),
+            _ => (seconds / 3_600, seconds / 60 % 60, seconds % 60),
+        };
+        Ok(IrigBFrame {
+            year: two_digit_year(year)?,
+            day_of_year: day_of_year as u16,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            control: IrigBControl {
+                leap_second_pending: matches!(
+                    leap,
+                    LeapIndicator::InsertSecond | LeapIndicator::DeleteSecond
+                ),
+                leap_second_negative: leap == LeapIndicator::DeleteSecond,
+                ..IrigBControl::default()
+            },
+            straight_binary_seconds: seconds,
+        })
+    }
+
+    /// Start of the second labelled by an IRIG-B frame.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn from_irig_b(frame: &IrigBFrame) -> Result<Self, TimeCodeError> {
+        Self::from_irig_b_with(frame, &TimeContext::new())
+    }
+
+    /// Like [`from_irig_b`](Self::from_irig_b), with an explicit
+    /// [`TimeContext`].
+    pub fn from_irig_b_with(frame: &IrigBFrame, ctx: &TimeContext) -> Result<Self, TimeCodeError> {
+        let mjd = encoding::mjd_from_year_day(2_000 + frame.year as i64, frame.day_of_year as u32)
+            .ok_or(TimeCodeError::InvalidFrame)?;
+        let clock = [frame.hour, frame.minute, frame.second].map(u32::from);
+        Ok(time_from_clock(mjd, clock, 0, ctx)?)
+    }
+
+    /// DCF77 frame labelling the UTC minute containing this instant (it is
+    /// transmitted during the preceding minute).
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn to_dcf77(self) -> Result<Dcf77Frame, TimeCodeError> {
+        self.to_dcf77_with(&TimeContext::new())
+    }
+
+    /// Like [`to_dcf77`](Self::to_dcf77), with an explicit [`TimeContext`].
+    ///
+    /// `A2` is set from 23:00 UTC on a day that ends in a leap second.
+    pub fn to_dcf77_with(self, ctx: &TimeContext) -> Result<Dcf77Frame, TimeCodeError> {
+        let (mjd, minute_of_day) = utc_minute(self, ctx)?;
+        let minutes = mjd * MINUTES_PER_DAY + minute_of_day;
+        let summer_time = eu_summer_time(minutes);
+        let local = minutes + if summer_time { 120 } else { 60 };
+        let local_mjd = local.div_euclid(MINUTES_PER_DAY);
+        let local_minute = local.rem_euclid(MINUTES_PER_DAY);
+        let (year, month, day) = encoding::gregorian_from_mjd(local_mjd);
+        let leap_second_announcement =
+            minute_of_day >= 23 * 60 && day_leap_indicator(mjd, ctx)? != LeapIndicator::NoWarning;
+        Ok(Dcf77Frame {
+            year: two_digit_year(year)?,
+            month: month as u8,
+            day: day as u8,
+            weekday: iso_weekday(local_mjd) as u8,
+            hour: (local_minute / 60) as u8,
+            minute: (local_minute % 60) as u8,
+            summer_time,
+            summer_time_announcement: eu_change_within(minutes, 60),
+            leap_second_announcement,
+            call_bit: false,
+        })
+    }
+
+    /// Start of the UTC minute labelled by a DCF77 frame.
+    ///
+    /// Snapshots the active time-data bundle via [`TimeContext::new`].
+    #[inline]
+    pub fn from_dcf77(frame: &Dcf77Frame) -> Result<Self, TimeCodeError> {
+        Self::from_dcf77_with(frame, &TimeContext::new())
+    }
+
+    /// Like [`from_dcf77`](Self::from_dcf77), with an explicit
+    /// [`TimeContext`].
+    ///
+    /// Returns [`TimeCodeError::InvalidFrame`] if the weekday does not match
+    /// the date.
+    pub fn from_dcf77_with(frame: &Dcf77Frame, ctx: &TimeContext) -> Result<Self, TimeCodeError> {
+        let offset = if frame.summer_time { 120 } else { 60 };
+        let minutes = local_minute(
+            frame.year,
+            frame.month,
+            frame.day,
+            frame.hour,
+            frame.minute,
+            offset,
+        )?;
+        let local_mjd = (minutes + offset).div_euclid(MINUTES_PER_DAY);
+        if iso_weekday(local_mjd) != frame.weekday as u32 {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        minute_start(minutes, ctx)
+    }
+
+    /// WWVB frame for the UTC minute containing this instant.
+    ///
+    /// Uses [`TimeContext::with_builtin_eop`] for DUT1.
+    #[inline]
+    pub fn to_wwvb(self) -> Result<WwvbFrame, TimeCodeError> {
+        self.to_wwvb_with(&TimeContext::with_builtin_eop())
+    }
+
+    /// Like [`to_wwvb`](Self::to_wwvb), with an explicit [`TimeContext`].
+    ///
+    /// Returns [`ConversionError::Ut1HorizonExceeded`] when `ctx` has no
+    /// UT1−UTC value for the day. The leap-second warning is set for the
+    /// whole month ending in a leap second.
+    pub fn to_wwvb_with(self, ctx: &TimeContext) -> Result<WwvbFrame, TimeCodeError> {
+        let (mjd, minute_of_day) = utc_minute(self, ctx)?;
+        let (year, day_of_year) = encoding::year_day_from_mjd(mjd);
+        let (_, month, _) = encoding::gregorian_from_mjd(mjd);
+        let month_end = first_of_month(year, month + 1) - 1;
+        // US daylight time runs from the second Sunday of March to the first
+        // Sunday of November; bits 57/58 give its status at 24:00/00:00 UTC.
+        let dst_days = nth_sunday(year, 3, 2)..nth_sunday(year, 11, 1);
+        let dst = match (dst_days.contains(&mjd), dst_days.contains(&(mjd - 1))) {
+            (false, false) => WwvbDst::Standard,
+            (true, false) => WwvbDst::Begins,
+            (true, true) => WwvbDst::InEffect,
+            (false, true) => WwvbDst::Ends,
+        };
+        Ok(WwvbFrame {
+            year: two_digit_year(year)?,
+            day_of_year: day_of_year as u16,
+            hour: (minute_of_day / 60) as u8,
+            minute: (minute_of_day % 60) as u8,
+            dut1_tenths: dut1_tenths(mjd, ctx)?,
+            leap_year: encoding::mjd_from_year_day(year, 366).is_some(),
+            leap_second_warning: day_leap_indicator(month_end, ctx)? != LeapIndicator::NoWarning,
+            dst,
+        })
+    }
+
+    /// Start of the UTC minute labelled by a WWVB frame.
+    ///
+    /// Uses [`TimeContext::with_builtin_eop`], like [`to_wwvb`](Self::to_wwvb).
+    #[inline]
+    pub fn from_wwvb(frame: &WwvbFrame) -> Result<Self, TimeCodeError> {
+        Self::from_wwvb_with(frame, &TimeContext::with_builtin_eop())
+    }
+
+    /// Like [`from_wwvb`](Self::from_wwvb), with an explicit [`TimeContext`].
+    pub fn from_wwvb_with(frame: &WwvbFrame, ctx: &TimeContext) -> Result<Self, TimeCodeError> {
+        if frame.hour >= 24 || frame.minute >= 60 {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        let mjd = encoding::mjd_from_year_day(2_000 + frame.year as i64, frame.day_of_year as u32)
+            .ok_or(TimeCodeError::InvalidFrame)?;
+        minute_start(
+            mjd * MINUTES_PER_DAY + frame.hour as i64 * 60 + frame.minute as i64,
+            ctx,
+        )
+    }
+
+    /// MSF frame labelling the UTC minute containing this instant (it is
+    /// transmitted during the preceding minute).
+    ///
+    /// Uses [`TimeContext::with_builtin_eop`] for DUT1.
+    #[inline]
+    pub fn to_msf(self) -> Result<MsfFrame, TimeCodeError> {
+        self.to_msf_with(&TimeContext::with_builtin_eop())
+    }
+
+    /// Like [`to_msf`](Self::to_msf), with an explicit [`TimeContext`].
+    ///
+    /// Returns [`ConversionError::Ut1HorizonExceeded`] when `ctx` has no
+    /// UT1−UTC value for the day, and [`ConversionError::OutOfRange`] when
+    /// |DUT1| exceeds the 0.8 s MSF can carry.
+    pub fn to_msf_with(self, ctx: &TimeContext) -> Result<MsfFrame, TimeCodeError> {
+        let (mjd, minute_of_day) = utc_minute(self, ctx)?;
+        let minutes = mjd * MINUTES_PER_DAY + minute_of_day;
+        let summer_time = eu_summer_time(minutes);
+        let local = minutes + if summer_time { 60 } else { 0 };
+        let local_mjd = local.div_euclid(MINUTES_PER_DAY);
+        let local_minute = local.rem_euclid(MINUTES_PER_DAY);
+        let (year, month, day) = encoding::gregorian_from_mjd(local_mjd);
+        let year = two_digit_year(year)?;
+        let dut1_tenths = dut1_tenths(mjd, ctx)?;
+        if dut1_tenths.abs() > 8 {
+            return Err(ConversionError::OutOfRange.into());
+        }
+        Ok(MsfFrame {
+            year,
+            month: month as u8,
+            day: day as u8,
+            weekday: (iso_weekday(local_mjd) % 7) as u8,
+            hour: (local_minute / 60) as u8,
+            minute: (local_minute % 60) as u8,
+            summer_time,
+            summer_time_warning: eu_change_within(minutes, 61),
+            dut1_tenths,
+        })
+    }
+
+    /// Start of the UTC minute labelled by an MSF frame.
+    ///
+    /// Uses [`TimeContext::with_builtin_eop`], like [`to_msf`](Self::to_msf).
+    #[inline]
+    pub fn from_msf(frame: &MsfFrame) -> Result<Self, TimeCodeError> {
+        Self::from_msf_with(frame, &TimeContext::with_builtin_eop())
+    }
+
+    /// Like [`from_msf`](Self::from_msf), with an explicit [`TimeContext`].
+    ///
+    /// Returns [`TimeCodeError::InvalidFrame`] if the weekday does not match
+    /// the date.
+    pub fn from_msf_with(frame: &MsfFrame, ctx: &TimeContext) -> Result<Self, TimeCodeError> {
+        let offset = if frame.summer_time { 60 } else { 0 };
+        let minutes = local_minute(
+            frame.year,
+            frame.month,
+            frame.day,
+            frame.hour,
+            frame.minute,
+            offset,
+        )?;
+        let local_mjd = (minutes + offset).div_euclid(MINUTES_PER_DAY);
+        if iso_weekday(local_mjd) % 7 != frame.weekday as u32 {
+            return Err(TimeCodeError::InvalidFrame);
+        }
+        minute_start(minutes, ctx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::archive::time::{EopPoint, TimeDataBundle, UtcTaiSegment};
+    use crate::data::runtime_data::{compiled_time_data, with_test_time_data};
+    use crate::format::MjdSod;
+    use crate::foundation::duration::ExactDuration;
+
+    /// `segments` plus a flat `UT1 - UTC = dut1` EOP series over 2014–2031.
+    fn time_data(segments: Vec<UtcTaiSegment>, dut1: f64) -> TimeDataBundle {
+        let base = compiled_time_data();
+        let eop_points = (56_658_i32..=62_867)
+            .map(|mjd| EopPoint {
+                mjd,
+                pm_observed: true,
+                ut1_observed: true,
+                nutation_observed: true,
+                pm_xp: None,
+                pm_yp: None,
+                ut1_minus_utc: qtty::Second::new(dut1),
+                lod: None,
+                dx: None,
+                dy: None,
+            })
+            .collect();
+        TimeDataBundle::new(
+            segments,
+            base.modern_delta_t_points().to_vec(),
+            base.modern_delta_t_observed_end_mjd(),
+            eop_points,
+            base.provenance().clone(),
+        )
+    }
+
+    /// Context over the compiled UTC-TAI table with a flat DUT1 of `dut1`.
+    fn dut1_context(dut1: f64) -> TimeContext {
+        let segments = compiled_time_data().utc_tai_segments().to_vec();
+        with_test_time_data(time_data(segments, dut1), TimeContext::with_builtin_eop)
+    }
+
+    /// Run `f` with the compiled tables plus a flat `UT1 - UTC = -0.4 s`
+    /// EOP series.
+    fn with_dut1<T>(f: impl FnOnce() -> T) -> T {
+        let segments = compiled_time_data().utc_tai_segments().to_vec();
+        with_test_time_data(time_data(segments, -0.4), f)
+    }
+
+    /// Context whose UTC-TAI table deletes 2030-06-30T23:59:59.
+    fn negative_leap_context() -> TimeContext {
+        let deletion = encoding::mjd_from_gregorian(2030, 7, 1).unwrap() as i32;
+        let mut segments = compiled_time_data().utc_tai_segments().to_vec();
+        let last = segments.last_mut().unwrap();
+        last.end_mjd = Some(deletion);
+        let base = last.base - qtty::Second::new(1.0);
+        segments.push(UtcTaiSegment {
+            start_mjd: deletion,
+            end_mjd: None,
+            base,
+            reference_mjd: deletion as f64,
+            slope_seconds_per_day: 0.0,
+        });
+        with_test_time_data(time_data(segments, -0.4), TimeContext::with_builtin_eop)
+    }
+
+    /// Set IRIG-B bit 75 for even parity after editing `symbols`.
+    fn irig_reparity(symbols: &mut [PulseSymbol; 100]) {
+        let ones = symbols[1..75]
+            .iter()
+            .filter(|&&s| s == PulseSymbol::One)
+            .count();
+        symbols[75] = if ones % 2 == 1 {
+            PulseSymbol::One
+        } else {
+            PulseSymbol::Zero
+        };
+    }
+
+    fn utc(date: (i64, u32, u32), clock: [i64; 3]) -> Time<UTC> {
+        let mjd = encoding::mjd_from_gregorian(date.0, date.1, date.2).unwrap();
+        let seconds = clock[0] * 3_600 + clock[1] * 60 + clock[2];
+        let label = MjdSod::new(
+            qtty::i64::Day::new(mjd),
+            ExactDuration::from_nanos(seconds as i128 * 1_000_000_000),
+        )
+        .unwrap();
+        Time::<UTC>::from_mjd_sod(label).unwrap()
+    }
+
+    #[test]
+    fn dcf77_bits_match_hand_encoded_frame() {
+        // 2024-01-15 (Monday) 12:30 CET.
+        let t = utc((2024, 1, 15), [11, 30, 17]);
+        let frame = t.to_dcf77().unwrap();
+        let expected = [18, 20, 25, 26, 30, 33, 36, 38, 40, 42, 45, 52, 55, 58]
+            .iter()
+            .fold(0_u64, |bits, position| bits | 1 << position);
+        assert_eq!(frame.to_bits(), Ok(expected));
+        assert_eq!(Dcf77Frame::from_bits(expected).unwrap(), frame);
+        assert_eq!(
+            Time::<UTC>::from_dcf77(&frame).unwrap(),
+            utc((2024, 1, 15), [11, 30, 0])
+        );
+
+        assert_eq!(
+            Dcf77Frame::from_bits(expected ^ 1 << 25),
+            Err(TimeCodeError::ParityMismatch)
+        );
+        assert_eq!(
+            Dcf77Frame::from_bits(expected ^ 1 << 20),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        let wide_minute = Dcf77Frame {
+            minute: 99,
+            ..frame
+        };
+        assert_eq!(wide_minute.to_bits(), Err(TimeCodeError::InvalidFrame));
+        let wrong_weekday = Dcf77Frame {
+            weekday: 2,
+            ..frame
+        };
+        assert_eq!(
+            Time::<UTC>::from_dcf77(&wrong_weekday),
+            Err(TimeCodeError::InvalidFrame)
+        );
+    }
+
+    #[test]
+    fn summer_time_change_is_announced() {
+        // EU clocks change at 2024-03-31T01:00Z.
+        let before = utc((2024, 3, 31), [0, 30, 0]);
+        let dcf = before.to_dcf77().unwrap();
+        assert!(!dcf.summer_time && dcf.summer_time_announcement);
+        assert_eq!((dcf.hour, dcf.minute), (1, 30));
+        let msf = with_dut1(|| before.to_msf()).unwrap();
+        assert!(!msf.summer_time && msf.summer_time_warning);
+        assert_eq!(msf.weekday, 0);
+
+        let after = utc((2024, 3, 31), [1, 0, 0]);
+        let dcf = after.to_dcf77().unwrap();
+        assert!(dcf.summer_time && !dcf.summer_time_announcement);
+        assert_eq!((dcf.hour, dcf.minute), (3, 0));
+        assert_eq!(Time::<UTC>::from_dcf77(&dcf).unwrap(), after);
+        let msf = with_dut1(|| after.to_msf()).unwrap();
+        assert_eq!((msf.hour, msf.minute, msf.summer_time), (2, 0, true));
+        assert_eq!(Time::<UTC>::from_msf(&msf).unwrap(), after);
+    }
+
+    #[test]
+    fn irig_b_round_trips_and_flags_leap_second() {
+        let t = utc((2024, 2, 29), [17, 4, 9]);
+        let frame = t.to_irig_b().unwrap();
+        assert_eq!((frame.year, frame.day_of_year), (24, 60));
+        assert_eq!(frame.straight_binary_seconds, 61_449);
+        assert!(!frame.control.leap_second_pending);
+        let symbols = frame.to_symbols().unwrap();
+        assert_eq!(IrigBFrame::from_symbols(&symbols).unwrap(), frame);
+        let wide_sbs = IrigBFrame {
+            straight_binary_seconds: 1 << 17,
+            ..frame
+        };
+        assert_eq!(wide_sbs.to_symbols(), Err(TimeCodeError::InvalidFrame));
+        assert_eq!(Time::<UTC>::from_irig_b(&frame).unwrap(), t);
+
+        let mut broken = symbols;
+        broken[9] = PulseSymbol::Zero;
+        assert_eq!(
+            IrigBFrame::from_symbols(&broken),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        let mut flipped = symbols;
+        flipped[60] = PulseSymbol::One;
+        assert_eq!(
+            IrigBFrame::from_symbols(&flipped),
+            Err(TimeCodeError::ParityMismatch)
+        );
+
+        let pending = utc((2016, 12, 31), [23, 59, 0]).to_irig_b().unwrap();
+        assert!(pending.control.leap_second_pending);
+        assert!(!pending.control.leap_second_negative);
+        let earlier = utc((2016, 12, 31), [23, 58, 59]).to_irig_b().unwrap();
+        assert!(!earlier.control.leap_second_pending);
+    }
+
+    #[test]
+    fn wwvb_carries_dut1_leap_warning_and_dst() {
+        let ctx = with_dut1(TimeContext::with_builtin_eop);
+        let t = utc((2016, 12, 31), [12, 0, 0]);
+        let frame = t.to_wwvb_with(&ctx).unwrap();
+        assert_eq!((frame.year, frame.day_of_year), (16, 366));
+        assert!(frame.leap_year && frame.leap_second_warning);
+        assert_eq!(frame.dut1_tenths, -4);
+        assert_eq!(frame.dst, WwvbDst::Standard);
+        let symbols = frame.to_symbols().unwrap();
+        assert_eq!(
+            symbols[36..39],
+            [PulseSymbol::Zero, PulseSymbol::One, PulseSymbol::Zero]
+        );
+        assert_eq!(WwvbFrame::from_symbols(&symbols).unwrap(), frame);
+        assert_eq!(Time::<UTC>::from_wwvb(&frame).unwrap(), t);
+
+        let begins = utc((2024, 3, 10), [12, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        assert_eq!(begins.dst, WwvbDst::Begins);
+        let ends = utc((2024, 11, 3), [12, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        assert_eq!(ends.dst, WwvbDst::Ends);
+        assert_eq!(
+            t.to_wwvb_with(&TimeContext::new()),
+            Err(TimeCodeError::Conversion(
+                ConversionError::Ut1HorizonExceeded
+            ))
+        );
+    }
+
+    #[test]
+    fn wwvb_rejects_malformed_frames() {
+        let frame = WwvbFrame {
+            year: 24,
+            day_of_year: 166,
+            hour: 12,
+            minute: 34,
+            dut1_tenths: 3,
+            leap_year: true,
+            leap_second_warning: false,
+            dst: WwvbDst::InEffect,
+        };
+        let symbols = frame.to_symbols().unwrap();
+        assert_eq!(
+            symbols[36..39],
+            [PulseSymbol::One, PulseSymbol::Zero, PulseSymbol::One]
+        );
+        assert_eq!(WwvbFrame::from_symbols(&symbols), Ok(frame));
+
+        let mut marker = symbols;
+        marker[19] = PulseSymbol::Zero;
+        assert_eq!(
+            WwvbFrame::from_symbols(&marker),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        let mut sign = symbols;
+        sign[37] = PulseSymbol::One;
+        assert_eq!(
+            WwvbFrame::from_symbols(&sign),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        // DUT1 magnitude 12 is valid binary but not a BCD digit.
+        let mut dut1 = symbols;
+        dut1[40..44].copy_from_slice(&[
+            PulseSymbol::One,
+            PulseSymbol::One,
+            PulseSymbol::Zero,
+            PulseSymbol::Zero,
+        ]);
+        assert_eq!(
+            WwvbFrame::from_symbols(&dut1),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        let mut minute = symbols;
+        minute[5..9].fill(PulseSymbol::One);
+        assert_eq!(
+            WwvbFrame::from_symbols(&minute),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        let too_large = WwvbFrame {
+            dut1_tenths: -10,
+            ..frame
+        };
+        assert_eq!(too_large.to_symbols(), Err(TimeCodeError::InvalidFrame));
+    }
+
+    #[test]
+    fn msf_round_trips_with_odd_parity() {
+        let ctx = with_dut1(TimeContext::with_builtin_eop);
+        let t = utc((2016, 12, 31), [12, 0, 0]);
+        let frame = t.to_msf_with(&ctx).unwrap();
+        assert_eq!((frame.month, frame.day, frame.weekday), (12, 31, 6));
+        assert_eq!(frame.dut1_tenths, -4);
+        let (a, b) = frame.to_bits().unwrap();
+        assert_eq!(b >> 9 & 0xff, 0b1111);
+        assert_eq!(MsfFrame::from_bits(a, b).unwrap(), frame);
+        assert_eq!(Time::<UTC>::from_msf(&frame).unwrap(), t);
+        assert_eq!(
+            MsfFrame::from_bits(a ^ 1 << 45, b),
+            Err(TimeCodeError::ParityMismatch)
+        );
+        assert_eq!(
+            MsfFrame::from_bits(a, b | 1 << 1),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        assert_eq!(
+            utc((1999, 6, 1), [0, 0, 0]).to_msf_with(&ctx),
+            Err(TimeCodeError::Conversion(ConversionError::OutOfRange))
+        );
+    }
+
+    #[test]
+    fn msf_positive_dut1_and_malformed_frames() {
+        let frame = MsfFrame {
+            year: 24,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 13,
+            minute: 34,
+            summer_time: true,
+            summer_time_warning: false,
+            dut1_tenths: 3,
+        };
+        let (a, b) = frame.to_bits().unwrap();
+        assert_eq!(b >> 1 & 0xff, 0b111);
+        assert_eq!(b >> 9 & 0xff, 0);
+        assert_eq!(MsfFrame::from_bits(a, b), Ok(frame));
+        assert_eq!(
+            Time::<UTC>::from_msf(&frame).unwrap(),
+            utc((2024, 6, 14), [12, 34, 0])
+        );
+
+        assert_eq!(
+            MsfFrame::from_bits(a ^ 1 << 52, b),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        // A gap in the DUT1 run, and both runs set at once.
+        assert_eq!(
+            MsfFrame::from_bits(a, b ^ 1 << 2),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        assert_eq!(
+            MsfFrame::from_bits(a, b | 1 << 9),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        assert_eq!(
+            MsfFrame::from_bits(a, b ^ 1 << 54),
+            Err(TimeCodeError::ParityMismatch)
+        );
+        let too_large = MsfFrame {
+            dut1_tenths: 9,
+            ..frame
+        };
+        assert_eq!(too_large.to_bits(), Err(TimeCodeError::InvalidFrame));
+    }
+
+    #[test]
+    fn eu_summer_time_ends_with_announcement_and_warning() {
+        // EU clocks go back at 2024-10-27T01:00Z.
+        let ctx = dut1_context(-0.4);
+        let last_summer = utc((2024, 10, 27), [0, 59, 0]);
+        let dcf = last_summer.to_dcf77().unwrap();
+        assert!(dcf.summer_time && dcf.summer_time_announcement);
+        assert_eq!((dcf.hour, dcf.minute), (2, 59));
+        assert_eq!(Time::<UTC>::from_dcf77(&dcf).unwrap(), last_summer);
+        let msf = last_summer.to_msf_with(&ctx).unwrap();
+        assert!(msf.summer_time && msf.summer_time_warning);
+        assert_eq!((msf.hour, msf.minute), (1, 59));
+        assert_eq!(Time::<UTC>::from_msf_with(&msf, &ctx).unwrap(), last_summer);
+
+        // The local labels repeat; the summer-time bit tells them apart.
+        let first_winter = utc((2024, 10, 27), [1, 0, 0]);
+        let dcf = first_winter.to_dcf77().unwrap();
+        assert!(!dcf.summer_time && !dcf.summer_time_announcement);
+        assert_eq!((dcf.hour, dcf.minute), (2, 0));
+        assert_eq!(Time::<UTC>::from_dcf77(&dcf).unwrap(), first_winter);
+        let msf = first_winter.to_msf_with(&ctx).unwrap();
+        assert!(!msf.summer_time && !msf.summer_time_warning);
+        assert_eq!((msf.hour, msf.minute), (1, 0));
+        assert_eq!(
+            Time::<UTC>::from_msf_with(&msf, &ctx).unwrap(),
+            first_winter
+        );
+
+        // DCF77 announces the change during the hour before it, MSF during
+        // the 61 minutes before it.
+        let hour_before = utc((2024, 10, 27), [0, 0, 0]);
+        assert!(hour_before.to_dcf77().unwrap().summer_time_announcement);
+        assert!(hour_before.to_msf_with(&ctx).unwrap().summer_time_warning);
+        let msf_edge = utc((2024, 10, 26), [23, 59, 0]);
+        assert!(!msf_edge.to_dcf77().unwrap().summer_time_announcement);
+        assert!(msf_edge.to_msf_with(&ctx).unwrap().summer_time_warning);
+        let too_early = utc((2024, 10, 26), [23, 58, 0]);
+        assert!(!too_early.to_msf_with(&ctx).unwrap().summer_time_warning);
+    }
+
+    #[test]
+    fn wwvb_dst_status_around_both_changes() {
+        let ctx = dut1_context(-0.4);
+        let days = [
+            ((2024, 3, 9), WwvbDst::Standard),
+            ((2024, 3, 10), WwvbDst::Begins),
+            ((2024, 3, 11), WwvbDst::InEffect),
+            ((2024, 11, 2), WwvbDst::InEffect),
+            ((2024, 11, 3), WwvbDst::Ends),
+            ((2024, 11, 4), WwvbDst::Standard),
+        ];
+        for (date, dst) in days {
+            for clock in [[0, 0, 0], [23, 59, 0]] {
+                let frame = utc(date, clock).to_wwvb_with(&ctx).unwrap();
+                assert_eq!(frame.dst, dst, "{date:?} {clock:?}");
+                let symbols = frame.to_symbols().unwrap();
+                assert_eq!(WwvbFrame::from_symbols(&symbols), Ok(frame));
+            }
+        }
+        let begins = utc((2024, 3, 10), [12, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        let symbols = begins.to_symbols().unwrap();
+        assert_eq!(symbols[57..59], [PulseSymbol::One, PulseSymbol::Zero]);
+        let ends = utc((2024, 11, 3), [12, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        let symbols = ends.to_symbols().unwrap();
+        assert_eq!(symbols[57..59], [PulseSymbol::Zero, PulseSymbol::One]);
+    }
+
+    #[test]
+    fn negative_leap_second_is_flagged() {
+        let ctx = negative_leap_context();
+        // 2030-06-30 ends at 23:59:58.
+        let last = utc((2030, 6, 30), [23, 59, 0])
+            .try_add_exact(ExactDuration::from_nanos(58_000_000_000))
+            .unwrap();
+        let irig = last.to_irig_b_with(&ctx).unwrap();
+        assert_eq!((irig.hour, irig.minute, irig.second), (23, 59, 58));
+        assert!(irig.control.leap_second_pending && irig.control.leap_second_negative);
+        let symbols = irig.to_symbols().unwrap();
+        assert_eq!(symbols[60..62], [PulseSymbol::One, PulseSymbol::One]);
+        assert_eq!(IrigBFrame::from_symbols(&symbols), Ok(irig));
+        let earlier = utc((2030, 6, 30), [23, 58, 59])
+            .to_irig_b_with(&ctx)
+            .unwrap();
+        assert!(!earlier.control.leap_second_pending && !earlier.control.leap_second_negative);
+
+        let dcf = utc((2030, 6, 30), [23, 0, 0]).to_dcf77_with(&ctx).unwrap();
+        assert!(dcf.leap_second_announcement);
+        let wwvb = utc((2030, 6, 1), [0, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        assert!(wwvb.leap_second_warning);
+        let next_month = utc((2030, 7, 1), [0, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        assert!(!next_month.leap_second_warning);
+    }
+
+    #[test]
+    fn dut1_is_clamped_to_nine_tenths() {
+        let t = utc((2024, 6, 14), [12, 0, 0]);
+        for (dut1, tenths) in [(-0.97, -9), (0.96, 9), (-0.84, -8), (0.84, 8)] {
+            let ctx = dut1_context(dut1);
+            let frame = t.to_wwvb_with(&ctx).unwrap();
+            assert_eq!(frame.dut1_tenths, tenths, "{dut1}");
+            let symbols = frame.to_symbols().unwrap();
+            assert_eq!(WwvbFrame::from_symbols(&symbols), Ok(frame));
+            // MSF carries at most 0.8 s.
+            match t.to_msf_with(&ctx) {
+                Ok(frame) => assert_eq!(frame.dut1_tenths, tenths),
+                Err(err) => {
+                    assert_eq!(tenths.abs(), 9);
+                    assert_eq!(err, TimeCodeError::Conversion(ConversionError::OutOfRange));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn dcf77_parity_covers_each_group() {
+        let frame = utc((2024, 1, 15), [11, 30, 0]).to_dcf77().unwrap();
+        let bits = frame.to_bits().unwrap();
+        // A data bit and the parity bit of the minute, hour, and date groups.
+        for position in [22, 28, 30, 35, 38, 46, 57, 58] {
+            assert_eq!(
+                Dcf77Frame::from_bits(bits ^ 1 << position),
+                Err(TimeCodeError::ParityMismatch),
+                "bit {position}"
+            );
+        }
+        // Flipping a bit in two groups still fails on the first.
+        assert_eq!(
+            Dcf77Frame::from_bits(bits ^ (1 << 21 | 1 << 29)),
+            Err(TimeCodeError::ParityMismatch)
+        );
+        // Bits 59 and above are ignored.
+        assert_eq!(Dcf77Frame::from_bits(bits | 1 << 60), Ok(frame));
+    }
+
+    #[test]
+    fn frame_decoders_reject_each_malformed_field() {
+        let irig = utc((2024, 2, 29), [17, 4, 9]).to_irig_b().unwrap();
+        let symbols = irig.to_symbols().unwrap();
+        let mut stray_marker = symbols;
+        stray_marker[5] = PulseSymbol::Marker;
+        let mut offset = symbols;
+        offset[64] = PulseSymbol::One;
+        irig_reparity(&mut offset);
+        let mut bcd = symbols;
+        bcd[1..5].fill(PulseSymbol::One);
+        irig_reparity(&mut bcd);
+        let mut sbs = IrigBFrame {
+            straight_binary_seconds: irig.straight_binary_seconds + 1,
+            ..irig
+        }
+        .to_symbols()
+        .unwrap();
+        for broken in [stray_marker, offset, bcd, sbs] {
+            assert_eq!(
+                IrigBFrame::from_symbols(&broken),
+                Err(TimeCodeError::InvalidFrame)
+            );
+        }
+        // Straight binary seconds may be omitted.
+        sbs[80..99]
+            .iter_mut()
+            .filter(|s| **s == PulseSymbol::One)
+            .for_each(|s| *s = PulseSymbol::Zero);
+        irig_reparity(&mut sbs);
+        let omitted = IrigBFrame {
+            straight_binary_seconds: 0,
+            ..irig
+        };
+        assert_eq!(IrigBFrame::from_symbols(&sbs), Ok(omitted));
+
+        let dcf = utc((2024, 1, 15), [11, 30, 0]).to_dcf77().unwrap();
+        let bits = dcf.to_bits().unwrap();
+        // Minute units 15: bits 21–24 add four ones, so parity still holds.
+        let bad_minute = bits | 0b1111 << 21;
+        for broken in [bits | 1, bits | 1 << 17, bits & !(1 << 18), bad_minute] {
+            assert_eq!(
+                Dcf77Frame::from_bits(broken),
+                Err(TimeCodeError::InvalidFrame)
+            );
+        }
+
+        let wwvb = WwvbFrame {
+            year: 24,
+            day_of_year: 166,
+            hour: 12,
+            minute: 34,
+            dut1_tenths: 3,
+            leap_year: true,
+            leap_second_warning: false,
+            dst: WwvbDst::InEffect,
+        };
+        let mut stray_marker = wwvb.to_symbols().unwrap();
+        stray_marker[4] = PulseSymbol::Marker;
+        assert_eq!(
+            WwvbFrame::from_symbols(&stray_marker),
+            Err(TimeCodeError::InvalidFrame)
+        );
+
+        let msf = MsfFrame {
+            year: 24,
+            month: 6,
+            day: 14,
+            weekday: 5,
+            hour: 13,
+            minute: 34,
+            summer_time: true,
+            summer_time_warning: false,
+            dut1_tenths: 3,
+        };
+        let (a, b) = msf.to_bits().unwrap();
+        // Month units 15 (bits 26–29); bit 55 restores odd parity.
+        let bad_month = a | 0b1111 << 26;
+        let flips = (bad_month ^ a).count_ones() as u64;
+        assert_eq!(
+            MsfFrame::from_bits(bad_month, b ^ (flips % 2) << 55),
+            Err(TimeCodeError::InvalidFrame)
+        );
+    }
+
+    #[test]
+    fn time_conversions_reject_impossible_labels() {
+        let irig = utc((2024, 2, 29), [17, 4, 9]).to_irig_b().unwrap();
+        let no_day_366 = IrigBFrame {
+            year: 23,
+            day_of_year: 366,
+            ..irig
+        };
+        assert_eq!(
+            Time::<UTC>::from_irig_b(&no_day_366),
+            Err(TimeCodeError::InvalidFrame)
+        );
+        let early_leap = IrigBFrame { second: 60, ..irig };
+        assert_eq!(
+            Time::<UTC>::from_irig_b(&early_leap),
+            Err(TimeCodeError::Conversion(
+                ConversionError::InvalidLeapSecond
+            ))
+        );
+        let unannounced_leap = IrigBFrame {
+            hour: 23,
+            minute: 59,
+            second: 60,
+            ..irig
+        };
+        assert_eq!(
+            Time::<UTC>::from_irig_b(&unannounced_leap),
+            Err(TimeCodeError::Conversion(
+                ConversionError::InvalidLeapSecond
+            ))
+        );
+
+        let dcf = utc((2024, 1, 15), [11, 30, 0]).to_dcf77().unwrap();
+        for broken in [
+            Dcf77Frame { hour: 24, ..dcf },
+            Dcf77Frame { minute: 60, ..dcf },
+            Dcf77Frame { month: 13, ..dcf },
+            Dcf77Frame {
+                month: 2,
+                day: 30,
+                ..dcf
+            },
+        ] {
+            assert_eq!(
+                Time::<UTC>::from_dcf77(&broken),
+                Err(TimeCodeError::InvalidFrame)
+            );
+        }
+
+        let ctx = dut1_context(-0.4);
+        let wwvb = utc((2024, 6, 14), [12, 0, 0]).to_wwvb_with(&ctx).unwrap();
+        for broken in [
+            WwvbFrame { hour: 24, ..wwvb },
+            WwvbFrame { minute: 60, ..wwvb },
+            WwvbFrame {
+                day_of_year: 0,
+                ..wwvb
+            },
+            WwvbFrame {
+                year: 23,
+                day_of_year: 366,
+                ..wwvb
+            },
+        ] {
+            assert_eq!(
+                Time::<UTC>::from_wwvb_with(&broken, &ctx),
+                Err(TimeCodeError::InvalidFrame)
+            );
+        }
+
+        let msf = utc((2024, 6, 14), [12, 0, 0]).to_msf_with(&ctx).unwrap();
+        for broken in [
+            MsfFrame { hour: 24, ..msf },
+            MsfFrame {
+                month: 4,
+                day: 31,
+                ..msf
+            },
+            MsfFrame {
+                weekday: (msf.weekday + 1) % 7,
+                ..msf
+            },
+        ] {
+            assert_eq!(
+                Time::<UTC>::from_msf_with(&broken, &ctx),
+                Err(TimeCodeError::InvalidFrame)
+            );
+        }
+    }
+
+    #[test]
+    fn leap_second_warning_spans_the_month_and_dcf77_hour() {
+        let ctx = dut1_context(-0.4);
+        let warning = |date, clock| {
+            utc(date, clock)
+                .to_wwvb_with(&ctx)
+                .unwrap()
+                .leap_second_warning
+        };
+        assert!(!warning((2016, 11, 30), [23, 59, 0]));
+        assert!(warning((2016, 12, 1), [0, 0, 0]));
+        assert!(warning((2016, 12, 31), [23, 59, 0]));
+        assert!(!warning((2017, 1, 1), [0, 0, 0]));
+        let leap = utc((2016, 12, 31), [23, 59, 0])
+            .try_add_exact(ExactDuration::from_nanos(60_000_000_000))
+            .unwrap();
+        let frame = leap.to_wwvb_with(&ctx).unwrap();
+        assert!(frame.leap_second_warning);
+        assert_eq!((frame.day_of_year, frame.hour, frame.minute), (366, 23, 59));
+
+        let announced = |date, clock| {
+            utc(date, clock)
+                .to_dcf77()
+                .unwrap()
+                .leap_second_announcement
+        };
+        assert!(!announced((2016, 12, 31), [22, 59, 0]));
+        assert!(announced((2016, 12, 31), [23, 0, 0]));
+        assert!(!announced((2017, 1, 1), [0, 0, 0]));
+    }
+}
@@ -0,0 +1,1212 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! IANA time zones read from compiled TZif files (RFC 8536).
+//!
+//! [`TimeZone`] loads a zone from [`SYSTEM_ZONEINFO_DIR`] or any other
+//! directory and maps [`Time<UTC>`] to local civil labels
+//! ([`ZonedDateTime`]) and back:
+//!
+//! * Offsets come from the file's transition table; instants after the last
+//!   transition follow the POSIX `TZ` footer rule (v2+ files).
+//! * `right/` zones count leap seconds in their transition times. These are
+//!   normalised to POSIX seconds with the file's own leap records when the
+//!   zone is loaded, so both trees give the same offsets.
+//! * Leap seconds are labelled from the active UTC-TAI table, not from the
+//!   zone, so `2016-12-31T23:59:60Z` reads as second `60` of whatever local
+//!   minute it falls on (`2017-01-01T05:29:60+05:30` in `Asia/Kolkata`).
+//! * Local labels that occur twice (clocks set back) or never (clocks set
+//!   forward) resolve through an explicit [`LocalTimePolicy`].
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use tempoch_core::{LocalTimePolicy, Time, TimeZone, UTC};
+//!
+//! let zone = TimeZone::system("Europe/Madrid").unwrap();
+//! let t = Time::<UTC>::parse_ccsds_ascii("2024-06-15T12:34:56Z").unwrap();
+//! let local = t.to_zoned(&zone).unwrap();
+//! assert_eq!(local.to_string(), "2024-06-15T14:34:56+02:00 CEST");
+//!
+//! let back = Time::<UTC>::from_local(local.local, &zone, LocalTimePolicy::Reject).unwrap();
+//! assert_eq!(back, t);
+//! ```
+
+use std::path::{Component, Path};
+
+use crate::earth::context::TimeContext;
+use crate::encoding;
+use crate::foundation::error::ConversionError;
+use crate::model::scale::UTC;
+use crate::model::time::Time;
+
+use super::iso::time_from_clock;
+use super::CivilDateTime;
+
+/// Directory searched by [`TimeZone::system`].
+pub const SYSTEM_ZONEINFO_DIR: &str = "/usr/share/zoneinfo";
+
+const SECONDS_PER_DAY: i64 = 86_400;
+const UNIX_EPOCH_MJD: i64 = 40_587;
+
+/// Error returned by the time-zone layer.
+#[derive(Debug)]
+pub enum ZoneError {
+    /// The TZif file could not be read.
+    Io(std::io::Error),
+    /// The zone name is empty or escapes the zoneinfo directory.
+    InvalidName,
+    /// The TZif data or its POSIX `TZ` footer is malformed.
+    InvalidTzif(&'static str),
+    /// The local label occurs twice and the policy is
+    /// [`LocalTimePolicy::Reject`].
+    AmbiguousLocalTime,
+    /// The local label falls in a gap and the policy is
+    /// [`LocalTimePolicy::Reject`].
+    NonexistentLocalTime,
+    /// The UTC instant or label could not be converted.
+    Conversion(ConversionError),
+}
+
+impl core::fmt::Display for ZoneError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "cannot read time zone: {err}"),
+            Self::InvalidName => f.write_str("invalid time-zone name"),
+            Self::InvalidTzif(msg) => write!(f, "invalid TZif data: {msg}"),
+            Self::AmbiguousLocalTime => f.write_str("local time occurs twice in this zone"),
+            Self::NonexistentLocalTime => f.write_str("local time does not exist in this zone"),
+            Self::Conversion(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ZoneError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Conversion(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ZoneError {
+    fn from(err: std::io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<ConversionError> for ZoneError {
+    fn from(err: ConversionError) -> Self {
+        Self::Conversion(err)
+    }
+}
+
+/// How to map a local label that occurs twice or never to an instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalTimePolicy {
+    /// Take the earlier instant. In a gap this is the instant just before
+    /// the transition, shifted back by the gap length.
+    Earlier,
+    /// Take the later instant. In a gap this is the instant just after the
+    /// transition, shifted forward by the gap length.
+    Later,
+    /// Return [`ZoneError::AmbiguousLocalTime`] or
+    /// [`ZoneError::NonexistentLocalTime`].
+    Reject,
+}
+
+/// One local time type of a zone: offset, DST flag, and abbreviation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LocalTimeType {
+    /// Seconds east of UTC.
+    pub utc_offset_seconds: i32,
+    /// Whether this type is daylight-saving time.
+    pub is_dst: bool,
+    /// Designation such as `CEST` or `-03`.
+    pub abbreviation: String,
+}
+
+/// Proleptic Gregorian wall-clock label without a zone.
+///
+/// The same fields as a [`CivilDateTime`] label on a scale. A local leap
+/// second is second 60 of whichever minute UTC `23:59` falls on, which
+/// [`CivilDateTime::new`] only accepts at `23:59`; build other ones with
+/// struct syntax or take them from [`Time::to_zoned`].
+pub type LocalDateTime = CivilDateTime;
+
+/// Local POSIX seconds of `local` (leap second folded onto `:59`).
+fn local_seconds(local: &LocalDateTime) -> Result<i64, ConversionError> {
+    let mjd = encoding::mjd_from_gregorian(local.year, local.month, local.day)
+        .ok_or(ConversionError::OutOfRange)?;
+    if local.hour >= 24 || local.minute >= 60 || local.second > 60 {
+        return Err(ConversionError::OutOfRange);
+    }
+    let second = local.second.min(59);
+    (mjd - UNIX_EPOCH_MJD)
+        .checked_mul(SECONDS_PER_DAY)
+        .and_then(|days| days.checked_add((local.hour * 3_600 + local.minute * 60 + second) as i64))
+        .ok_or(ConversionError::OutOfRange)
+}
+
+fn local_from_seconds(seconds: i64, nanosecond: u32) -> LocalDateTime {
+    let (year, month, day) =
+        encoding::gregorian_from_mjd(seconds.div_euclid(SECONDS_PER_DAY) + UNIX_EPOCH_MJD);
+    let second_of_day = seconds.rem_euclid(SECONDS_PER_DAY) as u32;
+    LocalDateTime {
+        year,
+        month,
+        day,
+        hour: second_of_day / 3_600,
+        minute: second_of_day / 60 % 60,
+        second: second_of_day % 60,
+        nanosecond,
+    }
+}
+
+/// A [`LocalDateTime`] together with the zone's local time type in effect.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ZonedDateTime {
+    /// Wall-clock label.
+    pub local: LocalDateTime,
+    /// Offset, DST flag, and abbreviation in effect.
+    pub local_time_type: LocalTimeType,
+}
+
+/// `YYYY-MM-DDThh:mm:ss[.nnnnnnnnn]±hh:mm[:ss] ABBR`.
+impl core::fmt::Display for ZonedDateTime {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.local)?;
+        let offset = self.local_time_type.utc_offset_seconds;
+        let sign = if offset < 0 { '-' } else { '+' };
+        let abs = offset.unsigned_abs();
+        write!(f, "{sign}{:02}:{:02}", abs / 3_600, abs / 60 % 60)?;
+        if !abs.is_multiple_of(60) {
+            write!(f, ":{:02}", abs % 60)?;
+        }
+        write!(f, " {}", self.local_time_type.abbreviation)
+    }
+}
+
+/// A compiled IANA time zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimeZone {
+    name: String,
+    /// Transition instants in POSIX seconds, ascending.
+    transitions: Vec<i64>,
+    /// Index into `types` in effect from each transition on.
+    transition_types: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    rule: Option<PosixTz>,
+    counts_leap_seconds: bool,
+}
+
+impl TimeZone {
+    /// Load `name` (e.g. `Europe/Madrid` or `right/UTC`) from
+    /// [`SYSTEM_ZONEINFO_DIR`].
+    pub fn system(name: &str) -> Result<Self, ZoneError> {
+        Self::from_dir(SYSTEM_ZONEINFO_DIR, name)
+    }
+
+    /// Load `name` from a zoneinfo directory.
+    ///
+    /// Names must be relative paths without `.` or `..` components.
+    pub fn from_dir(dir: impl AsRef<Path>, name: &str) -> Result<Self, ZoneError> {
+        let path = Path::new(name);
+        if name.is_empty() || !path.components().all(|c| matches!(c, Component::Normal(_))) {
+            return Err(ZoneError::InvalidName);
+        }
+        let bytes = std::fs::read(dir.as_ref().join(path))?;
+        Self::from_tzif(name, &bytes)
+    }
+
+    /// Parse TZif (version 1 to 4) bytes.
+    pub fn from_tzif(name: impl Into<String>, bytes: &[u8]) -> Result<Self, ZoneError> {
+        let mut reader = Reader { bytes, pos: 0 };
+        let header = Header::read(&mut reader)?;
+        let (data, footer) = if header.version == 0 {
+            (DataBlock::read(&mut reader, &header, 4)?, None)
+        } else {
+            reader.take(header.block_len(4))?;
+            let header = Header::read(&mut reader)?;
+            let data = DataBlock::read(&mut reader, &header, 8)?;
+            (data, Some(read_footer(&mut reader)?))
+        };
+        if data.types.is_empty() {
+            return Err(ZoneError::InvalidTzif("no local time types"));
+        }
+        let rule = match footer {
+            Some(footer) if !footer.is_empty() => Some(PosixTz::parse(footer)?),
+            _ => None,
+        };
+
+        // `right/` files count leap seconds; bring transitions onto the
+        // POSIX axis with the correction in effect at each one.
+        let transitions = data
+            .transitions
+            .iter()
+            .map(|&t| {
+                let applied = data
+                    .leaps
+                    .partition_point(|&(occurrence, _)| occurrence <= t);
+                match applied {
+                    0 => t,
+                    n => t - data.leaps[n - 1].1 as i64,
+                }
+            })
+            .collect();
+        Ok(Self {
+            name: name.into(),
+            transitions,
+            transition_types: data.transition_types,
+            types: data.types,
+            rule,
+            counts_leap_seconds: !data.leaps.is_empty(),
+        })
+    }
+
+    /// Zone name as given when loading.
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the file carried leap-second records (a `right/` zone).
+    #[inline]
+    pub fn counts_leap_seconds(&self) -> bool {
+        self.counts_leap_seconds
+    }
+
+    /// Local time type in effect at POSIX second `t`.
+    fn type_at(&self, t: i64) -> Result<&LocalTimeType, ConversionError> {
+        let n = self.transitions.partition_point(|&x| x <= t);
+        Ok(match (&self.rule, n) {
+            (Some(rule), n) if n == self.transitions.len() => rule.type_at(t)?,
+            (_, 0) => &self.types[0],
+            (_, n) => &self.types[self.transition_types[n - 1]],
+        })
+    }
+
+    /// Offset in seconds east of UTC in effect at POSIX second `t`.
+    fn offset_at(&self, t: i64) -> Result<i64, ConversionError> {
+        Ok(self.type_at(t)?.utc_offset_seconds as i64)
+    }
+
+    /// Transition instants in `lo..=hi`, from the table and the footer rule.
+    fn transitions_in(&self, lo: i64, hi: i64) -> Result<Vec<i64>, ConversionError> {
+        let mut out: Vec<i64> = self
+            .transitions
+            .iter()
+            .copied()
+            .filter(|t| (lo..=hi).contains(t))
+            .collect();
+        if let Some(rule) = &self.rule {
+            let after = self.transitions.last().copied().unwrap_or(i64::MIN);
+            out.extend(
+                rule.transitions_in(lo, hi)?
+                    .into_iter()
+                    .filter(|&t| t >= after),
+            );
+        }
+        out.sort_unstable();
+        Ok(out)
+    }
+
+    /// POSIX second for local second `local` under `policy`.
+    fn resolve(&self, local: i64, policy: LocalTimePolicy) -> Result<i64, ZoneError> {
+        // RFC 8536 bounds offsets to (-25 h, +26 h).
+        let (lo, hi) = local
+            .checked_sub(2 * SECONDS_PER_DAY)
+            .zip(local.checked_add(2 * SECONDS_PER_DAY))
+            .ok_or(ConversionError::OutOfRange)?;
+        let transitions = self.transitions_in(lo, hi)?;
+        let mut offsets = vec![self.offset_at(lo)?];
+        for &t in &transitions {
+            offsets.push(self.offset_at(t)?);
+        }
+        offsets.sort_unstable();
+        offsets.dedup();
+
+        let mut matches = Vec::new();
+        for offset in offsets {
+            let t = local - offset;
+            if self.offset_at(t)? == offset {
+                matches.push(t);
+            }
+        }
+        matches.sort_unstable();
+        match (matches.as_slice(), policy) {
+            ([t], _) => return Ok(*t),
+            ([_, _, ..], LocalTimePolicy::Reject) => return Err(ZoneError::AmbiguousLocalTime),
+            ([first, ..], LocalTimePolicy::Earlier) => return Ok(*first),
+            ([.., last], LocalTimePolicy::Later) => return Ok(*last),
+            _ => {}
+        }
+
+        for t in transitions {
+            let before = self.offset_at(t - 1)?;
+            let after = self.offset_at(t)?;
+            if (t + before..t + after).contains(&local) {
+                return match policy {
+                    LocalTimePolicy::Earlier => Ok(local - after),
+                    LocalTimePolicy::Later => Ok(local - before),
+                    LocalTimePolicy::Reject => Err(ZoneError::NonexistentLocalTime),
+                };
+            }
+        }
+        Err(ZoneError::NonexistentLocalTime)
+    }
+}
+
+impl Time<UTC> {
+    /// Local label of this instant in `zone`.
+    #[inline]
+    pub fn to_zoned(&self, zone: &TimeZone) -> Result<ZonedDateTime, ZoneError> {
+        self.to_zoned_with(zone, &TimeContext::new())
+    }
+
+    /// Like [`to_zoned`](Self::to_zoned), with an explicit [`TimeContext`].
+    pub fn to_zoned_with(
+        &self,
+        zone: &TimeZone,
+        ctx: &TimeContext,
+    ) -> Result<ZonedDateTime, ZoneError> {
+        let label = self.to_mjd_sod_with(ctx)?;
+        let nanos = label.second_of_day.as_nanos_i128();
+        let second_of_day = (nanos / 1_000_000_000) as i64;
+        let posix = (label.mjd.value() - UNIX_EPOCH_MJD) * SECONDS_PER_DAY
+            + second_of_day.min(SECONDS_PER_DAY - 1);
+        let local_time_type = zone.type_at(posix)?.clone();
+        let mut local = local_from_seconds(
+            posix + local_time_type.utc_offset_seconds as i64,
+            (nanos % 1_000_000_000) as u32,
+        );
+        if second_of_day >= SECONDS_PER_DAY {
+            local.second = 60;
+        }
+        Ok(ZonedDateTime {
+            local,
+            local_time_type,
+        })
+    }
+
+    /// Instant labelled `local` in `zone`, resolving repeated and skipped
+    /// labels through `policy`.
+    ///
+    /// Second 60 is accepted where it maps to an announced positive leap
+    /// second at UTC `23:59`.
+    #[inline]
+    pub fn from_local(
+        local: LocalDateTime,
+        zone: &TimeZone,
+        policy: LocalTimePolicy,
+    ) -> Result<Self, ZoneError> {
+        Self::from_local_with(local, zone, policy, &TimeContext::new())
+    }
+
+    /// Like [`from_local`](Self::from_local), with an explicit
+    /// [`TimeContext`].
+    pub fn from_local_with(
+        local: LocalDateTime,
+        zone: &TimeZone,
+        policy: LocalTimePolicy,
+        ctx: &TimeContext,
+    ) -> Result<Self, ZoneError> {
+        if local.nanosecond >= 1_000_000_000 {
+            return Err(ConversionError::OutOfRange.into());
+        }
+        let posix = zone.resolve(local_seconds(&local)?, policy)?;
+        let second_of_day = posix.rem_euclid(SECONDS_PER_DAY) as u32;
+        let second = if local.second == 60 {
+            60
+        } else {
+            second_of_day % 60
+        };
+        Ok(time_from_clock(
+            posix.div_euclid(SECONDS_PER_DAY) + UNIX_EPOCH_MJD,
+            [second_of_day / 3_600, second_of_day / 60 % 60, second],
+            local.nanosecond,
+            ctx,
+        )?)
+    }
+}
+
+// ── TZif decoding ────────────────────────────────────────────────────────
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, n: usize) -> Result<&'a [u8], ZoneError> {
+        let end = self
+            .pos
+            .checked_add(n)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or(ZoneError::InvalidTzif("truncated file"))?;
+        let out = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, ZoneError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u32(&mut self) -> Result<u32, ZoneError> {
+        let b = self.take(4)?;
+        Ok(u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn i32(&mut self) -> Result<i32, ZoneError> {
+        Ok(self.u32()? as i32)
+    }
+
+    fn time(&mut self, size: usize) -> Result<i64, ZoneError> {
+        if size == 4 {
+            return Ok(self.i32()? as i64);
+        }
+        let b = self.take(8)?;
+        Ok(i64::from_be_bytes([
+            b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7],
+        ]))
+    }
+}
+
+struct Header {
+    version: u8,
+    isutcnt: usize,
+    isstdcnt: usize,
+    leapcnt: usize,
+    timecnt: usize,
+    typecnt: usize,
+    charcnt: usize,
+}
+
+impl Header {
+    fn read(reader: &mut Reader<'_>) -> Result<Self, ZoneError> {
+        if reader.take(4)? != b"TZif" {
+            return Err(ZoneError::InvalidTzif("missing TZif magic"));
+        }
+        let version = match reader.u8()? {
+            0 => 0,
+            v @ b'2'..=b'4' => v - b'0',
+            _ => return Err(ZoneError::InvalidTzif("unsupported version")),
+        };
+        reader.take(15)?;
+        let mut counts = [0_usize; 6];
+        for count in &mut counts {
+            *count = reader.u32()? as usize;
+        }
+        let [isutcnt, isstdcnt, leapcnt, timecnt, typecnt, charcnt] = counts;
+        Ok(Self {
+            version,
+            isutcnt,
+            isstdcnt,
+            leapcnt,
+            timecnt,
+            typecnt,
+            charcnt,
+        })
+    }
+
+    fn block_len(&self, time_size: usize) -> usize {
+        self.timecnt * (time_size + 1)
+            + self.typecnt * 6
+            + self.charcnt
+            + self.leapcnt * (time_size + 4)
+            + self.isstdcnt
+            + self.isutcnt
+    }
+}
+
+struct DataBlock {
+    transitions: Vec<i64>,
+    transition_types: Vec<usize>,
+    types: Vec<LocalTimeType>,
+    /// `(occurrence, cumulative correction)`, on the leap-counting axis.
+    leaps: Vec<(i64, i32)>,
+}
+
+impl DataBlock {
+    fn read(reader: &mut Reader<'_>, header: &Header, time_size: usize) -> Result<Self, ZoneError> {
+        let transitions = (0..header.timecnt)
+            .map(|_| reader.time(time_size))
+            .collect::<Result<Vec<_>, _>>()?;
+        if transitions.windows(2).any(|w| w[0] >= w[1]) {
+            return Err(ZoneError::InvalidTzif("transitions out of order"));
+        }
+        let transition_types = reader
+            .take(header.timecnt)?
+            .iter()
+            .map(|&i| i as usize)
+            .collect::<Vec<_>>();
+        if transition_types.iter().any(|&i| i >= header.typecnt) {
+            return Err(ZoneError::InvalidTzif("transition type out of range"));
+        }
+        let raw_types = (0..header.typecnt)
+            .map(|_| Ok((reader.i32()?, reader.u8()?, reader.u8()? as usize)))
+            .collect::<Result<Vec<_>, ZoneError>>()?;
+        let chars = reader.take(header.charcnt)?;
+        let types = raw_types
+            .into_iter()
+            .map(|(utc_offset_seconds, is_dst, index)| {
+                // RFC 8536 §3.2: the offset must not be -2^31.
+                if utc_offset_seconds == i32::MIN {
+                    return Err(ZoneError::InvalidTzif("UTC offset out of range"));
+                }
+                let tail = chars
+                    .get(index..)
+                    .ok_or(ZoneError::InvalidTzif("designation out of range"))?;
+                let len = tail
+                    .iter()
+                    .position(|&b| b == 0)
+                    .ok_or(ZoneError::InvalidTzif("unterminated designation"))?;
+                Ok(LocalTimeType {
+                    utc_offset_seconds,
+                    is_dst: is_dst != 0,
+                    abbreviation: String::from_utf8_lossy(&tail[..len]).into_owned(),
+                })
+            })
+            .collect::<Result<Vec<_>, ZoneError>>()?;
+        let leaps = (0..header.leapcnt)
+            .map(|_| Ok((reader.time(time_size)?, reader.i32()?)))
+            .collect::<Result<Vec<_>, ZoneError>>()?;
+        reader.take(header.isstdcnt + header.isutcnt)?;
+        Ok(Self {
+            transitions,
+            transition_types,
+            types,
+            leaps,
+        })
+    }
+}
+
+fn read_footer<'a>(reader: &mut Reader<'a>) -> Result<&'a str, ZoneError> {
+    let rest = &reader.bytes[reader.pos..];
+    let body = rest
+        .strip_prefix(b"\n")
+        .and_then(|body| {
+            body.iter()
+                .position(|&b| b == b'\n')
+                .map(|end| &body[..end])
+        })
+        .ok_or(ZoneError::InvalidTzif("missing footer"))?;
+    core::str::from_utf8(body).map_err(|_| ZoneError::InvalidTzif("footer is not UTF-8"))
+}
+
+// ── POSIX TZ footer rules ────────────────────────────────────────────────
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PosixTz {
+    std: LocalTimeType,
+    dst: Option<(LocalTimeType, PosixRule, PosixRule)>,
+}
+
+/// Day of a DST rule, with the transition time in local seconds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct PosixRule {
+    day: RuleDay,
+    time: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RuleDay {
+    /// `Jn`: day 1–365, February 29 never counted.
+    Julian1(u32),
+    /// `n`: day 0–365, February 29 counted.
+    Julian0(u32),
+    /// `Mm.w.d`: weekday `d` (0 = Sunday) of week `w` (5 = last) of month `m`.
+    MonthWeekDay(u32, u32, u32),
+}
+
+impl RuleDay {
+    fn mjd(self, year: i64) -> Result<i64, ConversionError> {
+        let month_start =
+            |month| encoding::mjd_from_gregorian(year, month, 1).ok_or(ConversionError::OutOfRange);
+        let jan1 = month_start(1)?;
+        Ok(match self {
+            Self::Julian1(n) => {
+                let leap = encoding::days_in_month(year, 2) == 29 && n >= 60;
+                jan1 + n as i64 - 1 + leap as i64
+            }
+            Self::Julian0(n) => jan1 + n as i64,
+            Self::MonthWeekDay(month, week, weekday) => {
+                let first = month_start(month)?;
+                // MJD 0 was a Wednesday.
+                let first_weekday = (first + 3).rem_euclid(7);
+                let mut day = first + (weekday as i64 - first_weekday).rem_euclid(7);
+                day += 7 * (week as i64 - 1);
+                while day >= first + encoding::days_in_month(year, month) as i64 {
+                    day -= 7;
+                }
+                day
+            }
+        })
+    }
+}
+
+impl PosixRule {
+    /// POSIX second of this rule in `year`, with `offset` in effect before.
+    fn instant(self, year: i64, offset: i32) -> Result<i64, ConversionError> {
+        (self.day.mjd(year)? - UNIX_EPOCH_MJD)
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|seconds| seconds.checked_add(self.time - offset as i64))
+            .ok_or(ConversionError::OutOfRange)
+    }
+}
+
+impl PosixTz {
+    fn parse(s: &str) -> Result<Self, ZoneError> {
+        let mut p = PosixParser { s: s.as_bytes() };
+        let std_name = p.name()?;
+        let std_offset = -p.offset()?;
+        let std = LocalTimeType {
+            utc_offset_seconds: std_offset,
+            is_dst: false,
+            abbreviation: std_name,
+        };
+        if p.s.is_empty() {
+            return Ok(Self { std, dst: None });
+        }
+        let dst_name = p.name()?;
+        let dst_offset = match p.s.first() {
+            Some(b',') => std_offset + 3_600,
+            _ => -p.offset()?,
+        };
+        if !p.eat(b',') {
+            return Err(ZoneError::InvalidTzif("POSIX TZ without DST rules"));
+        }
+        let start = p.rule()?;
+        if !p.eat(b',') {
+            return Err(ZoneError::InvalidTzif("POSIX TZ without DST end"));
+        }
+        let end = p.rule()?;
+        if !p.s.is_empty() {
+            return Err(ZoneError::InvalidTzif("trailing POSIX TZ data"));
+        }
+        let dst = LocalTimeType {
+            utc_offset_seconds: dst_offset,
+            is_dst: true,
+            abbreviation: dst_name,
+        };
+        Ok(Self {
+            std,
+            dst: Some((dst, start, end)),
+        })
+    }
+
+    /// DST start and end in `year`, as POSIX seconds (`None` without DST).
+    fn bounds(&self, year: i64) -> Result<Option<(i64, i64)>, ConversionError> {
+        let Some((dst, start, end)) = &self.dst else {
+            return Ok(None);
+        };
+        Ok(Some((
+            start.instant(year, self.std.utc_offset_seconds)?,
+            end.instant(year, dst.utc_offset_seconds)?,
+        )))
+    }
+
+    fn type_at(&self, t: i64) -> Result<&LocalTimeType, ConversionError> {
+        let Some((dst, _, _)) = &self.dst else {
+            return Ok(&self.std);
+        };
+        let year = year_of(t + self.std.utc_offset_seconds as i64);
+        let Some((start, end)) = self.bounds(year)? else {
+            return Ok(&self.std);
+        };
+        let in_dst = if start < end {
+            (start..end).contains(&t)
+        } else {
+            !(end..start).contains(&t)
+        };
+        Ok(if in_dst { dst } else { &self.std })
+    }
+
+    fn transitions_in(&self, lo: i64, hi: i64) -> Result<Vec<i64>, ConversionError> {
+        let mut out = Vec::new();
+        for year in year_of(lo) - 1..=year_of(hi) + 1 {
+            if let Some((start, end)) = self.bounds(year)? {
+                out.extend([start, end].into_iter().filter(|t| (lo..=hi).contains(t)));
+            }
+        }
+        Ok(out)
+    }
+}
+
+fn year_of(posix: i64) -> i64 {
+    encoding::gregorian_from_mjd(posix.div_euclid(SECONDS_PER_DAY) + UNIX_EPOCH_MJD).0
+}
+
+struct PosixParser<'a> {
+    s: &'a [u8],
+}
+
+impl PosixParser<'_> {
+    fn eat(&mut self, b: u8) -> bool {
+        if self.s.first() == Some(&b) {
+            self.s = &self.s[1..];
+            true
+        } else {
+            false
+        }
+    }
+
+    fn name(&mut self) -> Result<String, ZoneError> {
+        let (name, rest) = if self.eat(b'<') {
+            let end = self
+                .s
+                .iter()
+                .position(|&b| b == b'>')
+                .ok_or(ZoneError::InvalidTzif("unterminated POSIX TZ name"))?;
+            (&self.s[..end], &self.s[end + 1..])
+        } else {
+            let end = self
+                .s
+                .iter()
+                .position(|b| !b.is_ascii_alphabetic())
+                .unwrap_or(self.s.len());
+            (&self.s[..end], &self.s[end..])
+        };
+        if name.len() < 3 {
+            return Err(ZoneError::InvalidTzif("POSIX TZ name too short"));
+        }
+        self.s = rest;
+        Ok(String::from_utf8_lossy(name).into_owned())
+    }
+
+    fn number(&mut self) -> Result<u32, ZoneError> {
+        let end = self
+            .s
+            .iter()
+            .position(|b| !b.is_ascii_digit())
+            .unwrap_or(self.s.len());
+        let digits = core::str::from_utf8(&self.s[..end]).unwrap_or_default();
+        let value = digits
+            .parse()
+            .map_err(|_| ZoneError::InvalidTzif("expected a number in POSIX TZ"))?;
+        self.s = &self.s[end..];
+        Ok(value)
+    }
+
+    /// `[+-]hh[:mm[:ss]]` in seconds; hours up to 167 as in RFC 8536 §3.3.1.
+    fn signed_hms(&mut self) -> Result<i32, ZoneError> {
+        let sign = if self.eat(b'-') {
+            -1
+        } else {
+            self.eat(b'+');
+            1
+        };
+        let hours = self.number()?;
+        let minutes = if self.eat(b':') { self.number()? } else { 0 };
+        let seconds = if self.eat(b':') { self.number()? } else { 0 };
+        if hours > 167 || minutes > 59 || seconds > 59 {
+            return Err(ZoneError::InvalidTzif("POSIX TZ time out of range"));
+        }
+        Ok(sign * (hours * 3_600 + minutes * 60 + seconds) as i32)
+    }
+
+    /// POSIX offset, positive west of Greenwich.
+    fn offset(&mut self) -> Result<i32, ZoneError> {
+        let offset = self.signed_hms()?;
+        if offset.unsigned_abs() >= 25 * 3_600 {
+            return Err(ZoneError::InvalidTzif("POSIX TZ offset out of range"));
+        }
+        Ok(offset)
+    }
+
+    fn rule(&mut self) -> Result<PosixRule, ZoneError> {
+        let day = if self.eat(b'J') {
+            match self.number()? {
+                n @ 1..=365 => RuleDay::Julian1(n),
+                _ => return Err(ZoneError::InvalidTzif("POSIX TZ Julian day out of range")),
+            }
+        } else if self.eat(b'M') {
+            let month = self.number()?;
+            let week = if self.eat(b'.') { self.number()? } else { 0 };
+            let weekday = if self.eat(b'.') { self.number()? } else { 7 };
+            if !(1..=12).contains(&month) || !(1..=5).contains(&week) || weekday > 6 {
+                return Err(ZoneError::InvalidTzif("POSIX TZ month rule out of range"));
+            }
+            RuleDay::MonthWeekDay(month, week, weekday)
+        } else {
+            match self.number()? {
+                n @ 0..=365 => RuleDay::Julian0(n),
+                _ => return Err(ZoneError::InvalidTzif("POSIX TZ day out of range")),
+            }
+        };
+        let time = if self.eat(b'/') {
+            self.signed_hms()?
+        } else {
+            7_200
+        };
+        Ok(PosixRule {
+            day,
+            time: time as i64,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal TZif v2 file with an empty v1 block.
+    fn tzif(
+        transitions: &[(i64, u8)],
+        types: &[(i32, bool, &str)],
+        leaps: &[(i64, i32)],
+        footer: &str,
+    ) -> Vec<u8> {
+        let mut chars = Vec::new();
+        let mut ttinfo = Vec::new();
+        for &(offset, is_dst, abbreviation) in types {
+            ttinfo.extend_from_slice(&offset.to_be_bytes());
+            ttinfo.push(is_dst as u8);
+            ttinfo.push(chars.len() as u8);
+            chars.extend_from_slice(abbreviation.as_bytes());
+            chars.push(0);
+        }
+        let header = |counts: [usize; 6]| {
+            let mut out = b"TZif2".to_vec();
+            out.extend_from_slice(&[0; 15]);
+            for count in counts {
+                out.extend_from_slice(&(count as u32).to_be_bytes());
+            }
+            out
+        };
+        let mut out = header([0; 6]);
+        out.extend(header([
+            0,
+            0,
+            leaps.len(),
+            transitions.len(),
+            types.len(),
+            chars.len(),
+        ]));
+        for (t, _) in transitions {
+            out.extend_from_slice(&t.to_be_bytes());
+        }
+        out.extend(transitions.iter().map(|&(_, i)| i));
+        out.extend(ttinfo);
+        out.extend(chars);
+        for (t, correction) in leaps {
+            out.extend_from_slice(&t.to_be_bytes());
+            out.extend_from_slice(&correction.to_be_bytes());
+        }
+        out.extend_from_slice(format!("\n{footer}\n").as_bytes());
+        out
+    }
+
+    fn utc(s: &str) -> Time<UTC> {
+        Time::<UTC>::parse_ccsds_ascii(s).unwrap()
+    }
+
+    fn posix(s: &str) -> i64 {
+        let label = utc(s).to_mjd_sod().unwrap();
+        (label.mjd.value() - UNIX_EPOCH_MJD) * SECONDS_PER_DAY
+            + (label.second_of_day.as_nanos_i128() / 1_000_000_000) as i64
+    }
+
+    fn local(s: &str) -> LocalDateTime {
+        let bytes = tzif(&[], &[(0, false, "UTC")], &[], "UTC0");
+        let zone = TimeZone::from_tzif("Etc/UTC", &bytes).unwrap();
+        utc(s).to_zoned(&zone).unwrap().local
+    }
+
+    fn berlin() -> TimeZone {
+        let bytes = tzif(
+            &[],
+            &[(3_600, false, "CET")],
+            &[],
+            "CET-1CEST,M3.5.0,M10.5.0/3",
+        );
+        TimeZone::from_tzif("Europe/Berlin", &bytes).unwrap()
+    }
+
+    #[test]
+    fn footer_rule_gives_summer_and_winter_offsets() {
+        let zone = berlin();
+        let summer = utc("2024-06-15T12:34:56.5").to_zoned(&zone).unwrap();
+        assert_eq!(
+            summer.to_string(),
+            "2024-06-15T14:34:56.500000000+02:00 CEST"
+        );
+        let winter = utc("2024-01-15T12:00:00").to_zoned(&zone).unwrap();
+        assert_eq!(winter.to_string(), "2024-01-15T13:00:00+01:00 CET");
+        // Last Sunday of March 2024 is the 31st; the change is at 01:00 UTC.
+        let before = utc("2024-03-31T00:59:59").to_zoned(&zone).unwrap();
+        let after = utc("2024-03-31T01:00:00").to_zoned(&zone).unwrap();
+        assert_eq!((before.local.hour, after.local.hour), (1, 3));
+    }
+
+    #[test]
+    fn ambiguous_and_nonexistent_labels_follow_policy() {
+        let zone = berlin();
+        let resolve = |s: &str, policy| Time::<UTC>::from_local(local(s), &zone, policy);
+
+        // 02:30 occurs twice on 2024-10-27.
+        let fold = "2024-10-27T02:30:00";
+        assert_eq!(
+            resolve(fold, LocalTimePolicy::Earlier).unwrap(),
+            utc("2024-10-27T00:30:00")
+        );
+        assert_eq!(
+            resolve(fold, LocalTimePolicy::Later).unwrap(),
+            utc("2024-10-27T01:30:00")
+        );
+        assert!(matches!(
+            resolve(fold, LocalTimePolicy::Reject),
+            Err(ZoneError::AmbiguousLocalTime)
+        ));
+
+        // 02:30 never happens on 2024-03-31.
+        let gap = "2024-03-31T02:30:00";
+        assert_eq!(
+            resolve(gap, LocalTimePolicy::Earlier).unwrap(),
+            utc("2024-03-31T00:30:00")
+        );
+        assert_eq!(
+            resolve(gap, LocalTimePolicy::Later).unwrap(),
+            utc("2024-03-31T01:30:00")
+        );
+        assert!(matches!(
+            resolve(gap, LocalTimePolicy::Reject),
+            Err(ZoneError::NonexistentLocalTime)
+        ));
+
+        let noon = resolve("2024-06-15T12:00:00", LocalTimePolicy::Reject).unwrap();
+        assert_eq!(noon, utc("2024-06-15T10:00:00"));
+    }
+
+    #[test]
+    fn right_zone_transitions_are_normalised_and_leap_second_shows_as_sixty() {
+        // Switch from +00:00 to +05:30 at 2016-12-31T00:00Z, written on the
+        // leap-counting axis (26 s of correction in effect then).
+        let switch = posix("2016-12-31T00:00:00");
+        let leaps = [
+            (posix("2015-07-01T00:00:00") + 25, 26),
+            (posix("2017-01-01T00:00:00") + 26, 27),
+        ];
+        let bytes = tzif(
+            &[(switch + 26, 1)],
+            &[(0, false, "OLD"), (19_800, false, "IST")],
+            &leaps,
+            "IST-5:30",
+        );
+        let zone = TimeZone::from_tzif("right/Test", &bytes).unwrap();
+        assert!(zone.counts_leap_seconds());
+        assert_eq!(
+            utc("2016-12-30T23:59:59")
+                .to_zoned(&zone)
+                .unwrap()
+                .local_time_type
+                .abbreviation,
+            "OLD"
+        );
+
+        let leap = utc("2016-12-31T23:59:60.25");
+        let zoned = leap.to_zoned(&zone).unwrap();
+        assert_eq!(zoned.to_string(), "2017-01-01T05:29:60.250000000+05:30 IST");
+        let back = Time::<UTC>::from_local(zoned.local, &zone, LocalTimePolicy::Reject).unwrap();
+        assert_eq!(back, leap);
+
+        let mut wrong = zoned.local;
+        wrong.minute = 28;
+        assert!(matches!(
+            Time::<UTC>::from_local(wrong, &zone, LocalTimePolicy::Reject),
+            Err(ZoneError::Conversion(ConversionError::InvalidLeapSecond))
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_names_and_data() {
+        assert!(matches!(
+            TimeZone::from_dir("/tmp", "../etc/passwd"),
+            Err(ZoneError::InvalidName)
+        ));
+        assert!(matches!(
+            TimeZone::from_tzif("x", b"TZif2"),
+            Err(ZoneError::InvalidTzif(_))
+        ));
+        let bytes = tzif(&[], &[(0, false, "UTC")], &[], "UTC0,M3.5.0");
+        assert!(matches!(
+            TimeZone::from_tzif("x", &bytes),
+            Err(ZoneError::InvalidTzif(_))
+        ));
+        assert!(LocalDateTime::new(2024, 2, 30, 0, 0, 0, 0).is_err());
+    }
+
+    #[test]
+    fn far_local_years_are_out_of_range() {
+        let zone = berlin();
+        // Past the calendar range, past `i64` seconds, and a year whose
+        // footer-rule transitions no longer fit in `i64` seconds.
+        for year in [1_000_000_000_000_000, 200_000_000_000_000, 292_000_000_000] {
+            let local = LocalDateTime::new(year, 6, 1, 0, 0, 0, 0);
+            let result = local
+                .map_err(ZoneError::from)
+                .and_then(|local| Time::<UTC>::from_local(local, &zone, LocalTimePolicy::Reject));
+            assert!(
+                matches!(
+                    result,
+                    Err(ZoneError::Conversion(ConversionError::OutOfRange))
+                ),
+                "{year}: {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    #[ignore = "needs Asia/Kolkata and right/Asia/Kolkata in /usr/share/zoneinfo"]
+    fn system_zones_agree_with_right_zones() {
+        let posix_zone = TimeZone::system("Asia/Kolkata").unwrap();
+        let right_zone = TimeZone::system("right/Asia/Kolkata").unwrap();
+        let leap = utc("2016-12-31T23:59:60");
+        assert_eq!(
+            leap.to_zoned(&posix_zone).unwrap(),
+            leap.to_zoned(&right_zone).unwrap()
+        );
+        assert_eq!(
+            leap.to_zoned(&right_zone).unwrap().to_string(),
+            "2017-01-01T05:29:60+05:30 IST"
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_tzif_blocks() {
+        let valid = tzif(
+            &[(0, 1)],
+            &[(0, false, "OLD"), (3_600, false, "NEW")],
+            &[],
+            "NEW-1",
+        );
+        assert!(TimeZone::from_tzif("x", &valid).is_ok());
+        // Every proper prefix is truncated somewhere: header, data block,
+        // or footer.
+        for len in 0..valid.len() {
+            assert!(
+                matches!(
+                    TimeZone::from_tzif("x", &valid[..len]),
+                    Err(ZoneError::InvalidTzif(_))
+                ),
+                "{len}"
+            );
+        }
+
+        let invalid = |bytes: &[u8], reason: &str| match TimeZone::from_tzif("x", bytes) {
+            Err(ZoneError::InvalidTzif(msg)) => assert_eq!(msg, reason),
+            other => panic!("{reason}: {other:?}"),
+        };
+        let mut magic = valid.clone();
+        magic[0] = b'X';
+        invalid(&magic, "missing TZif magic");
+        let mut version = valid.clone();
+        version[4] = b'5';
+        invalid(&version, "unsupported version");
+        invalid(
+            &tzif(&[(0, 2)], &[(0, false, "A"), (1, false, "B")], &[], ""),
+            "transition type out of range",
+        );
+        invalid(
+            &tzif(&[(10, 0), (10, 0)], &[(0, false, "UTC")], &[], ""),
+            "transitions out of order",
+        );
+        invalid(
+            &tzif(&[], &[(i32::MIN, false, "MIN")], &[], ""),
+            "UTC offset out of range",
+        );
+        invalid(&tzif(&[], &[], &[], ""), "no local time types");
+        // Two v1 headers, one transition (8 + 1 bytes), then the type's
+        // designation index.
+        let mut designation = valid.clone();
+        designation[2 * 44 + 9 + 5] = 200;
+        invalid(&designation, "designation out of range");
+        let mut unterminated = tzif(&[], &[(0, false, "UTC")], &[], "");
+        let nul = unterminated.len() - 3;
+        assert_eq!(unterminated[nul], 0);
+        unterminated[nul] = b'X';
+        invalid(&unterminated, "unterminated designation");
+
+        // The most negative offset RFC 8536 allows still loads.
+        let min = tzif(&[], &[(i32::MIN + 1, false, "MIN")], &[], "");
+        assert!(TimeZone::from_tzif("x", &min).is_ok());
+    }
+
+    #[test]
+    fn rejects_bad_footers() {
+        let invalid = |footer: &[u8], reason: &str| {
+            let mut bytes = tzif(&[], &[(0, false, "UTC")], &[], "");
+            bytes.truncate(bytes.len() - 2);
+            bytes.extend_from_slice(footer);
+            match TimeZone::from_tzif("x", &bytes) {
+                Err(ZoneError::InvalidTzif(msg)) => assert_eq!(msg, reason, "{footer:?}"),
+                other => panic!("{footer:?}: {other:?}"),
+            }
+        };
+        invalid(b"UTC0\n", "missing footer");
+        invalid(b"\nUTC0", "missing footer");
+        invalid(b"\nUTCx\n", "expected a number in POSIX TZ");
+        invalid(b"\n<UTC\xff>0\n", "footer is not UTF-8");
+        invalid(b"\n<UTC0\n", "unterminated POSIX TZ name");
+        invalid(b"\nUT0\n", "POSIX TZ name too short");
+        invalid(b"\nUTC25\n", "POSIX TZ offset out of range");
+        invalid(b"\nUTC0:60\n", "POSIX TZ time out of range");
+        invalid(b"\nUTC0DST1\n", "POSIX TZ without DST rules");
+        invalid(b"\nUTC0DST,M3.5.0\n", "POSIX TZ without DST end");
+        invalid(b"\nUTC0DST,M3.5.0,M10.5.0x\n", "trailing POSIX TZ data");
+        for rule in ["M13.1.0", "M3.0.0", "M3.6.0", "M3.5.7", "M3.5"] {
+            let footer = format!("\nUTC0DST,{rule},M10.5.0\n");
+            invalid(footer.as_bytes(), "POSIX TZ month rule out of range");
+        }
+        for rule in ["J0", "J366"] {
+            let footer = format!("\nUTC0DST,{rule},M10.5.0\n");
+            invalid(footer.as_bytes(), "POSIX TZ Julian day out of range");
+        }
+        invalid(b"\nUTC0DST,366,M10.5.0\n", "POSIX TZ day out of range");
+        invalid(
+            b"\nUTC0DST,M3.5.0/168,M10.5.0\n",
+            "POSIX TZ time out of range",
+        );
+    }
+
+    #[test]
+    fn posix_rule_days_at_year_and_month_edges() {
+        let mjd = |y, m, d| encoding::mjd_from_gregorian(y, m, d).unwrap();
+        let cases = [
+            // `Jn` never counts February 29.
+            (RuleDay::Julian1(59), 2023, mjd(2023, 2, 28)),
+            (RuleDay::Julian1(60), 2023, mjd(2023, 3, 1)),
+            (RuleDay::Julian1(59), 2024, mjd(2024, 2, 28)),
+            (RuleDay::Julian1(60), 2024, mjd(2024, 3, 1)),
+            (RuleDay::Julian1(1), 2024, mjd(2024, 1, 1)),
+            (RuleDay::Julian1(365), 2024, mjd(2024, 12, 31)),
+            // `n` does; day 365 of a common year spills into January.
+            (RuleDay::Julian0(0), 2023, mjd(2023, 1, 1)),
+            (RuleDay::Julian0(59), 2023, mjd(2023, 3, 1)),
+            (RuleDay::Julian0(59), 2024, mjd(2024, 2, 29)),
+            (RuleDay::Julian0(365), 2024, mjd(2024, 12, 31)),
+            (RuleDay::Julian0(365), 2023, mjd(2024, 1, 1)),
+            // `Mm.w.d`: week 5 is the last such weekday of the month.
+            (RuleDay::MonthWeekDay(2, 5, 4), 2024, mjd(2024, 2, 29)),
+            (RuleDay::MonthWeekDay(2, 5, 4), 2023, mjd(2023, 2, 23)),
+            (RuleDay::MonthWeekDay(2, 4, 0), 2026, mjd(2026, 2, 22)),
+            (RuleDay::MonthWeekDay(2, 5, 0), 2026, mjd(2026, 2, 22)),
+            (RuleDay::MonthWeekDay(1, 1, 0), 2023, mjd(2023, 1, 1)),
+            (RuleDay::MonthWeekDay(3, 1, 6), 2024, mjd(2024, 3, 2)),
+            (RuleDay::MonthWeekDay(12, 5, 2), 2024, mjd(2024, 12, 31)),
+            (RuleDay::MonthWeekDay(12, 1, 3), 2024, mjd(2024, 12, 4)),
+        ];
+        for (day, year, expected) in cases {
+            assert_eq!(day.mjd(year), Ok(expected), "{day:?} {year}");
+        }
+
+        // Rule times may be negative or past 24:00 (RFC 8536 §3.3.1).
+        let tz = PosixTz::parse("XST3XDT,J60/-1,M11.1.0/26").unwrap();
+        let (start, end) = tz.bounds(2024).unwrap().unwrap();
+        assert_eq!(start, posix("2024-03-01T02:00:00"));
+        assert_eq!(end, posix("2024-11-04T04:00:00"));
+    }
+}
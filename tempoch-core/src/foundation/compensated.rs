@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: AGPL-3.0-only
+// Copyright (C) 2026 Vallés Puig, Ramon
+
+//! Error-free `f64` transformations for compensated arithmetic.
+
+/// Exact sum and rounding error of `a + b` (Knuth's two-sum):
+/// `s + err == a + b` exactly.
+#[inline]
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_sum_recovers_the_rounding_error() {
+        assert_eq!(two_sum(1e16, 1.5), (1e16 + 2.0, -0.5));
+        assert_eq!(
+            two_sum(0.1, 0.2),
+            (0.30000000000000004, -2.7755575615628914e-17)
+        );
+    }
+}
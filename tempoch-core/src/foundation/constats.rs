@@ -49,6 +49,10 @@ pub const GPS_EPOCH_JD_UTC_DAY: Day = Day::new(2_444_244.5);
 /// bare [`qtty::Second`].
 pub const GPS_EPOCH_TAI_MINUS_UTC: Second = Second::new(19.0);
 
+/// `right/` POSIX time lags TAI seconds since the Unix epoch by this offset
+/// (the 1972 value of `TAI - UTC`), so it matches POSIX at 1972-01-01.
+pub(crate) const UNIX_RIGHT_TAI_OFFSET: Second = Second::new(10.0);
+
 /// IAU 2000 B1.9 reference epoch `T0` as a JD value on the TT axis.
 pub const IAU_TIME_EPOCH_T0_JD_DAY: Day = Day::new(2_443_144.500_372_5);
 
@@ -94,6 +98,12 @@ pub(crate) fn gps_epoch_tai_seconds() -> Second {
     (GPS_EPOCH_JD_UTC_DAY - J2000_JD_TT_DAY).to::<qtty::unit::Second>() + GPS_EPOCH_TAI_MINUS_UTC
 }
 
+/// TAI J2000 seconds of `right/` POSIX time zero (1970-01-01T00:00:10 TAI).
+#[inline]
+pub(crate) fn unix_right_epoch_tai_seconds() -> Second {
+    (UNIX_EPOCH_JD_DAY - J2000_JD_TT_DAY).to::<qtty::unit::Second>() + UNIX_RIGHT_TAI_OFFSET
+}
+
 /// J2000 epoch as [`Time<TT, JD>`].
 #[inline]
 pub fn j2000_jd_tt() -> Time<TT, JD> {
@@ -179,6 +189,7 @@ mod tests {
                 < Day::new(1e-9)
         );
         assert!((gps_epoch_tai_seconds() - Second::new(-630_763_181.0)).abs() < Second::new(1e-9));
+        assert_eq!(unix_right_epoch_tai_seconds(), Second::new(-946_727_990.0));
     }
 
     #[test]
@@ -3,6 +3,7 @@
 
 //! Shared crate foundations used by every domain layer.
 
+pub(crate) mod compensated;
 pub mod constats;
 pub mod duration;
 pub mod error;
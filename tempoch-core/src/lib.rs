@@ -23,7 +23,7 @@
 //!
 //! - [`Time::new`] builds from a raw scalar when `F` is [`InfallibleFormatForScale`] for `S` (**NaN panics**; ±∞ allowed at rest). POSIX [`Unix`] instants still use [`Time::try_new`] / [`Time::try_new_with`] because decoding depends on leap-second tables.
 //! - [`Time::try_new`] / [`Time::try_new_with`] surface **domain** decode failures only (UTC policy, leap seconds, …); callers must not pass **NaN**.
-//! - `UTC`: civil (`chrono`) and POSIX ([`Unix`]); `TAI`: GPS ([`GPS`])
+//! - `UTC`: civil ([`CivilDateTime`], RFC 3339, and `chrono` with the default `chrono` feature), POSIX ([`Unix`]), and `right/` POSIX ([`UnixRight`]); `TAI`: GPS ([`GPS`])
 //! - Unified targets: [`Time::to`], [`Time::try_to`], [`Time::to_with`]. Prefer
 //!   [`try_to`](Time::try_to) or [`to_with`](Time::to_with) for [`Unix`] so positive
 //!   leap-second instants are rejected when they are not representable as POSIX.
@@ -79,6 +79,8 @@ pub use foundation::{constats, error};
 pub use data::runtime_data::{
     fetch_latest_time_data, refresh_runtime_time_data, update_runtime_time_data,
 };
+#[cfg(feature = "chrono")]
+pub use data::status::assert_fresh_chrono as assert_time_data_fresh_chrono;
 pub use data::status::{
     assert_fresh as assert_time_data_fresh, time_data_status, ActiveTimeDataSource, DataHorizons,
     FreshnessError, TimeDataStatus,
@@ -89,10 +91,21 @@ pub use earth::delta_t::{
 };
 pub use earth::eop::{eop_end, eop_observed_end, eop_start};
 pub use features::TimeInstant;
+#[cfg(feature = "hifitime")]
+pub use format::HifitimeScale;
 pub use format::{
-    FormatForScale, FormatOptions, FormatPrecision, GnssWeek, GnssWeekScale, GpsTime,
-    InfallibleFormatForScale, J2000Seconds, J2000s, JulianDate, ModifiedJulianDate, TimeFormat,
-    Unix, UnixTime, GPS, JD, MJD,
+    Calendar, CalendarDate, CalendarScale, CcsdsAsciiCode, CcsdsError, CcsdsLevel, CcsdsPField,
+    CdsCodec, CdsSubMillis, CivilDateTime, CucCodec, DayOfYearProfile, DayOfYearStyle, Dcf77Frame,
+    DecimalSign, EpochFormat, EpochUnit, FitsTimeError, FitsTimeHeader, FitsTimeSys,
+    FormatForScale, FormatOptions, FormatPrecision, GnssEpochLayout, GnssTimeSystem, GnssWeek,
+    GnssWeekScale, GpsTime, InfallibleFormatForScale, IrigBControl, IrigBFrame, Iso8601DateForm,
+    Iso8601Duration, Iso8601DurationError, Iso8601IntervalError, Iso8601IntervalForm,
+    Iso8601Notation, Iso8601Profile, Iso8601Resolution, J2000Seconds, J2000s, JdSplit, JulianDate,
+    LeapIndicator, LocalDateTime, LocalTimePolicy, LocalTimeType, MjdSod, ModifiedJulianDate,
+    MsfFrame, NtpDate, NtpTimestamp, PtpTimestamp, PulseSymbol, SpiceScale, SpiceTime, Tai64Kind,
+    Tai64Label, TimeCodeError, TimeFormat, TimeParseError, TimeParseErrorKind, TimePattern,
+    TimeZone, Unix, UnixRight, UnixRightTime, UnixTime, UtcOffset, WwvbDst, WwvbFrame, ZoneError,
+    ZonedDateTime, GPS, JD, MJD,
 };
 pub use foundation::constats::{
     gps_epoch_jd_tai, gps_epoch_jd_utc, gps_epoch_tai, iau_time_epoch_t0_jd, j2000_jd_tt,
@@ -1,24 +1,28 @@
 // SPDX-License-Identifier: AGPL-3.0-only
 // Copyright (C) 2026 Vallés Puig, Ramon
 
-//! Civil layer: `chrono::DateTime<Utc>` interop plus Unix and GPS
-//! representations.
+//! Civil layer: `chrono::DateTime<Utc>` interop (with the `chrono`
+//! feature) plus Unix and GPS representations.
 
+#[cfg(feature = "chrono")]
+use crate::data::runtime_data::{time_data_tai_seconds_from_utc, time_data_utc_from_tai_seconds};
 use crate::data::runtime_data::{
-    time_data_tai_seconds_from_utc, time_data_tai_seconds_is_in_leap_window,
-    time_data_try_tai_minus_utc_mjd, time_data_utc_from_tai_seconds,
+    time_data_tai_seconds_is_in_leap_window, time_data_try_tai_minus_utc_mjd,
 };
 use crate::earth::context::TimeContext;
-use crate::encoding::{day_to_j2000_seconds, unix_seconds_to_mjd};
+use crate::encoding::{day_to_j2000_seconds, mjd_to_unix_seconds, unix_seconds_to_mjd};
 use crate::format::TimeFormat;
 use crate::format::MJD;
-use crate::foundation::constats::gps_epoch_tai_seconds;
+use crate::foundation::compensated::two_sum;
+use crate::foundation::constats::{gps_epoch_tai_seconds, unix_right_epoch_tai_seconds};
 use crate::foundation::error::ConversionError;
 use crate::model::scale::{TAI, UTC};
 use crate::model::time::Time;
+#[cfg(feature = "chrono")]
 use chrono::{DateTime, Utc};
-use qtty::Second;
+use qtty::{Day, Second};
 
+#[cfg(feature = "chrono")]
 impl<F: TimeFormat> Time<UTC, F> {
     /// Build a UTC instant from a `chrono::DateTime<Utc>` using the context's
     /// captured time-data bundle.
@@ -109,7 +113,9 @@ impl<F: TimeFormat> Time<UTC, F> {
     pub fn to_chrono(self) -> Option<DateTime<Utc>> {
         self.try_to_chrono().ok()
     }
+}
 
+impl<F: TimeFormat> Time<UTC, F> {
     /// Build a UTC instant from a POSIX timestamp in seconds using the
     /// context's captured time-data bundle.
     #[inline]
@@ -143,9 +149,10 @@ impl<F: TimeFormat> Time<UTC, F> {
         if self.to_j2000s().is_leap_second_with(ctx) {
             return Err(ConversionError::InvalidLeapSecond);
         }
-        let dt = self.try_to_chrono_with(ctx)?;
-        let nanos = dt.timestamp_subsec_nanos();
-        Ok(Second::new(dt.timestamp() as f64 + nanos as f64 / 1e9))
+        let label = self.to_j2000s().to_mjd_sod_with(ctx)?;
+        let nanos = label.second_of_day.as_nanos_i128();
+        Ok(mjd_to_unix_seconds(Day::new(label.mjd.value() as f64))
+            + Second::new(nanos as f64 / 1e9))
     }
 
     /// Returns `true` if this instant falls inside a positive leap second in
@@ -187,13 +194,42 @@ impl<F: TimeFormat> Time<TAI, F> {
     pub(crate) fn raw_gps_seconds(self) -> Second {
         self.to_j2000s().total_seconds() - gps_epoch_tai_seconds()
     }
+
+    /// Build a TAI instant from `right/` POSIX seconds.
+    ///
+    /// The epoch offset is an integer, so it goes into the compensation word
+    /// and the value is stored without rounding.
+    #[inline]
+    pub(crate) fn from_raw_unix_right_seconds(
+        seconds: Second,
+    ) -> Result<Time<TAI, crate::format::J2000s>, ConversionError> {
+        if seconds.value().is_nan() {
+            return Err(ConversionError::NonFinite);
+        }
+        Time::<TAI, crate::format::J2000s>::try_from_raw_j2000_seconds_split(
+            seconds,
+            unix_right_epoch_tai_seconds(),
+        )
+    }
+
+    /// Return `right/` POSIX seconds for this instant, rounded once from the
+    /// compensated storage.
+    #[inline]
+    pub(crate) fn raw_unix_right_seconds(self) -> Second {
+        let (hi, lo) = self.split_seconds();
+        let (hi, minus_epoch) = (hi.value(), -unix_right_epoch_tai_seconds().value());
+        let (shifted, error) = two_sum(hi, minus_epoch);
+        Second::new(shifted + (error + lo.value()))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "chrono")]
     use crate::data::runtime_data::{active_time_data, with_test_time_data};
 
+    #[cfg(feature = "chrono")]
     #[test]
     fn chrono_convenience_wrappers_roundtrip_with_context() {
         let bundle = active_time_data().as_ref().clone();
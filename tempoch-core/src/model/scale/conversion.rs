@@ -539,7 +539,6 @@ mod tests {
     use crate::earth::delta_t::interpolate_modern_delta_t_points;
     use crate::foundation::constats::TT_MINUS_TAI;
     use crate::time_data::{MODERN_DELTA_T_POINTS, UTC_TAI_SEGMENTS};
-    use chrono::{Duration, NaiveDate};
     use qtty::{Arcsecond, Day as JulianDay, Millisecond, Second};
 
     const TDB_TT_GOLDEN_SAMPLES: &[(f64, f64)] = &[
@@ -560,8 +559,10 @@ mod tests {
     }
 
     fn mjd_to_date_string(mjd: i32) -> String {
-        let epoch = NaiveDate::from_ymd_opt(1858, 11, 17).expect("valid MJD epoch");
-        (epoch + Duration::days(mjd as i64)).to_string()
+        crate::format::Calendar::Gregorian
+            .date_from_mjd(mjd as i64)
+            .unwrap()
+            .to_string()
     }
 
     #[test]
@@ -72,7 +72,8 @@ define_scale!(
     /// Use the civil layer for any operation that depends on the UTC-TAI
     /// offset (i.e., leap seconds):
     ///
-    /// * [`crate::Time::<UTC>::from_chrono`] / [`crate::Time::try_from_chrono`] / [`crate::Time::try_to_chrono`]
+    /// * [`crate::Time::<UTC>::from_civil`] / [`crate::Time::to_civil`], RFC 3339 and ISO 8601 labels
+    /// * `Time::<UTC>::from_chrono` / `try_from_chrono` / `try_to_chrono` (with the `chrono` feature)
     /// * Unix time: `time.try_to::<`[`crate::Unix`]`>()` returns a [`crate::UnixTime`]
     UTC = "UTC"
 );
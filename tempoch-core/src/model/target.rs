@@ -3,12 +3,12 @@
 
 //! Conversion-target markers for the unified `Time::to::<T>()` API.
 //!
-//! Format markers (`JD`, `MJD`, `J2000s`, `Unix`, `GPS`) and scale markers
+//! Format markers (`JD`, `MJD`, `J2000s`, `Unix`, `UnixRight`, `GPS`) and scale markers
 //! (`TT`, `TAI`, …) implement these traits. The source instant may carry any
 //! format phantom `SrcF`; storage is always the compensated J2000-second pair.
 
 use crate::earth::context::TimeContext;
-use crate::format::markers::{J2000s, Unix, GPS, JD, MJD};
+use crate::format::markers::{J2000s, Unix, UnixRight, GPS, JD, MJD};
 use crate::format::TimeFormat;
 use crate::foundation::error::ConversionError;
 use crate::foundation::sealed::Sealed;
@@ -223,9 +223,44 @@ impl<S: Scale + ContextScaleConvert<TAI>, SrcF: TimeFormat> ContextConversionTar
     }
 }
 
+/// `UnixRight` keeps the source scale, which must be `UTC` or `TAI`.
+macro_rules! unix_right_target {
+    ($($scale:ty),+) => {$(
+        impl<SrcF: TimeFormat> ConversionTarget<$scale, SrcF> for UnixRight {
+            type Output = Time<$scale, UnixRight>;
+
+            #[inline]
+            fn try_convert(src: Time<$scale, SrcF>) -> Result<Self::Output, ConversionError> {
+                Ok(<UnixRight as InfallibleConversionTarget<$scale, SrcF>>::convert(src))
+            }
+        }
+
+        impl<SrcF: TimeFormat> InfallibleConversionTarget<$scale, SrcF> for UnixRight {
+            #[inline]
+            fn convert(src: Time<$scale, SrcF>) -> Self::Output {
+                src.reinterpret()
+            }
+        }
+
+        impl<SrcF: TimeFormat> ContextConversionTarget<$scale, SrcF> for UnixRight {
+            type Output = Time<$scale, UnixRight>;
+
+            #[inline]
+            fn convert_with(
+                src: Time<$scale, SrcF>,
+                _ctx: &TimeContext,
+            ) -> Result<Self::Output, ConversionError> {
+                Ok(src.reinterpret())
+            }
+        }
+    )+};
+}
+
+unix_right_target!(UTC, TAI);
+
 #[cfg(test)]
 mod tests {
-    use crate::format::{J2000s, Unix, GPS, JD, MJD};
+    use crate::format::{J2000s, Unix, UnixRight, GPS, JD, MJD};
     use crate::model::scale::{TAI, TT, UT1, UTC};
     use qtty::Second;
 
@@ -297,4 +332,19 @@ mod tests {
         assert!(unix_sec.is_finite());
         assert!(gps.raw().is_finite());
     }
+
+    #[test]
+    fn unix_right_target_keeps_utc_and_tai_axes() {
+        let ctx = crate::earth::context::TimeContext::new();
+        let utc = crate::model::time::Time::<UTC>::from_raw_unix_seconds_with(
+            Second::new(1_700_000_000.0),
+            &ctx,
+        )
+        .unwrap();
+        // TAI - UTC = 37 s in 2023; right/ time runs 37 - 10 s ahead of POSIX.
+        let right = utc.to::<UnixRight>();
+        assert_eq!(right.raw(), Second::new(1_700_000_027.0));
+        assert_eq!(utc.to::<TAI>().to::<UnixRight>().raw(), right.raw());
+        assert_eq!(utc.to_with::<UnixRight>(&ctx).unwrap(), right);
+    }
 }
@@ -26,6 +26,9 @@ fn coordinate_pair_ok(hi: f64, lo: f64) -> bool {
     !hi.is_nan() && !lo.is_nan()
 }
 
+/// Largest magnitude (2⁵³ s) for which whole J2000 seconds are exact in `f64`.
+const MAX_EXACT_F64_SECONDS: f64 = 9_007_199_254_740_992.0;
+
 #[derive(Copy, Clone)]
 pub(crate) struct ScaleAxis<S: Scale>(PhantomData<fn() -> S>);
 
@@ -266,6 +269,76 @@ impl<S: CoordinateScale, F: InfallibleFormatForScale<S>> Time<S, F> {
 }
 
 impl<S: CoordinateScale, F: TimeFormat> Time<S, F> {
+    /// Whole J2000 seconds (floor) and the `[0, 1)` fraction on the scale axis.
+    ///
+    /// The fraction is recovered from the compensated pair, so it keeps the
+    /// sub-nanosecond detail that a single `f64` total would lose.
+    pub(crate) fn j2000_whole_and_fraction(self) -> Result<(i64, f64), ConversionError> {
+        let (hi, lo) = self.split_seconds();
+        let (hi, lo) = (hi.value(), lo.value());
+        if !hi.is_finite() || !lo.is_finite() {
+            return Err(ConversionError::NonFinite);
+        }
+        if hi.abs() >= MAX_EXACT_F64_SECONDS {
+            return Err(ConversionError::OutOfRange);
+        }
+        let whole = hi.floor();
+        let mut fraction = (hi - whole) + lo;
+        let mut whole = whole as i64;
+        if fraction < 0.0 {
+            fraction += 1.0;
+            whole -= 1;
+        }
+        if fraction >= 1.0 {
+            fraction -= 1.0;
+            whole += 1;
+        }
+        Ok((whole, fraction))
+    }
+
+    /// Inverse of [`Self::j2000_whole_and_fraction`].
+    pub(crate) fn try_from_j2000_whole_and_fraction(
+        whole: i64,
+        fraction: f64,
+    ) -> Result<Self, ConversionError> {
+        if !fraction.is_finite() {
+            return Err(ConversionError::NonFinite);
+        }
+        if (whole as f64).abs() >= MAX_EXACT_F64_SECONDS {
+            return Err(ConversionError::OutOfRange);
+        }
+        Ok(Self::from_split(
+            Second::new(whole as f64),
+            Second::new(fraction),
+        ))
+    }
+
+    /// Exact offset from J2000.0 on the scale axis, rounded to the nearest
+    /// nanosecond.
+    ///
+    /// Unlike [`Self::diff_exact`], the whole-second and fractional parts are
+    /// taken from the compensated pair separately, so an instant built with
+    /// [`Self::try_from_exact_j2000`] always round-trips to the same value.
+    pub fn to_exact_j2000(self) -> Result<crate::ExactDuration, ConversionError> {
+        let (whole, fraction) = self.j2000_whole_and_fraction()?;
+        let nanos = (fraction * 1e9).round() as i128;
+        Ok(crate::ExactDuration::from_nanos(
+            whole as i128 * crate::NANOS_PER_SECOND + nanos,
+        ))
+    }
+
+    /// Build an instant from an exact offset from J2000.0 on the scale axis.
+    ///
+    /// The whole seconds go to the high word and the nanosecond remainder to
+    /// the compensation word, so no precision is lost for offsets within
+    /// ±2⁵³ s. Larger offsets return [`ConversionError::OutOfRange`].
+    pub fn try_from_exact_j2000(offset: crate::ExactDuration) -> Result<Self, ConversionError> {
+        let (whole, nanos) = offset
+            .as_seconds_i64_nanos_checked()
+            .map_err(|_| ConversionError::OutOfRange)?;
+        Self::try_from_j2000_whole_and_fraction(whole, nanos as f64 * 1e-9)
+    }
+
     /// Exact-precision duration from `other` to `self`.
     ///
     /// Unlike the [`Sub`] implementation that returns a `Quantity<F::Unit>`
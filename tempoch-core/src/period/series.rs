@@ -154,6 +154,18 @@ impl<S: CoordinateScale, F: TimeFormat> TimeSeries<S, F> {
         Self::new(start, end, step)
     }
 
+    /// First item of the series (independent of cursor).
+    #[inline]
+    pub fn start(&self) -> Time<S, F> {
+        self.start
+    }
+
+    /// Step between consecutive items.
+    #[inline]
+    pub fn step(&self) -> ExactDuration {
+        ExactDuration::from_nanos(self.step_nanos)
+    }
+
     /// Number of items remaining in the series.
     #[inline]
     pub fn remaining(&self) -> u64 {
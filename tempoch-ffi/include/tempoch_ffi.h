@@ -90,6 +90,28 @@ typedef int32_t tempoch_format_tag_t;
 #endif // __STDC_VERSION__ >= 202311L
 #endif // __cplusplus
 
+// Two-part Julian Date conventions used by the split-instant C ABI.
+//
+enum tempoch_jd_split_t
+#if defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+  : int32_t
+#endif // defined(__cplusplus) || __STDC_VERSION__ >= 202311L
+ {
+  // `jd1 = 2451545.0`, `jd2` = days since J2000.0.
+  TEMPOCH_JD_SPLIT_T_J2000 = 0,
+  // `jd1` = Julian Date of the preceding midnight, `jd2` in `[0, 1)`.
+  TEMPOCH_JD_SPLIT_T_DATE_FRACTION = 1,
+  // `jd1 = 2400000.5`, `jd2` = Modified Julian Date.
+  TEMPOCH_JD_SPLIT_T_MJD = 2,
+};
+#ifndef __cplusplus
+#if __STDC_VERSION__ >= 202311L
+typedef enum tempoch_jd_split_t tempoch_jd_split_t;
+#else
+typedef int32_t tempoch_jd_split_t;
+#endif // __STDC_VERSION__ >= 202311L
+#endif // __cplusplus
+
 // Scale tags used by the split-instant C ABI.
 //
 enum tempoch_scale_tag_t
@@ -560,6 +582,28 @@ tempoch_status_t tempoch_time_from_format(double raw,
                                           const struct tempoch_context_t *context,
                                           struct tempoch_time_t *out);
 
+// Encode a split instant as a two-part Julian Date `jd1 + jd2`.
+//
+// The pair is taken directly from the split storage, so it keeps more
+// precision than [`tempoch_time_to_format`] with `TEMPOCH_FORMAT_TAG_JD`.
+// `split` is a [`TempochJdSplit`] discriminant.
+//
+// # Safety
+// `out_jd1` and `out_jd2` must be valid, writable pointers to `double`.
+
+tempoch_status_t tempoch_time_to_jd_pair(struct tempoch_time_t value,
+                                         int32_t split,
+                                         double *out_jd1,
+                                         double *out_jd2);
+
+// Decode a two-part Julian Date `jd1 + jd2` into a split instant.
+//
+// Any split of the Julian Date is accepted.
+//
+// # Safety
+// `out` must be a valid, writable pointer to `TempochTime`.
+ tempoch_status_t tempoch_time_from_jd_pair(double jd1, double jd2, struct tempoch_time_t *out);
+
 // Build a UTC-axis split instant from a civil calendar label.
 //
 // # Safety
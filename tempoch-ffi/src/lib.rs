@@ -162,4 +162,11 @@ mod tests {
         assert_eq!(TempochFormatTag::Unix as i32, 3);
         assert_eq!(TempochFormatTag::GPS as i32, 4);
     }
+
+    #[test]
+    fn jd_split_discriminants_are_stable() {
+        assert_eq!(TempochJdSplit::J2000 as i32, 0);
+        assert_eq!(TempochJdSplit::DateFraction as i32, 1);
+        assert_eq!(TempochJdSplit::MJD as i32, 2);
+    }
 }
@@ -9,8 +9,8 @@ use crate::time::TempochUtc;
 use crate::{catch_panic, QttyQuantity, UnitId};
 use qtty::Second;
 use tempoch::{
-    ConversionError, FormatForScale, GpsTime, J2000Seconds, Time, TimeContext, Unix, UnixTime, BDT,
-    ET, GPST, GST, JD, MJD, QZSST, TAI, TCB, TCG, TDB, TT, UT1, UTC,
+    ConversionError, FormatForScale, GpsTime, J2000Seconds, JdSplit, Time, TimeContext, Unix,
+    UnixTime, BDT, ET, GPST, GST, JD, MJD, QZSST, TAI, TCB, TCG, TDB, TT, UT1, UTC,
 };
 
 /// Scale tags used by the split-instant C ABI.
@@ -100,6 +100,42 @@ impl TempochFormatTag {
     }
 }
 
+/// Two-part Julian Date conventions used by the split-instant C ABI.
+///
+/// cbindgen:prefix-with-name
+#[repr(i32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TempochJdSplit {
+    /// `jd1 = 2451545.0`, `jd2` = days since J2000.0.
+    J2000 = 0,
+    /// `jd1` = Julian Date of the preceding midnight, `jd2` in `[0, 1)`.
+    DateFraction = 1,
+    /// `jd1 = 2400000.5`, `jd2` = Modified Julian Date.
+    MJD = 2,
+}
+
+impl TempochJdSplit {
+    /// Attempt to decode a raw ABI discriminant.
+    #[inline]
+    pub fn from_raw(raw: i32) -> Option<Self> {
+        match raw {
+            0 => Some(Self::J2000),
+            1 => Some(Self::DateFraction),
+            2 => Some(Self::MJD),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    fn to_core(self) -> JdSplit {
+        match self {
+            Self::J2000 => JdSplit::J2000,
+            Self::DateFraction => JdSplit::DateFraction,
+            Self::MJD => JdSplit::Mjd,
+        }
+    }
+}
+
 /// Split J2000-second instant on a scale-specific axis.
 #[repr(C)]
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -560,6 +596,74 @@ pub unsafe extern "C" fn tempoch_time_from_format(
     })
 }
 
+/// Encode a split instant as a two-part Julian Date `jd1 + jd2`.
+///
+/// The pair is taken directly from the split storage, so it keeps more
+/// precision than [`tempoch_time_to_format`] with `TEMPOCH_FORMAT_TAG_JD`.
+/// `split` is a [`TempochJdSplit`] discriminant.
+///
+/// # Safety
+/// `out_jd1` and `out_jd2` must be valid, writable pointers to `double`.
+#[no_mangle]
+pub unsafe extern "C" fn tempoch_time_to_jd_pair(
+    value: TempochTime,
+    split: i32,
+    out_jd1: *mut f64,
+    out_jd2: *mut f64,
+) -> TempochStatus {
+    catch_panic!(TempochStatus::InternalPanic, {
+        if out_jd1.is_null() || out_jd2.is_null() {
+            return TempochStatus::NullPointer;
+        }
+        let Some(split) = TempochJdSplit::from_raw(split) else {
+            return TempochStatus::InvalidFormatId;
+        };
+        match split_time::<TT>(value) {
+            Ok(time) => {
+                let (jd1, jd2) = time.to_jd_pair(split.to_core());
+                // SAFETY: both pointers were checked for null and the function
+                // safety contract requires them to point to writable `f64`
+                // storage.
+                unsafe {
+                    *out_jd1 = jd1;
+                    *out_jd2 = jd2;
+                }
+                TempochStatus::Ok
+            }
+            Err(err) => status_from_conversion(err),
+        }
+    })
+}
+
+/// Decode a two-part Julian Date `jd1 + jd2` into a split instant.
+///
+/// Any split of the Julian Date is accepted.
+///
+/// # Safety
+/// `out` must be a valid, writable pointer to `TempochTime`.
+#[no_mangle]
+pub unsafe extern "C" fn tempoch_time_from_jd_pair(
+    jd1: f64,
+    jd2: f64,
+    out: *mut TempochTime,
+) -> TempochStatus {
+    catch_panic!(TempochStatus::InternalPanic, {
+        if out.is_null() {
+            return TempochStatus::NullPointer;
+        }
+        match Time::<TT>::from_jd_pair(jd1, jd2) {
+            Ok(time) => {
+                // SAFETY: `out` was checked for null and the function safety
+                // contract requires it to point to writable `TempochTime`
+                // storage.
+                unsafe { *out = TempochTime::from_time(time) };
+                TempochStatus::Ok
+            }
+            Err(err) => status_from_conversion(err),
+        }
+    })
+}
+
 /// Build a UTC-axis split instant from a civil calendar label.
 ///
 /// # Safety
@@ -97,6 +97,37 @@ fn split_time_roundtrips_tt_jd() {
     assert!((jd - 2_451_545.0).abs() < 1e-12);
 }
 
+#[test]
+fn jd_pair_roundtrips_through_split_storage() {
+    let mut value = empty_time();
+    let status = unsafe { tempoch_time_from_jd_pair(2_460_475.5, 0.25, &mut value) };
+    assert_eq!(status, TempochStatus::Ok);
+
+    let (mut jd1, mut jd2) = (0.0, 0.0);
+    let status = unsafe {
+        tempoch_time_to_jd_pair(
+            value,
+            TempochJdSplit::DateFraction as i32,
+            &mut jd1,
+            &mut jd2,
+        )
+    };
+    assert_eq!(status, TempochStatus::Ok);
+    assert_eq!((jd1, jd2), (2_460_475.5, 0.25));
+
+    let status =
+        unsafe { tempoch_time_to_jd_pair(value, TempochJdSplit::MJD as i32, &mut jd1, &mut jd2) };
+    assert_eq!(status, TempochStatus::Ok);
+    assert_eq!((jd1, jd2), (2_400_000.5, 60_475.25));
+
+    let status = unsafe { tempoch_time_to_jd_pair(value, 99, &mut jd1, &mut jd2) };
+    assert_eq!(status, TempochStatus::InvalidFormatId);
+    let status = unsafe { tempoch_time_to_jd_pair(value, 0, std::ptr::null_mut(), &mut jd2) };
+    assert_eq!(status, TempochStatus::NullPointer);
+    let status = unsafe { tempoch_time_from_jd_pair(f64::NAN, 0.0, &mut value) };
+    assert_eq!(status, TempochStatus::ConversionFailed);
+}
+
 #[test]
 fn split_time_rejects_invalid_scale_and_format_ids() {
     let mut value = empty_time();
@@ -12,22 +12,36 @@
 //! - [`constats`] for epoch [`Time`] helpers plus canonical astronomical
 //!   `qtty::Day` / `qtty::Second` facts
 
+#[cfg(feature = "chrono")]
+pub use tempoch_core::assert_time_data_fresh_chrono;
+#[cfg(feature = "hifitime")]
+pub use tempoch_core::HifitimeScale;
 pub use tempoch_core::{
     assert_time_data_fresh, complement_within, constats, delta_t_seconds,
     delta_t_seconds_extrapolated, eop, gps_epoch_jd_tai, gps_epoch_jd_utc, gps_epoch_tai,
     iau_time_epoch_t0_jd, j2000_jd_tt, tdb_tt_model_high_accuracy_end_jd,
     tdb_tt_model_high_accuracy_start_jd, time_data_status, unix_epoch_jd, unix_epoch_mjd,
-    utc_defined_from_mjd, ActiveTimeDataSource, ContextConversionTarget, ContinuousScale,
-    ConversionError, ConversionTarget, CoordinateScale, DataHorizons, DurationError, ExactDuration,
-    FormatForScale, FormatOptions, FormatPrecision, FreshnessError, GnssWeek, GnssWeekScale,
-    GpsTime, InfallibleConversionTarget, InfallibleFormatForScale, Interval, InvalidIntervalError,
-    J2000Seconds, J2000s, JulianDate, ModifiedJulianDate, Period, PeriodListError, Scale, Time,
-    TimeContext, TimeDataError, TimeDataStatus, TimeFormat, TimeInstant, TimeSeries,
-    TimeSeriesError, Unix, UnixTime, BDT, DELTA_T_PREDICTION_HORIZON_MJD, ET, GPS, GPST,
-    GPS_EPOCH_JD_UTC_DAY, GPS_EPOCH_TAI_MINUS_UTC, GST, IAU_TIME_EPOCH_T0_JD_DAY, J2000_JD_TT_DAY,
-    JD, MJD, MODERN_DELTA_T_OBSERVED_END_MJD, NANOS_PER_SECOND, QZSST, TAI, TCB, TCG, TDB,
-    TDB_TT_MODEL_HIGH_ACCURACY_END_JD_DAY, TDB_TT_MODEL_HIGH_ACCURACY_START_JD_DAY, TT,
-    TT_MINUS_TAI, UNIX_EPOCH_JD_DAY, UT1, UTC, UTC_DEFINED_FROM_MJD_DAY,
+    utc_defined_from_mjd, ActiveTimeDataSource, Calendar, CalendarDate, CalendarScale,
+    CcsdsAsciiCode, CcsdsError, CcsdsLevel, CcsdsPField, CdsCodec, CdsSubMillis, CivilDateTime,
+    ContextConversionTarget, ContinuousScale, ConversionError, ConversionTarget, CoordinateScale,
+    CucCodec, DataHorizons, DayOfYearProfile, DayOfYearStyle, Dcf77Frame, DecimalSign,
+    DurationError, EpochFormat, EpochUnit, ExactDuration, FitsTimeError, FitsTimeHeader,
+    FitsTimeSys, FormatForScale, FormatOptions, FormatPrecision, FreshnessError, GnssEpochLayout,
+    GnssTimeSystem, GnssWeek, GnssWeekScale, GpsTime, InfallibleConversionTarget,
+    InfallibleFormatForScale, Interval, InvalidIntervalError, IrigBControl, IrigBFrame,
+    Iso8601DateForm, Iso8601Duration, Iso8601DurationError, Iso8601IntervalError,
+    Iso8601IntervalForm, Iso8601Notation, Iso8601Profile, Iso8601Resolution, J2000Seconds, J2000s,
+    JdSplit, JulianDate, LeapIndicator, LocalDateTime, LocalTimePolicy, LocalTimeType, MjdSod,
+    ModifiedJulianDate, MsfFrame, NtpDate, NtpTimestamp, Period, PeriodListError, PtpTimestamp,
+    PulseSymbol, Scale, SpiceScale, SpiceTime, Tai64Kind, Tai64Label, Time, TimeCodeError,
+    TimeContext, TimeDataError, TimeDataStatus, TimeFormat, TimeInstant, TimeParseError,
+    TimeParseErrorKind, TimePattern, TimeSeries, TimeSeriesError, TimeZone, Unix, UnixRight,
+    UnixRightTime, UnixTime, UtcOffset, WwvbDst, WwvbFrame, ZoneError, ZonedDateTime, BDT,
+    DELTA_T_PREDICTION_HORIZON_MJD, ET, GPS, GPST, GPS_EPOCH_JD_UTC_DAY, GPS_EPOCH_TAI_MINUS_UTC,
+    GST, IAU_TIME_EPOCH_T0_JD_DAY, J2000_JD_TT_DAY, JD, MJD, MODERN_DELTA_T_OBSERVED_END_MJD,
+    NANOS_PER_SECOND, QZSST, TAI, TCB, TCG, TDB, TDB_TT_MODEL_HIGH_ACCURACY_END_JD_DAY,
+    TDB_TT_MODEL_HIGH_ACCURACY_START_JD_DAY, TT, TT_MINUS_TAI, UNIX_EPOCH_JD_DAY, UT1, UTC,
+    UTC_DEFINED_FROM_MJD_DAY,
 };
 
 /// Historical name for [`Time<S, F>`] after the format-parameter merge.